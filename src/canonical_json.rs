@@ -0,0 +1,31 @@
+//! Canonical JSON rendering of the built documents (`serde_json` feature).
+
+use serde_json::Value;
+
+/// Renders a document as compact JSON, with the keys of all the nested documents sorted.
+///
+/// The values are rendered in relaxed extended JSON (e.g. `{"$oid":"..."}` for an `ObjectId`),
+/// while the order of the array elements is kept.
+pub(crate) fn to_canonical_json(doc: &bson::Document) -> String {
+    sorted(bson::Bson::Document(doc.clone()).into_relaxed_extjson()).to_string()
+}
+
+/// Sorts the keys of the JSON objects, at any depth.
+fn sorted(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries = map.into_iter().collect::<Vec<_>>();
+
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, nested)| (key, sorted(nested)))
+                    .collect(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sorted).collect()),
+        value => value,
+    }
+}
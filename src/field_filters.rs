@@ -4,12 +4,22 @@ use bson;
 
 /// A builder for operation-specific filters.
 ///
+/// It's either standalone (e.g. in the closures of `FilterBuilder::not`), or selected on a
+/// filter builder with `FilterBuilder::field`, to which the operations are then added as a
+/// single clause by `apply`.
+///
 /// # Type Parameters
 ///
 /// * `F` - The field name marker type that this operation builder targets
 /// * `T` - The struct type that contains the field `F`
-pub struct FieldFilterBuilder<F: FieldName, T: HasField<F>> {
+/// * `P` - The filter builder it was selected on, if any (`()` for a standalone builder)
+///
+/// As the operations are only added to the filter by `apply` (or `build`), the builder is
+/// `#[must_use]`, so the `unused_must_use` lint warns when it's dropped before.
+#[must_use = "call `apply()` (or `build()` for a standalone builder) to add the operations"]
+pub struct FieldFilterBuilder<F: FieldName, T: HasField<F>, P = ()> {
     ops: Vec<(&'static str, bson::Bson)>,
+    parent: P,
     _marker: std::marker::PhantomData<(F, T)>,
 }
 
@@ -34,12 +44,59 @@ where
     ///
     /// * `build` - A closure that takes a BSON document and returns a FilterBuilder for the target struct
     pub fn new() -> Self {
+        Self::with_parent(())
+    }
+
+    /// Builds the configured operations into a FilterBuilder.
+    ///
+    /// This method consumes the FieldFilterBuilder and transforms all accumulated
+    /// operations into a FilterBuilder by creating a BSON document from the operations
+    /// and passing it to the build closure provided during construction.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `FilterBuilder<T>` that contains the configured operations,
+    /// ready to be used for further filter building or converted to a final BSON document.
+    pub fn build(self) -> bson::Document {
+        let field_name = F::field_name().to_string();
+
+        // Special handling for equality: MongoDB allows both { field: value } and { field: { $eq: value } }
+        // For simplicity with other operations, we'll use the explicit $eq form
+        match self.into_parts() {
+            (Some(operations), _) => bson::doc! { field_name: operations },
+            (None, _) => bson::doc! {},
+        }
+    }
+}
+
+impl<F, T, P> FieldFilterBuilder<F, T, P>
+where
+    F: FieldName,
+    T: HasField<F>,
+{
+    pub(crate) fn with_parent(parent: P) -> Self {
         Self {
             ops: Vec::new(),
+            parent,
             _marker: std::marker::PhantomData,
         }
     }
 
+    // Returns the accumulated operations (if any) as a document, and the parent builder
+    pub(crate) fn into_parts(self) -> (Option<bson::Document>, P) {
+        if self.ops.is_empty() {
+            return (None, self.parent);
+        }
+
+        let mut operations = bson::Document::new();
+
+        for (op_name, value) in self.ops {
+            operations.insert(op_name, value);
+        }
+
+        (Some(operations), self.parent)
+    }
+
     // ---
 
     /// Type-safe equality operation for the FieldFilterBuilder.
@@ -82,6 +139,45 @@ where
         self
     }
 
+    /// Type-safe inequality operation for the FieldFilterBuilder.
+    ///
+    /// Adds a `$ne` operation to the current operation builder, which can later
+    /// be built into a MongoDB filter with the configured operations.
+    ///
+    /// # Type parameters:
+    /// * `V` - The type of the field value or a compatible type
+    ///
+    /// # Arguments
+    /// * `value` - The value the field must not be equal to
+    ///
+    /// # Returns
+    /// Returns self for method chaining by value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::field_filters::FieldFilterBuilder;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User { pub name: String }
+    ///
+    /// let filter_doc = FieldFilterBuilder::<user_fields::Name, User>::new()
+    ///     .ne("John Doe")
+    ///     .build();
+    ///
+    /// assert_eq!(filter_doc, bson::doc! { "name": { "$ne": "John Doe" } });
+    /// ```
+    pub fn ne<V>(mut self, value: V) -> Self
+    where
+        T: MongoComparable<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        self.ops.push(("$ne", value.into()));
+
+        self
+    }
+
     /// Type-safe greater than operation for the FieldFilterBuilder.
     ///
     /// Adds a greater than operation to the current operation builder, which can later
@@ -371,32 +467,93 @@ where
         self
     }
 
-    /// Builds the configured operations into a FilterBuilder.
+    /// Regular expression operation for the FieldFilterBuilder.
     ///
-    /// This method consumes the FieldFilterBuilder and transforms all accumulated
-    /// operations into a FilterBuilder by creating a BSON document from the operations
-    /// and passing it to the build closure provided during construction.
+    /// Adds a `$regex` operation, with the same options handling as `FilterBuilder::regex`:
+    /// the `$options` are only added when some non-empty options are given.
+    /// It allows negating a pattern with `FilterBuilder::not`.
+    ///
+    /// # Arguments
+    /// * `expr` - The regular expression pattern
+    /// * `options` - The optional regex options (e.g. `Some("i")` for case insensitive)
     ///
     /// # Returns
+    /// Returns self for method chaining by value.
     ///
-    /// Returns a `FilterBuilder<T>` that contains the configured operations,
-    /// ready to be used for further filter building or converted to a final BSON document.
-    pub fn build(self) -> bson::Document {
-        let field_name = F::field_name().to_string();
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct Product { pub name: String }
+    ///
+    /// let filter_doc = empty::<Product>()
+    ///     .not::<product_fields::Name, _>(|op| op.regex("^temp", Some("i")))
+    ///     .and();
+    ///
+    /// assert_eq!(
+    ///     filter_doc,
+    ///     bson::doc! { "name": { "$not": { "$regex": "^temp", "$options": "i" } } }
+    /// );
+    /// ```
+    pub fn regex(mut self, expr: &str, options: Option<&str>) -> Self {
+        self.ops.push(("$regex", expr.into()));
 
-        if self.ops.is_empty() {
-            return bson::doc! {};
+        if let Some(opts) = options
+            && !opts.is_empty()
+        {
+            self.ops.push(("$options", opts.into()));
         }
 
-        // Special handling for equality: MongoDB allows both { field: value } and { field: { $eq: value } }
-        // For simplicity with other operations, we'll use the explicit $eq form
-        let mut operations = bson::Document::new();
+        self
+    }
 
-        for (op_name, value) in self.ops {
-            operations.insert(op_name, value);
-        }
+    /// Array element matching operation for the FieldFilterBuilder.
+    ///
+    /// Adds an `$elemMatch` operation, with the operations configured by `f` applying to
+    /// the elements of the array field (see `FilterBuilder::elem_match`).
+    /// It allows matching the arrays where no element satisfies all the operations,
+    /// with `FilterBuilder::not`.
+    ///
+    /// # Arguments
+    /// * `f` - A closure configuring the operations on the array elements
+    ///
+    /// # Returns
+    /// Returns self for method chaining by value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct Student { pub scores: Vec<i32> }
+    ///
+    /// // No score above 90
+    /// let filter_doc = empty::<Student>()
+    ///     .not::<student_fields::Scores, _>(|op| op.elem_match(|elem| elem.gt(90)))
+    ///     .and();
+    ///
+    /// assert_eq!(
+    ///     filter_doc,
+    ///     bson::doc! { "scores": { "$not": { "$elemMatch": { "$gt": 90 } } } }
+    /// );
+    /// ```
+    pub fn elem_match<B>(mut self, f: B) -> Self
+    where
+        T::Value: IntoIterator,
+        B: FnOnce(FieldFilterBuilder<F, T>) -> FieldFilterBuilder<F, T>,
+    {
+        let (operations, _) = f(FieldFilterBuilder::new()).into_parts();
+
+        self.ops
+            .push(("$elemMatch", operations.unwrap_or_default().into()));
 
-        // Return document with operations
-        bson::doc! { field_name: operations }
+        self
     }
 }
@@ -1,3 +1,7 @@
+use crate::filters::FilterBuilder;
+use crate::projection::BasicProjectionBuilder;
+use crate::updates::UpdateBuilder;
+
 /// # Field Witnesses
 ///
 /// This module provides type-level field references for Rust structs, enabling compile-time
@@ -37,6 +41,14 @@ pub trait HasField<F: FieldName> {
     /// The value type of the field
     type Value;
 
+    /// The `$type` alias of the BSON value stored for the field (e.g. `"string"`), if known.
+    ///
+    /// The derive infers it from the Rust type, looking through `Option` (so the `null` values
+    /// aside), or takes the one declared with `#[tnuctipun(bson_type = "...")]`. It's `None`
+    /// when the serialization can't be inferred (e.g. for an embedded struct, or for a
+    /// `chrono::DateTime`, stored as a string unless declared otherwise).
+    const BSON_TYPE: Option<&'static str> = None;
+
     /// Get a reference to the field value
     fn get_field(&self) -> &Self::Value;
 }
@@ -68,6 +80,56 @@ pub trait FieldName {
     fn field_name() -> &'static str;
 }
 
+/// Asserts at compile time that a field witness is valid for a struct.
+///
+/// The assertion expands to a constant item type-checking `HasField<F>` for the struct,
+/// without any runtime cost. It's allowed both at module level and in function bodies,
+/// which helps writing guard tests or documenting compile-time guarantees.
+///
+/// ```rust
+/// use tnuctipun::{FieldWitnesses, assert_has_field};
+///
+/// #[derive(FieldWitnesses)]
+/// struct User {
+///     pub name: String,
+/// }
+///
+/// assert_has_field!(User, user_fields::Name);
+/// ```
+///
+/// The compilation fails if the witness is not a field of the struct:
+///
+/// ```compile_fail
+/// use tnuctipun::{FieldWitnesses, assert_has_field};
+///
+/// #[derive(FieldWitnesses)]
+/// struct User {
+///     pub name: String,
+/// }
+///
+/// #[derive(FieldWitnesses)]
+/// struct Product {
+///     pub price: f64,
+/// }
+///
+/// assert_has_field!(User, product_fields::Price);
+/// ```
+#[macro_export]
+macro_rules! assert_has_field {
+    ($struct_type:ty, $field:ty $(,)?) => {
+        const _: fn() = || {
+            fn _assert<T, F>()
+            where
+                F: $crate::field_witnesses::FieldName,
+                T: $crate::field_witnesses::HasField<F>,
+            {
+            }
+
+            _assert::<$struct_type, $field>();
+        };
+    };
+}
+
 /// Evidence that a type is a non-empty struct.
 /// It's implemented for structs that have at least one field and are using the `FieldWitnesses` derive macro.
 ///
@@ -82,7 +144,133 @@ pub trait FieldName {
 /// /// impl NonEmptyStruct for User {}
 /// ```
 ///
-pub trait NonEmptyStruct {}
+/// It also provides constructors for the typed builders, as alternatives to the `empty`
+/// functions that read better and are discoverable by autocompletion:
+///
+/// ```rust
+/// use tnuctipun::{FieldWitnesses, MongoComparable, NonEmptyStruct};
+///
+/// #[derive(FieldWitnesses, MongoComparable)]
+/// struct User {
+///     pub name: String,
+///     pub age: i32,
+/// }
+///
+/// let filter = User::filter().eq::<user_fields::Name, _>("John").and();
+///
+/// assert_eq!(filter, bson::doc! { "name": "John" });
+///
+/// let update = User::update().inc::<user_fields::Age, _>(1).build();
+///
+/// assert_eq!(update, bson::doc! { "$inc": { "age": 1 } });
+///
+/// let projection = User::project().includes::<user_fields::Name>().build();
+///
+/// assert_eq!(projection, bson::doc! { "name": 1 });
+/// ```
+pub trait NonEmptyStruct {
+    /// Creates an empty filter builder for this struct (same as `filters::empty`)
+    fn filter() -> FilterBuilder<Self>
+    where
+        Self: Sized,
+    {
+        FilterBuilder::new()
+    }
+
+    /// Creates an empty update builder for this struct (same as `updates::empty`)
+    fn update() -> UpdateBuilder<Self>
+    where
+        Self: Sized,
+    {
+        UpdateBuilder::new()
+    }
+
+    /// Creates an empty projection builder for this struct (same as `projection::empty`).
+    ///
+    /// It's unrelated to `ProjectionBuilder::project`, which adds a clause to a builder.
+    fn project() -> BasicProjectionBuilder<Self>
+    where
+        Self: Sized,
+    {
+        BasicProjectionBuilder::new()
+    }
+}
+
+/// Evidence that a field can be missing from the stored documents, without breaking the
+/// deserialization of the struct.
+///
+/// It's implemented by the `FieldWitnesses` derive macro for the `Option` fields, and for
+/// the fields having a serde default (`#[serde(default)]` on the field or on the struct).
+/// It allows `UpdateBuilder::unset_optional` to only remove such fields.
+///
+/// ```rust
+/// use tnuctipun::{FieldWitnesses, OptionalField};
+/// use serde::Deserialize;
+///
+/// #[derive(FieldWitnesses, Deserialize)]
+/// struct User {
+///     pub name: String,
+///     pub nickname: Option<String>,
+///     #[serde(default)]
+///     pub tags: Vec<String>,
+/// }
+///
+/// fn assert_optional<F: tnuctipun::FieldName, T: OptionalField<F>>() {}
+///
+/// assert_optional::<user_fields::Nickname, User>();
+/// assert_optional::<user_fields::Tags, User>();
+/// ```
+pub trait OptionalField<F: FieldName>: HasField<F> {}
+
+/// Runtime listing of the MongoDB field names witnessed for a struct.
+///
+/// It's implemented by the `FieldWitnesses` derive macro, in declaration order, and honors
+/// the naming strategy, `rename`, `skip` and `include_private` attributes. It allows
+/// validating field names only known at runtime (e.g. received by an HTTP API).
+///
+/// ```rust
+/// use tnuctipun::{FieldNames, FieldWitnesses};
+///
+/// #[derive(Debug, Clone, FieldWitnesses)]
+/// #[tnuctipun(field_naming = "camelCase")]
+/// struct User {
+///     pub user_name: String,
+///     pub age: i32,
+/// }
+///
+/// assert_eq!(User::field_names(), &["userName", "age"]);
+/// ```
+pub trait FieldNames {
+    /// Returns the MongoDB names of all the witnessed fields
+    fn field_names() -> &'static [&'static str];
+}
+
+/// Error raised when a runtime field name doesn't match any witnessed field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownFieldError {
+    name: String,
+}
+
+impl UnknownFieldError {
+    pub(crate) fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+
+    /// Returns the unknown field name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl std::fmt::Display for UnknownFieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown field: '{}'", self.name)
+    }
+}
+
+impl std::error::Error for UnknownFieldError {}
 
 /// Example showing compile error when trying to access a nonexistent field:
 ///
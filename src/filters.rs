@@ -4,6 +4,7 @@ use crate::field_witnesses::{FieldName, HasField};
 use crate::mongo_comparable::{MongoComparable, MongoOrdered};
 use crate::path::Path;
 use bson;
+use chrono::{DateTime, Utc};
 
 /// A builder for constructing MongoDB filters with type safety.
 ///
@@ -13,13 +14,20 @@ use bson;
 /// The builder can be used to chain multiple filter conditions together,
 /// and provides methods to combine them using MongoDB's `$and` semantics.
 ///
+/// As no value of `T` is stored, the builder is `Send` and `Sync` whatever `T` is,
+/// so it can be built on a thread and used on another.
+///
+/// The constructors and the finalizers (`and`, `and_flat`, `and_explicit`) are `#[must_use]`,
+/// so the `unused_must_use` lint warns when a built filter is dropped. The chained methods
+/// returning `&mut Self` are not, so the conditions can be added in separate statements.
+///
 /// # Type Parameters
 ///
 /// * `T` - The struct type that this filter builder operates on (e.g., `User`, `Product`)
 pub struct FilterBuilder<T> {
     prefix: Vec<String>,
     clauses: Vec<bson::Document>,
-    _marker: std::marker::PhantomData<T>,
+    _marker: std::marker::PhantomData<fn() -> T>,
 }
 
 impl<T> FilterBuilder<T> {
@@ -39,6 +47,7 @@ impl<T> FilterBuilder<T> {
     ///
     /// let builder = FilterBuilder::<User>::new();
     /// ```
+    #[must_use]
     pub fn new() -> Self {
         Self {
             prefix: Vec::new(),
@@ -47,6 +56,39 @@ impl<T> FilterBuilder<T> {
         }
     }
 
+    /// Creates an empty FilterBuilder whose field paths are prefixed by the given path.
+    ///
+    /// This is for the nested contexts whose dotted path is already known (e.g. the
+    /// `identifier` of the array filters), while `with_lookup` resolves it from the witnesses.
+    /// The prefix itself is not verified against `T`.
+    ///
+    /// # Arguments
+    /// * `prefix` - The path segments, prepended to each field name (e.g. `["home_address"]`)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::FilterBuilder;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Address { pub city: String }
+    ///
+    /// let filter = FilterBuilder::<Address>::with_prefix(vec!["home_address".to_string()])
+    ///     .eq::<address_fields::City, _>("Lyon")
+    ///     .and();
+    ///
+    /// assert_eq!(filter, bson::doc! { "home_address.city": "Lyon" });
+    /// ```
+    #[must_use]
+    pub fn with_prefix(prefix: Vec<String>) -> Self {
+        Self {
+            prefix,
+            clauses: Vec::new(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
     /// Returns a fully qualified field path for the given field name marker type.
     fn field_path<F: FieldName>(&self) -> String {
         if self.prefix.is_empty() {
@@ -76,6 +118,38 @@ impl<T> FilterBuilder<T> {
     /// // Using builder pattern with efficient chaining:
     /// empty::<User>().eq::<user_fields::Name, _>("John".to_string());
     /// ```
+    ///
+    /// Querying by `_id` works the same way with an `ObjectId` field, as the derive makes
+    /// any field type comparable with itself:
+    ///
+    /// ```rust
+    /// use bson::oid::ObjectId;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use tnuctipun::filters::empty;
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User {
+    ///     #[tnuctipun(rename = "_id")]
+    ///     pub id: ObjectId,
+    ///     pub name: String,
+    /// }
+    ///
+    /// let object_id = ObjectId::new();
+    /// let filter = empty::<User>().eq::<user_fields::Id, _>(object_id).and();
+    ///
+    /// assert_eq!(filter, bson::doc! { "_id": object_id });
+    /// ```
+    ///
+    /// On a `String` field, the value can be a `String`, a `&str` or a `&String`. `Cow<str>`
+    /// values aren't supported, as bson has no `Cow` conversion into `bson::Bson`: they must be
+    /// passed as `&*cow` (or `cow.as_ref()`).
+    ///
+    /// A `bool` field is only comparable with `bool` values (e.g. `eq::<user_fields::Active, _>(true)`
+    /// results in `{ "active": true }`), as MongoDB doesn't match booleans with numbers.
+    ///
+    /// On an array field, MongoDB gives `eq` two different meanings depending on the value:
+    /// an array value matches the array exactly, while an element value matches if the array
+    /// contains it. To make the intent explicit, prefer `array_eq` and `array_contains`.
     pub fn eq<F, V>(&mut self, value: V) -> &mut Self
     where
         F: FieldName,
@@ -89,311 +163,409 @@ impl<T> FilterBuilder<T> {
         self
     }
 
-    /// Creates a type-safe version of MongoDB's regular expression (`$regex`) filter.
+    /// Creates an equality filter from any serde-serializable value.
     ///
-    /// This method allows you to filter documents based on pattern matching using regular expressions.
-    /// It's particularly useful for text searches, partial matches, and complex string pattern filtering.
+    /// This allows filtering on values having no conversion into `bson::Bson`, such as enums:
+    /// the value is converted with `bson::serialize_to_bson`, so its serde attributes are
+    /// honored. The field must exist in the struct, but as the value is type-erased by the
+    /// serialization, no `MongoComparable` evidence is required.
     ///
     /// # Type parameters:
-    /// * `F` - The field name marker type (e.g., `user_fields::Name`)
-    ///
-    /// # Arguments
-    /// * `expr` - The regular expression pattern as a string
-    /// * `options` - Optional regex options (None for no options, Some("i") for case-insensitive, etc.)
-    ///
-    /// # MongoDB Regex Behavior
-    ///
-    /// The `$regex` operator provides regular expression capabilities for pattern matching strings in queries.
-    /// MongoDB uses Perl Compatible Regular Expressions (PCRE) version 8.42 with UTF-8 support.
+    /// * `F` - The field name marker type (e.g., `user_fields::Status`)
+    /// * `V` - A value type that implements `serde::Serialize`
     ///
-    /// # Common Use Cases
+    /// # Errors
     ///
-    /// - **Case-insensitive search**: Use regex options like `Some("i")`
-    /// - **Partial matches**: Search for substrings within field values
-    /// - **Pattern validation**: Match specific formats (emails, phone numbers, etc.)
-    /// - **Wildcard searches**: Use `.*` for flexible matching
+    /// Returns an error if the value cannot be serialized to BSON.
     ///
     /// # Example
     ///
     /// ```rust
     /// use tnuctipun::filters::empty;
-    /// use tnuctipun::{FieldWitnesses, MongoComparable};
-    /// use serde::{Serialize, Deserialize};
+    /// use tnuctipun::FieldWitnesses;
+    /// use serde::Serialize;
     ///
-    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// struct User {
-    ///     pub name: String,
-    ///     pub email: String,
+    /// #[derive(Serialize)]
+    /// #[serde(rename_all = "snake_case")]
+    /// enum Status {
+    ///     Active,
+    ///     Suspended,
     /// }
     ///
-    /// // Case-insensitive search for names containing "john"
-    /// empty::<User>().regex::<user_fields::Name>("john", Some("i"));
-    /// // Resulting BSON: { "name": { "$regex": "john", "$options": "i" } }
-    ///
-    /// // Email validation pattern (no options)
-    /// empty::<User>().regex::<user_fields::Email>(r"^[\w\.-]+@[\w\.-]+\.\w+$", None);
-    /// // Resulting BSON: { "email": { "$regex": "^[\\w\\.-]+@[\\w\\.-]+\\.\\w+$" } }
-    ///
-    /// // Partial match for product names starting with "Laptop"
-    /// empty::<User>().regex::<user_fields::Name>("^Laptop.*", None);
-    /// // Resulting BSON: { "name": { "$regex": "^Laptop.*" } }
-    /// ```
-    ///
-    /// # Advanced Regex Options
-    ///
-    /// For more complex regex queries with options, use the `Some()` wrapper:
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub status: Status,
+    /// }
     ///
-    /// ```rust
-    /// use tnuctipun::filters::empty;
-    /// # use tnuctipun::{FieldWitnesses, MongoComparable};
-    /// # use serde::{Serialize, Deserialize};
-    /// # #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// # struct User { pub name: String }
+    /// let filter = empty::<User>()
+    ///     .eq_serialized::<user_fields::Status, _>(&Status::Suspended)
+    ///     .unwrap()
+    ///     .and();
     ///
-    /// // Case-insensitive and multiline options
-    /// empty::<User>().regex::<user_fields::Name>("pattern", Some("im"));
-    /// // Resulting BSON: { "name": { "$regex": "pattern", "$options": "im" } }
+    /// assert_eq!(filter, bson::doc! { "status": "suspended" });
+    /// // Resulting BSON: { "status": "suspended" }
     /// ```
-    ///
-    /// # Common Regex Patterns
-    ///
-    /// | Pattern | Description | Example |
-    /// |---------|-------------|---------|
-    /// | `^text` | Starts with "text" | `^Hello` matches "Hello World" |
-    /// | `text$` | Ends with "text" | `World$` matches "Hello World" |
-    /// | `.*text.*` | Contains "text" | `.*cat.*` matches "concatenate" |
-    /// | `(?i)text` | Case-insensitive (inline) | `(?i)hello` matches "HELLO" |
-    /// | `\d+` | One or more digits | `\d+` matches "123" |
-    /// | `[a-zA-Z]+` | Letters only | Matches alphabetic strings |
-    ///
-    /// # Regex Options
-    ///
-    /// MongoDB supports these regex options (passed as Some("options")):
-    /// - `i` - Case insensitive matching
-    /// - `m` - Multiline mode (^ and $ match line boundaries)
-    /// - `x` - Extended mode (ignore whitespace and allow comments)
-    /// - `s` - Dotall mode (. matches newlines)
-    ///
-    /// Options can be combined: `Some("im")` for case-insensitive multiline.
-    ///
-    /// # Type Safety
-    ///
-    /// This method ensures compile-time verification that:
-    /// - The field `F` exists in the struct `T`
-    /// - The field is compatible with regex operations (typically String fields)
-    pub fn regex<F: FieldName>(&mut self, expr: &str, options: Option<&str>) -> &mut Self
+    pub fn eq_serialized<F, V>(&mut self, value: &V) -> bson::error::Result<&mut Self>
     where
+        F: FieldName,
         T: HasField<F>,
+        V: serde::Serialize,
     {
         let path = self.field_path::<F>();
-        let mut regex_doc = bson::doc! { "$regex": expr };
-
-        if let Some(opts) = options
-            && !opts.is_empty()
-        {
-            regex_doc.insert("$options", opts);
-        }
-
-        self.clauses.push(bson::doc! { path: regex_doc });
+        let value = bson::serialize_to_bson(value)?;
 
-        self
-    }
+        self.clauses.push(bson::doc! { path: value });
 
-    /// Returns the current filter clauses as a vector of BSON documents.
-    pub fn clauses(&self) -> &Vec<bson::Document> {
-        &self.clauses
+        Ok(self)
     }
 
-    /// Creates a type-safe version of MongoDB's greater than (`$gt`) filter.
+    /// Creates an equality filter between an embedded document field and the given document
+    /// (e.g. a compound value used as a shard key).
     ///
-    /// # Type parameters:
-    /// * `F` - The field name marker type (e.g., `product_fields::Price`)
-    /// * `V` - The type of the field value or a compatible type
+    /// MongoDB matches the embedded document exactly: it must have the same fields, in the
+    /// same order, with the same values. The document is wrapped with `$eq`, so its keys are
+    /// never interpreted as query operators (unlike `untyped`). The field must exist in the
+    /// struct, but as the document is untyped, no `MongoComparable` evidence is required.
     ///
-    /// The comparison requires both `MongoComparable` and `MongoOrdered`
-    /// evidence for the field/value type pair.
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `order_fields::ShardKey`)
     ///
     /// # Example
     ///
     /// ```rust
     /// use tnuctipun::filters::empty;
-    /// use tnuctipun::{FieldWitnesses, MongoComparable};
-    /// use serde::{Serialize, Deserialize};
+    /// use tnuctipun::FieldWitnesses;
     ///
-    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// struct Product { pub Price: f64 }
+    /// #[derive(FieldWitnesses)]
+    /// struct ShardKey {
+    ///     pub region: String,
+    ///     pub customer_id: i64,
+    /// }
     ///
-    /// // Filter for products with price > 500
-    /// empty::<Product>().gt::<product_fields::Price, _>(500.0);
-    /// // Resulting BSON: { "Price": { "$gt": 500.0 } }
+    /// #[derive(FieldWitnesses)]
+    /// struct Order {
+    ///     pub shard_key: ShardKey,
+    /// }
+    ///
+    /// let filter = empty::<Order>()
+    ///     .eq_embedded::<order_fields::ShardKey>(bson::doc! { "region": "eu", "customer_id": 42_i64 })
+    ///     .and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! { "shard_key": { "$eq": { "region": "eu", "customer_id": 42_i64 } } }
+    /// );
     /// ```
-    pub fn gt<F, V>(&mut self, value: V) -> &mut Self
+    pub fn eq_embedded<F: FieldName>(&mut self, doc: bson::Document) -> &mut Self
     where
-        F: FieldName,
-        T: HasField<F> + MongoComparable<T::Value, V> + MongoOrdered<T::Value, V>,
-        V: Into<bson::Bson> + Clone,
+        T: HasField<F>,
     {
         let path = self.field_path::<F>();
 
-        self.clauses
-            .push(bson::doc! { path: { "$gt": value.into() } });
+        self.clauses.push(bson::doc! { path: { "$eq": doc } });
 
         self
     }
 
-    /// Creates a type-safe version of MongoDB's less (`$lt`) than filter.
+    /// Creates an equality filter between an embedded document field and a value of its type,
+    /// serialized with serde (as `eq_serialized`).
+    ///
+    /// Unlike `eq_embedded` and `eq_serialized`, the value must have the field type, which is
+    /// verified through the `MongoComparable` evidence the derive generates for each field type
+    /// (including the embedded structs). The serialized document is wrapped with `$eq`, as
+    /// with `eq_embedded`, so it must match the embedded document exactly (fields order included).
     ///
     /// # Type parameters:
-    /// * `F` - The field name marker type (e.g., `product_fields::Stock`)
-    /// * `V` - The type of the field value or a compatible type
+    /// * `F` - The field name marker type (e.g., `user_fields::HomeAddress`)
     ///
-    /// The comparison requires both `MongoComparable` and `MongoOrdered`
-    /// evidence for the field/value type pair.
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be serialized to BSON.
     ///
     /// # Example
     ///
     /// ```rust
     /// use tnuctipun::filters::empty;
     /// use tnuctipun::{FieldWitnesses, MongoComparable};
-    /// use serde::{Serialize, Deserialize};
+    /// use serde::Serialize;
     ///
-    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// struct Product { pub Stock: i32 }
+    /// #[derive(Serialize, FieldWitnesses, MongoComparable)]
+    /// struct Address {
+    ///     pub city: String,
+    ///     pub zip_code: String,
+    /// }
     ///
-    /// // Filter for products with stock < 10
-    /// empty::<Product>().lt::<product_fields::Stock, _>(10);
-    /// // Resulting BSON: { "Stock": { "$lt": 10 } }
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User {
+    ///     pub home_address: Address,
+    /// }
+    ///
+    /// let address = Address { city: "Paris".to_string(), zip_code: "75001".to_string() };
+    ///
+    /// let filter = empty::<User>()
+    ///     .eq_embedded_value::<user_fields::HomeAddress>(&address)
+    ///     .unwrap()
+    ///     .and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! { "home_address": { "$eq": { "city": "Paris", "zip_code": "75001" } } }
+    /// );
     /// ```
-    pub fn lt<F, V>(&mut self, value: V) -> &mut Self
+    pub fn eq_embedded_value<F: FieldName>(
+        &mut self,
+        value: &T::Value,
+    ) -> bson::error::Result<&mut Self>
     where
-        F: FieldName,
-        T: HasField<F> + MongoComparable<T::Value, V> + MongoOrdered<T::Value, V>,
-        V: Into<bson::Bson> + Clone,
+        T: HasField<F> + MongoComparable<T::Value, T::Value>,
+        T::Value: serde::Serialize,
     {
         let path = self.field_path::<F>();
+        let value = bson::serialize_to_bson(value)?;
 
-        self.clauses
-            .push(bson::doc! { path: { "$lt": value.into() } });
+        self.clauses.push(bson::doc! { path: { "$eq": value } });
 
-        self
+        Ok(self)
     }
 
-    /// Type-safe version of "in" operator filter
+    /// Creates a filter matching documents whose array field contains the given value.
     ///
-    /// Creates a MongoDB filter that matches any of the values in the provided array.
+    /// MongoDB equality on an array field already matches if any element equals the value,
+    /// so this emits the same `{ path: value }` clause as `eq`; it only makes the
+    /// array-contains intent explicit and is restricted to collection fields.
+    /// See `array_eq` to match the whole array instead.
     ///
     /// # Type parameters:
-    /// * `F` - The field name marker type (e.g., `user_fields::Age`)
-    /// * `V` - The type of the field value or a compatible type
+    /// * `F` - The field name marker type for an array field (e.g., `product_fields::Tags`)
+    /// * `V` - The type of the array elements or a compatible type
     ///
     /// # Example
     ///
     /// ```rust
-    /// use tnuctipun::filters::empty;
     /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use tnuctipun::filters::empty;
     /// use serde::{Serialize, Deserialize};
     ///
     /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// struct User { pub Age: i32 }
+    /// struct Product { pub name: String, pub tags: Vec<String> }
     ///
-    /// // Filter for users with age in [20, 30, 40]
-    /// empty::<User>().r#in::<user_fields::Age, _>(vec![20, 30, 40]);
-    /// // Resulting BSON: { "Age": { "$in": [20, 30, 40] } }
+    /// empty::<Product>().array_contains::<product_fields::Tags, _>("sale".to_string());
+    /// // Resulting BSON: { "tags": "sale" }
     /// ```
-    pub fn r#in<F, V>(&mut self, values: Vec<V>) -> &mut Self
+    ///
+    /// It cannot be used on scalar fields:
+    ///
+    /// ```compile_fail
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use tnuctipun::filters::empty;
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Product { pub name: String }
+    ///
+    /// empty::<Product>().array_contains::<product_fields::Name, _>("Laptop".to_string());
+    /// ```
+    pub fn array_contains<F, V>(&mut self, value: V) -> &mut Self
     where
         F: FieldName,
         T: HasField<F> + MongoComparable<T::Value, V>,
+        T::Value: IntoIterator,
         V: Into<bson::Bson> + Clone,
     {
         let path = self.field_path::<F>();
-        let bson_values: Vec<bson::Bson> = values.into_iter().map(|v| v.into()).collect();
 
-        self.clauses
-            .push(bson::doc! { path: { "$in": bson_values } });
+        self.clauses.push(bson::doc! { path: value.into() });
 
         self
     }
 
-    /// Creates a type-safe version of MongoDB's "not equal" (`$ne`) filter.
+    /// Creates a filter matching documents whose array field is exactly the given array.
+    ///
+    /// MongoDB compares the whole array, so the elements must be the same and in the same
+    /// order (`["a", "b"]` doesn't match `["b", "a"]` nor `["a", "b", "c"]`). See
+    /// `array_contains` to match a single element instead.
     ///
     /// # Type parameters:
-    /// * `F` - The field name marker type (e.g., `order_fields::Status`)
-    /// * `V` - The type of the field value or a compatible type
+    /// * `F` - The field name marker type for an array field (e.g., `product_fields::Tags`)
+    /// * `V` - The type of the array elements
     ///
     /// # Example
     ///
     /// ```rust
-    /// use tnuctipun::filters::empty;
     /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use tnuctipun::filters::empty;
     /// use serde::{Serialize, Deserialize};
     ///
     /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// struct Order { pub Status: String }
+    /// struct Product { pub name: String, pub tags: Vec<String> }
     ///
-    /// // Filter for orders with status not equal to "Delivered"
-    /// empty::<Order>().ne::<order_fields::Status, _>("Delivered".to_string());
-    /// // Resulting BSON: { "Status": { "$ne": "Delivered" } }
+    /// empty::<Product>().array_eq::<product_fields::Tags, _>(vec!["sale".to_string()]);
+    /// // Resulting BSON: { "tags": ["sale"] }
     /// ```
-    pub fn ne<F, V>(&mut self, value: V) -> &mut Self
+    pub fn array_eq<F, V>(&mut self, values: Vec<V>) -> &mut Self
     where
         F: FieldName,
-        T: HasField<F> + MongoComparable<T::Value, V>,
-        V: Into<bson::Bson> + Clone,
+        T: HasField<F> + MongoComparable<T::Value, Vec<V>>,
+        T::Value: IntoIterator,
+        V: Into<bson::Bson>,
     {
         let path = self.field_path::<F>();
+        let values: Vec<bson::Bson> = values.into_iter().map(Into::into).collect();
 
-        self.clauses
-            .push(bson::doc! { path: { "$ne": value.into() } });
+        self.clauses.push(bson::doc! { path: values });
 
         self
     }
 
-    /// Creates a type-safe version of MongoDB's "greater than or equal" (`$gte`) filter.
+    /// Creates a filter matching documents whose array field has at least one element
+    /// satisfying all the given operations (`$elemMatch`).
     ///
-    /// # Type parameters:
-    /// * `F` - The field name marker type (e.g., `product_fields::Rating`)
-    /// * `V` - The type of the field value or a compatible type
+    /// Unlike the operations directly on the array field, which can each be satisfied by a
+    /// different element, the `$elemMatch` operations must all match the same element
+    /// (e.g. a score that is both `>= 80` and `< 90`). Use `not` with
+    /// `FieldFilterBuilder::elem_match` to match the arrays where no element matches.
     ///
-    /// The comparison requires both `MongoComparable` and `MongoOrdered`
-    /// evidence for the field/value type pair.
+    /// # Type parameters:
+    /// * `F` - The field name marker type for an array field (e.g., `student_fields::Scores`)
     ///
     /// # Example
     ///
     /// ```rust
-    /// use tnuctipun::filters::empty;
     /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use tnuctipun::filters::empty;
     /// use serde::{Serialize, Deserialize};
     ///
     /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// struct Product { pub Rating: f64 }
+    /// struct Student { pub scores: Vec<i32> }
     ///
-    /// // Filter for products with rating >= 4.5
-    /// empty::<Product>().gte::<product_fields::Rating, _>(4.5);
-    /// // Resulting BSON: { "Rating": { "$gte": 4.5 } }
+    /// empty::<Student>().elem_match::<student_fields::Scores, _>(|elem| elem.gte(80).lt(90));
+    /// // Resulting BSON: { "scores": { "$elemMatch": { "$gte": 80, "$lt": 90 } } }
     /// ```
-    pub fn gte<F, V>(&mut self, value: V) -> &mut Self
+    pub fn elem_match<F, B>(&mut self, f: B) -> &mut Self
     where
         F: FieldName,
-        T: HasField<F> + MongoComparable<T::Value, V> + MongoOrdered<T::Value, V>,
-        V: Into<bson::Bson> + Clone,
+        T: HasField<F>,
+        T::Value: IntoIterator,
+        B: FnOnce(FieldFilterBuilder<F, T>) -> FieldFilterBuilder<F, T>,
     {
-        let path = self.field_path::<F>();
+        let (operations, _) = FieldFilterBuilder::new().elem_match(f).into_parts();
 
-        self.clauses
-            .push(bson::doc! { path: { "$gte": value.into() } });
+        if let Some(operations) = operations {
+            let path = self.field_path::<F>();
+
+            self.clauses.push(bson::doc! { path: operations });
+        }
 
         self
     }
 
-    /// Creates a type-safe version of MongoDB's "less than or equal" (`$lte`) filter.
+    /// Creates a filter matching documents whose array of documents has, for each condition,
+    /// an element satisfying it (`$all` of `$elemMatch`).
+    ///
+    /// Each condition configures a builder on the element type `U`, whose clauses must all
+    /// match the same element (combined as `and_flat()` does), while distinct conditions
+    /// can be satisfied by distinct elements. The conditions without any clause would match
+    /// any element, so they are skipped, and no clause is added if all of them are empty.
+    ///
+    /// As all the elements of a `Vec` have the same type, distinct closures must be boxed
+    /// (e.g. as `Box<dyn FnOnce(&mut FilterBuilder<U>)>`).
     ///
     /// # Type parameters:
-    /// * `F` - The field name marker type (e.g., `product_fields::Price`)
-    /// * `V` - The type of the field value or a compatible type
+    /// * `F` - The field name marker type for an array field (e.g., `order_fields::Lines`)
+    /// * `U` - The type of the array elements
     ///
-    /// The comparison requires both `MongoComparable` and `MongoOrdered`
-    /// evidence for the field/value type pair.
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::{FilterBuilder, empty};
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Line {
+    ///     pub sku: String,
+    ///     pub quantity: i32,
+    /// }
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Order {
+    ///     pub lines: Vec<Line>,
+    /// }
+    ///
+    /// let conditions: Vec<Box<dyn FnOnce(&mut FilterBuilder<Line>)>> = vec![
+    ///     Box::new(|line| {
+    ///         line.eq::<line_fields::Sku, _>("pen").gt::<line_fields::Quantity, _>(10);
+    ///     }),
+    ///     Box::new(|line| {
+    ///         line.eq::<line_fields::Sku, _>("ink");
+    ///     }),
+    /// ];
+    ///
+    /// let filter = empty::<Order>()
+    ///     .all_elem_match::<order_fields::Lines, Line, _>(conditions)
+    ///     .and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! {
+    ///         "lines": {
+    ///             "$all": [
+    ///                 { "$elemMatch": { "sku": "pen", "quantity": { "$gt": 10 } } },
+    ///                 { "$elemMatch": { "sku": "ink" } }
+    ///             ]
+    ///         }
+    ///     }
+    /// );
+    /// ```
+    pub fn all_elem_match<F, U, B>(&mut self, conditions: Vec<B>) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F>,
+        T::Value: IntoIterator<Item = U>,
+        B: FnOnce(&mut FilterBuilder<U>),
+    {
+        let elem_matches: Vec<bson::Document> = conditions
+            .into_iter()
+            .filter_map(|condition| {
+                let mut nested = empty::<U>();
+
+                condition(&mut nested);
+
+                (!nested.clauses.is_empty()).then(|| bson::doc! { "$elemMatch": nested.and_flat() })
+            })
+            .collect();
+
+        if !elem_matches.is_empty() {
+            let path = self.field_path::<F>();
+
+            self.clauses
+                .push(bson::doc! { path: { "$all": elem_matches } });
+        }
+
+        self
+    }
+
+    /// Creates a type-safe version of MongoDB's regular expression (`$regex`) filter.
+    ///
+    /// This method allows you to filter documents based on pattern matching using regular expressions.
+    /// It's particularly useful for text searches, partial matches, and complex string pattern filtering.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `user_fields::Name`)
+    ///
+    /// # Arguments
+    /// * `expr` - The regular expression pattern as a string
+    /// * `options` - Optional regex options (None for no options, Some("i") for case-insensitive, etc.)
+    ///
+    /// # MongoDB Regex Behavior
+    ///
+    /// The `$regex` operator provides regular expression capabilities for pattern matching strings in queries.
+    /// MongoDB uses Perl Compatible Regular Expressions (PCRE) version 8.42 with UTF-8 support.
+    ///
+    /// # Common Use Cases
+    ///
+    /// - **Case-insensitive search**: Use regex options like `Some("i")`
+    /// - **Partial matches**: Search for substrings within field values
+    /// - **Pattern validation**: Match specific formats (emails, phone numbers, etc.)
+    /// - **Wildcard searches**: Use `.*` for flexible matching
     ///
     /// # Example
     ///
@@ -403,73 +575,141 @@ impl<T> FilterBuilder<T> {
     /// use serde::{Serialize, Deserialize};
     ///
     /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// struct Product { pub Price: f64 }
+    /// struct User {
+    ///     pub name: String,
+    ///     pub email: String,
+    /// }
     ///
-    /// // Filter for products with price <= 100.0
-    /// empty::<Product>().lte::<product_fields::Price, _>(100.0);
-    /// // Resulting BSON: { "Price": { "$lte": 100.0 } }
+    /// // Case-insensitive search for names containing "john"
+    /// empty::<User>().regex::<user_fields::Name>("john", Some("i"));
+    /// // Resulting BSON: { "name": { "$regex": "john", "$options": "i" } }
+    ///
+    /// // Email validation pattern (no options)
+    /// empty::<User>().regex::<user_fields::Email>(r"^[\w\.-]+@[\w\.-]+\.\w+$", None);
+    /// // Resulting BSON: { "email": { "$regex": "^[\\w\\.-]+@[\\w\\.-]+\\.\\w+$" } }
+    ///
+    /// // Partial match for product names starting with "Laptop"
+    /// empty::<User>().regex::<user_fields::Name>("^Laptop.*", None);
+    /// // Resulting BSON: { "name": { "$regex": "^Laptop.*" } }
     /// ```
-    pub fn lte<F, V>(&mut self, value: V) -> &mut Self
+    ///
+    /// # Advanced Regex Options
+    ///
+    /// For more complex regex queries with options, use the `Some()` wrapper:
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// # use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// # use serde::{Serialize, Deserialize};
+    /// # #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// # struct User { pub name: String }
+    ///
+    /// // Case-insensitive and multiline options
+    /// empty::<User>().regex::<user_fields::Name>("pattern", Some("im"));
+    /// // Resulting BSON: { "name": { "$regex": "pattern", "$options": "im" } }
+    /// ```
+    ///
+    /// # Common Regex Patterns
+    ///
+    /// | Pattern | Description | Example |
+    /// |---------|-------------|---------|
+    /// | `^text` | Starts with "text" | `^Hello` matches "Hello World" |
+    /// | `text$` | Ends with "text" | `World$` matches "Hello World" |
+    /// | `.*text.*` | Contains "text" | `.*cat.*` matches "concatenate" |
+    /// | `(?i)text` | Case-insensitive (inline) | `(?i)hello` matches "HELLO" |
+    /// | `\d+` | One or more digits | `\d+` matches "123" |
+    /// | `[a-zA-Z]+` | Letters only | Matches alphabetic strings |
+    ///
+    /// # Regex Options
+    ///
+    /// MongoDB supports these regex options (passed as Some("options")):
+    /// - `i` - Case insensitive matching
+    /// - `m` - Multiline mode (^ and $ match line boundaries)
+    /// - `x` - Extended mode (ignore whitespace and allow comments)
+    /// - `s` - Dotall mode (. matches newlines)
+    ///
+    /// Options can be combined: `Some("im")` for case-insensitive multiline.
+    ///
+    /// # Type Safety
+    ///
+    /// This method ensures compile-time verification that:
+    /// - The field `F` exists in the struct `T`
+    /// - The field is compatible with regex operations (typically String fields)
+    pub fn regex<F: FieldName>(&mut self, expr: &str, options: Option<&str>) -> &mut Self
     where
-        F: FieldName,
-        T: HasField<F> + MongoComparable<T::Value, V> + MongoOrdered<T::Value, V>,
-        V: Into<bson::Bson> + Clone,
+        T: HasField<F>,
     {
         let path = self.field_path::<F>();
+        let mut regex_doc = bson::doc! { "$regex": expr };
 
-        self.clauses
-            .push(bson::doc! { path: { "$lte": value.into() } });
+        if let Some(opts) = options
+            && !opts.is_empty()
+        {
+            regex_doc.insert("$options", opts);
+        }
+
+        self.clauses.push(bson::doc! { path: regex_doc });
 
         self
     }
 
-    /// Creates a type-safe version of "exists" filter, that checks if a field exists in the document.
+    /// Creates a `$regex` filter matching the string values starting with the given prefix.
+    ///
+    /// The regex metacharacters of the prefix are escaped, so it's matched literally.
+    /// As the pattern is anchored and case-sensitive, MongoDB can use an index on the field.
     ///
     /// # Type parameters:
-    /// * `F` - The field name marker type (e.g., `user_fields::OptionalField`)
-    ///   (must implement `HasField<F>` to ensure compile-time field existence)
+    /// * `F` - The field name marker type (e.g., `user_fields::Name`)
+    ///
+    /// The field must be comparable with `bson::Regex`, which the `MongoComparable` derive
+    /// provides for `String` and `Option<String>` fields.
     ///
     /// # Example
     ///
     /// ```rust
     /// use tnuctipun::filters::empty;
-    /// use tnuctipun::FieldWitnesses;
-    /// use serde::{Serialize, Deserialize};
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
     ///
-    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses)]
+    /// #[derive(Debug, Clone, FieldWitnesses, MongoComparable)]
     /// struct User {
-    ///     pub Name: String,
-    ///     pub PhoneNumber: Option<String>
+    ///     pub name: String,
+    ///     pub nickname: Option<String>,
     /// }
     ///
-    /// // Filter for users that have a phone number
-    /// empty::<User>().exists::<user_fields::PhoneNumber>(true);
-    /// // Resulting BSON: { "PhoneNumber": { "$exists": true } }
+    /// let filter = empty::<User>()
+    ///     .starts_with::<user_fields::Nickname>("J.")
+    ///     .and();
     ///
-    /// // Filter for users without a phone number
-    /// empty::<User>().exists::<user_fields::PhoneNumber>(false);
-    /// // Resulting BSON: { "PhoneNumber": { "$exists": false } }
+    /// assert_eq!(filter, bson::doc! { "nickname": { "$regex": "^J\\." } });
+    /// // Resulting BSON: { "nickname": { "$regex": "^J\\." } }
     /// ```
-    pub fn exists<F>(&mut self, exists: bool) -> &mut Self
+    pub fn starts_with<F: FieldName>(&mut self, prefix: &str) -> &mut Self
     where
-        F: FieldName,
-        T: HasField<F>,
+        T: HasField<F> + MongoComparable<T::Value, bson::Regex>,
     {
         let path = self.field_path::<F>();
+        let pattern = format!("^{}", escape_regex(prefix));
 
         self.clauses
-            .push(bson::doc! { path: { "$exists": exists } });
+            .push(bson::doc! { path: { "$regex": pattern } });
 
         self
     }
 
-    /// Creates a type-safe version of MongoDB's "not in" (`$nin`) operator filter,
-    /// that matches values NOT in the provided array.
+    /// Returns the current filter clauses as a vector of BSON documents.
+    pub fn clauses(&self) -> &Vec<bson::Document> {
+        &self.clauses
+    }
+
+    /// Creates a type-safe version of MongoDB's greater than (`$gt`) filter.
     ///
     /// # Type parameters:
-    /// * `F` - The field name marker type (e.g., `product_fields::Category`)
+    /// * `F` - The field name marker type (e.g., `product_fields::Price`)
     /// * `V` - The type of the field value or a compatible type
     ///
+    /// The comparison requires both `MongoComparable` and `MongoOrdered`
+    /// evidence for the field/value type pair.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -478,58 +718,73 @@ impl<T> FilterBuilder<T> {
     /// use serde::{Serialize, Deserialize};
     ///
     /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// struct Product { pub Category: String }
+    /// struct Product { pub Price: f64 }
     ///
-    /// // Filter for products NOT in the categories "Clothing", "Shoes", or "Accessories"
-    /// empty::<Product>().nin::<product_fields::Category, _>(vec![
-    ///     "Clothing".to_string(),
-    ///     "Shoes".to_string(),
-    ///     "Accessories".to_string()
-    /// ]);
-    /// // Resulting BSON: { "Category": { "$nin": ["Clothing", "Shoes", "Accessories"] } }
+    /// // Filter for products with price > 500
+    /// empty::<Product>().gt::<product_fields::Price, _>(500.0);
+    /// // Resulting BSON: { "Price": { "$gt": 500.0 } }
     /// ```
-    pub fn nin<F, V>(&mut self, values: Vec<V>) -> &mut Self
+    pub fn gt<F, V>(&mut self, value: V) -> &mut Self
     where
         F: FieldName,
-        T: HasField<F> + MongoComparable<T::Value, V>,
+        T: HasField<F> + MongoComparable<T::Value, V> + MongoOrdered<T::Value, V>,
         V: Into<bson::Bson> + Clone,
     {
         let path = self.field_path::<F>();
-        let bson_values: Vec<bson::Bson> = values.into_iter().map(|v| v.into()).collect();
 
         self.clauses
-            .push(bson::doc! { path: { "$nin": bson_values } });
+            .push(bson::doc! { path: { "$gt": value.into() } });
 
         self
     }
 
-    /// Creates an untyped filter for a field using a raw BSON document.
-    ///
-    /// This method allows you to partially bypass the type safety mechanisms for the sake of flexibility,
-    /// and directly insert a BSON document as the filter condition for a specific field. While this provides
-    /// maximum flexibility for complex queries, it should be used with caution as it
-    /// sacrifices the compile-time type checking that other methods provide.
+    /// Creates a type-safe version of MongoDB's less (`$lt`) than filter.
     ///
     /// # Type parameters:
-    /// * `F` - The field name marker type (e.g., `product_fields::Price`)
+    /// * `F` - The field name marker type (e.g., `product_fields::Stock`)
+    /// * `V` - The type of the field value or a compatible type
     ///
-    /// # Arguments
-    /// * `value` - A BSON document containing the MongoDB operators and values to apply to the field
+    /// The comparison requires both `MongoComparable` and `MongoOrdered`
+    /// evidence for the field/value type pair.
     ///
-    /// # Use Cases
+    /// # Example
     ///
-    /// This method is particularly useful for:
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use serde::{Serialize, Deserialize};
     ///
-    /// - Advanced MongoDB operators not yet covered by typed methods
-    /// - Complex nested conditions that require manual BSON construction
-    /// - Migration scenarios where you need to use existing raw query fragments
-    /// - Testing and experimentation with MongoDB features
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct Product { pub Stock: i32 }
     ///
-    /// # Safety Considerations
+    /// // Filter for products with stock < 10
+    /// empty::<Product>().lt::<product_fields::Stock, _>(10);
+    /// // Resulting BSON: { "Stock": { "$lt": 10 } }
+    /// ```
+    pub fn lt<F, V>(&mut self, value: V) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F> + MongoComparable<T::Value, V> + MongoOrdered<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses
+            .push(bson::doc! { path: { "$lt": value.into() } });
+
+        self
+    }
+
+    /// Type-safe version of "in" operator filter
     ///
-    /// - No compile-time validation of field types or operator compatibility
-    /// - Potential runtime errors if the BSON structure is invalid
-    /// - Field existence is still verified at compile time through `HasField<F>`
+    /// Creates a MongoDB filter that matches any of the values in the provided array.
+    ///
+    /// Note that an empty `$in` array never matches any document; see `in_or_skip`
+    /// to add no clause at all when the values are empty.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `user_fields::Age`)
+    /// * `V` - The type of the field value or a compatible type
     ///
     /// # Example
     ///
@@ -537,16 +792,749 @@ impl<T> FilterBuilder<T> {
     /// use tnuctipun::filters::empty;
     /// use tnuctipun::{FieldWitnesses, MongoComparable};
     /// use serde::{Serialize, Deserialize};
-    /// use bson::{doc, Document};
     ///
     /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// struct Product {
-    ///     pub name: String,
-    ///     pub price: f64,
-    ///     pub tags: Vec<String>,
-    /// }
+    /// struct User { pub Age: i32 }
     ///
-    /// // Using advanced text search operators
+    /// // Filter for users with age in [20, 30, 40]
+    /// empty::<User>().r#in::<user_fields::Age, _>(vec![20, 30, 40]);
+    /// // Resulting BSON: { "Age": { "$in": [20, 30, 40] } }
+    /// ```
+    pub fn r#in<F, V>(&mut self, values: Vec<V>) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F> + MongoComparable<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        let path = self.field_path::<F>();
+        let bson_values: Vec<bson::Bson> = values.into_iter().map(|v| v.into()).collect();
+
+        self.clauses
+            .push(bson::doc! { path: { "$in": bson_values } });
+
+        self
+    }
+
+    /// Creates a filter matching the documents whose field equals any of the given values.
+    ///
+    /// This is an alias of `r#in`, reading as the same-field alternatives it expresses
+    /// (`$in`, which MongoDB prefers over a `$or` of equalities, see `or_values`).
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Category`)
+    /// * `V` - The type of the field value or a compatible type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Product { pub category: String }
+    ///
+    /// let filter = empty::<Product>()
+    ///     .eq_any::<product_fields::Category, _>(vec!["books", "music"])
+    ///     .and();
+    ///
+    /// assert_eq!(filter, bson::doc! { "category": { "$in": ["books", "music"] } });
+    /// ```
+    pub fn eq_any<F, V>(&mut self, values: Vec<V>) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F> + MongoComparable<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        self.r#in::<F, V>(values)
+    }
+
+    /// Creates a `$in` filter from any serde-serializable values (e.g. embedded documents).
+    ///
+    /// This is the `$in` counterpart of `eq_serialized`: each value is converted with
+    /// `bson::serialize_to_bson` (so its serde attributes are honored), which allows matching
+    /// an embedded document field against several sub-documents. As the values are type-erased
+    /// by the serialization, no `MongoComparable` evidence is required.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `user_fields::HomeAddress`)
+    /// * `V` - A value type that implements `serde::Serialize`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if some value cannot be serialized to BSON.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::FieldWitnesses;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Point {
+    ///     pub x: i32,
+    ///     pub y: i32,
+    /// }
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Shape {
+    ///     pub origin: Point,
+    /// }
+    ///
+    /// let filter = empty::<Shape>()
+    ///     .in_serialized::<shape_fields::Origin, _>(&[Point { x: 0, y: 0 }, Point { x: 1, y: 1 }])
+    ///     .unwrap()
+    ///     .and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! { "origin": { "$in": [{ "x": 0, "y": 0 }, { "x": 1, "y": 1 }] } }
+    /// );
+    /// ```
+    pub fn in_serialized<F, V>(&mut self, values: &[V]) -> bson::error::Result<&mut Self>
+    where
+        F: FieldName,
+        T: HasField<F>,
+        V: serde::Serialize,
+    {
+        let path = self.field_path::<F>();
+        let bson_values = values
+            .iter()
+            .map(bson::serialize_to_bson)
+            .collect::<bson::error::Result<Vec<_>>>()?;
+
+        self.clauses
+            .push(bson::doc! { path: { "$in": bson_values } });
+
+        Ok(self)
+    }
+
+    /// Same as `r#in`, except that no clause is added if the values are empty.
+    ///
+    /// This avoids an empty `$in` (that never matches) when the values are computed
+    /// at runtime, making the filter unrestricted on this field instead.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `user_fields::Age`)
+    /// * `V` - The type of the field value or a compatible type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User { pub Age: i32 }
+    ///
+    /// let filter = empty::<User>().in_or_skip::<user_fields::Age, i32>(vec![]).and();
+    /// // Resulting BSON: {}
+    /// assert!(filter.is_empty());
+    /// ```
+    pub fn in_or_skip<F, V>(&mut self, values: Vec<V>) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F> + MongoComparable<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        if values.is_empty() {
+            self
+        } else {
+            self.r#in::<F, V>(values)
+        }
+    }
+
+    /// Creates a type-safe version of MongoDB's "not equal" (`$ne`) filter.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `order_fields::Status`)
+    /// * `V` - The type of the field value or a compatible type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct Order { pub Status: String }
+    ///
+    /// // Filter for orders with status not equal to "Delivered"
+    /// empty::<Order>().ne::<order_fields::Status, _>("Delivered".to_string());
+    /// // Resulting BSON: { "Status": { "$ne": "Delivered" } }
+    /// ```
+    pub fn ne<F, V>(&mut self, value: V) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F> + MongoComparable<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses
+            .push(bson::doc! { path: { "$ne": value.into() } });
+
+        self
+    }
+
+    /// Creates a type-safe version of MongoDB's "greater than or equal" (`$gte`) filter.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Rating`)
+    /// * `V` - The type of the field value or a compatible type
+    ///
+    /// The comparison requires both `MongoComparable` and `MongoOrdered`
+    /// evidence for the field/value type pair.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct Product { pub Rating: f64 }
+    ///
+    /// // Filter for products with rating >= 4.5
+    /// empty::<Product>().gte::<product_fields::Rating, _>(4.5);
+    /// // Resulting BSON: { "Rating": { "$gte": 4.5 } }
+    /// ```
+    pub fn gte<F, V>(&mut self, value: V) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F> + MongoComparable<T::Value, V> + MongoOrdered<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses
+            .push(bson::doc! { path: { "$gte": value.into() } });
+
+        self
+    }
+
+    /// Creates a type-safe version of MongoDB's "less than or equal" (`$lte`) filter.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Price`)
+    /// * `V` - The type of the field value or a compatible type
+    ///
+    /// The comparison requires both `MongoComparable` and `MongoOrdered`
+    /// evidence for the field/value type pair.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct Product { pub Price: f64 }
+    ///
+    /// // Filter for products with price <= 100.0
+    /// empty::<Product>().lte::<product_fields::Price, _>(100.0);
+    /// // Resulting BSON: { "Price": { "$lte": 100.0 } }
+    /// ```
+    pub fn lte<F, V>(&mut self, value: V) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F> + MongoComparable<T::Value, V> + MongoOrdered<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses
+            .push(bson::doc! { path: { "$lte": value.into() } });
+
+        self
+    }
+
+    /// Creates a date range filter, matching a date field within `[from, to]` (inclusive).
+    ///
+    /// The bounds are converted to BSON dates (millisecond precision) and emitted together
+    /// as `{ path: { "$gte": from, "$lte": to } }`, the most common analytics query shape.
+    ///
+    /// The field is typically a `bson::DateTime`: a `chrono::DateTime` field only matches if
+    /// it's stored as a BSON date (e.g. with a bson serde helper), as serde stores it as a string.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type for a date field (e.g., `event_fields::CreatedAt`)
+    ///
+    /// # Arguments
+    /// * `from` - The inclusive lower bound
+    /// * `to` - The inclusive upper bound
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Event { pub created_at: bson::DateTime }
+    ///
+    /// let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    /// let to = Utc.with_ymd_and_hms(2024, 12, 31, 23, 59, 59).unwrap();
+    ///
+    /// empty::<Event>().between_dates::<event_fields::CreatedAt>(from, to);
+    /// // Resulting BSON: { "created_at": { "$gte": ISODate("2024-01-01..."), "$lte": ISODate("2024-12-31...") } }
+    /// ```
+    pub fn between_dates<F>(&mut self, from: DateTime<Utc>, to: DateTime<Utc>) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F>
+            + MongoComparable<T::Value, DateTime<Utc>>
+            + MongoOrdered<T::Value, DateTime<Utc>>,
+    {
+        let path = self.field_path::<F>();
+        let from = bson::DateTime::from_millis(from.timestamp_millis());
+        let to = bson::DateTime::from_millis(to.timestamp_millis());
+
+        self.clauses
+            .push(bson::doc! { path: { "$gte": from, "$lte": to } });
+
+        self
+    }
+
+    /// Creates a type-safe version of "exists" filter, that checks if a field exists in the document.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `user_fields::OptionalField`)
+    ///   (must implement `HasField<F>` to ensure compile-time field existence)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::FieldWitnesses;
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses)]
+    /// struct User {
+    ///     pub Name: String,
+    ///     pub PhoneNumber: Option<String>
+    /// }
+    ///
+    /// // Filter for users that have a phone number
+    /// empty::<User>().exists::<user_fields::PhoneNumber>(true);
+    /// // Resulting BSON: { "PhoneNumber": { "$exists": true } }
+    ///
+    /// // Filter for users without a phone number
+    /// empty::<User>().exists::<user_fields::PhoneNumber>(false);
+    /// // Resulting BSON: { "PhoneNumber": { "$exists": false } }
+    /// ```
+    pub fn exists<F>(&mut self, exists: bool) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F>,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses
+            .push(bson::doc! { path: { "$exists": exists } });
+
+        self
+    }
+
+    /// Creates a filter matching the documents where a field is missing or equal to a value.
+    ///
+    /// This is the usual query for an optional field having a default value (e.g. the
+    /// documents stored before the field was added), composed as a single `$or` clause.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `user_fields::Role`)
+    /// * `V` - The type of the field value or a compatible type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User {
+    ///     pub role: Option<String>,
+    /// }
+    ///
+    /// let filter = empty::<User>()
+    ///     .missing_or_eq::<user_fields::Role, _>("member")
+    ///     .and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! { "$or": [{ "role": { "$exists": false } }, { "role": "member" }] }
+    /// );
+    /// ```
+    pub fn missing_or_eq<F, V>(&mut self, value: V) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F> + MongoComparable<T::Value, V>,
+        V: Into<bson::Bson>,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses.push(bson::doc! {
+            "$or": [
+                { path.clone(): { "$exists": false } },
+                { path: value.into() }
+            ]
+        });
+
+        self
+    }
+
+    /// Creates a filter matching the documents where a field is null **or missing**.
+    ///
+    /// MongoDB equality with `null` also matches the documents not having the field
+    /// at all, which is usually what is expected for an `Option` field (as `None` can be
+    /// either serialized as `null` or skipped). Use `is_exactly_null` to only match
+    /// the fields explicitly set to `null`.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `user_fields::PhoneNumber`)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::FieldWitnesses;
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub phone_number: Option<String>,
+    /// }
+    ///
+    /// let filter = empty::<User>().is_null::<user_fields::PhoneNumber>().and();
+    ///
+    /// assert_eq!(filter, bson::doc! { "phone_number": null });
+    /// // Matches { "phone_number": null } and {}
+    /// ```
+    pub fn is_null<F>(&mut self) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F>,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses.push(bson::doc! { path: bson::Bson::Null });
+
+        self
+    }
+
+    /// Creates a filter matching the documents where a field is explicitly set to null.
+    ///
+    /// Unlike `is_null`, the documents not having the field are not matched,
+    /// as the BSON type of the field is checked (`$type: "null"`).
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `user_fields::PhoneNumber`)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::FieldWitnesses;
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub phone_number: Option<String>,
+    /// }
+    ///
+    /// let filter = empty::<User>().is_exactly_null::<user_fields::PhoneNumber>().and();
+    ///
+    /// assert_eq!(filter, bson::doc! { "phone_number": { "$type": "null" } });
+    /// // Matches { "phone_number": null }, but not {}
+    /// ```
+    pub fn is_exactly_null<F>(&mut self) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F>,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses.push(bson::doc! { path: { "$type": "null" } });
+
+        self
+    }
+
+    /// Creates a filter matching the documents where a field exists and is an array
+    /// (`{ "$exists": true, "$type": "array" }`).
+    ///
+    /// It reads as a data-quality check (e.g. on the documents written by a legacy schema),
+    /// without spelling the `$type` alias; see also `is_string` and `is_number`.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Tags`)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::FieldWitnesses;
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Product {
+    ///     pub tags: Vec<String>,
+    /// }
+    ///
+    /// let filter = empty::<Product>().is_array::<product_fields::Tags>().and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! { "tags": { "$exists": true, "$type": "array" } }
+    /// );
+    /// ```
+    pub fn is_array<F>(&mut self) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F>,
+    {
+        self.exists_with_type::<F>("array")
+    }
+
+    /// Creates a filter matching the documents where a field exists and is a string
+    /// (`{ "$exists": true, "$type": "string" }`).
+    ///
+    /// This is a data-quality check, as `is_array`.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Name`)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::FieldWitnesses;
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Product {
+    ///     pub name: String,
+    /// }
+    ///
+    /// let filter = empty::<Product>().is_string::<product_fields::Name>().and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! { "name": { "$exists": true, "$type": "string" } }
+    /// );
+    /// ```
+    pub fn is_string<F>(&mut self) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F>,
+    {
+        self.exists_with_type::<F>("string")
+    }
+
+    /// Creates a filter matching the documents where a field exists and is a number
+    /// (`{ "$exists": true, "$type": "number" }`).
+    ///
+    /// The `number` alias matches all the numeric BSON types (double, 32-bit and 64-bit
+    /// integers, decimal), so it's a data-quality check whatever the numeric storage.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Price`)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::FieldWitnesses;
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Product {
+    ///     pub price: f64,
+    /// }
+    ///
+    /// let filter = empty::<Product>().is_number::<product_fields::Price>().and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! { "price": { "$exists": true, "$type": "number" } }
+    /// );
+    /// ```
+    pub fn is_number<F>(&mut self) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F>,
+    {
+        self.exists_with_type::<F>("number")
+    }
+
+    // Adds a `{ path: { "$exists": true, "$type": alias } }` clause
+    fn exists_with_type<F>(&mut self, type_alias: &str) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F>,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses
+            .push(bson::doc! { path: { "$exists": true, "$type": type_alias } });
+
+        self
+    }
+
+    /// Creates a type-safe version of MongoDB's "not in" (`$nin`) operator filter,
+    /// that matches values NOT in the provided array.
+    ///
+    /// Note that an empty `$nin` array matches every document; see `nin_or_skip`
+    /// to make it explicit that no clause is added when the values are empty.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Category`)
+    /// * `V` - The type of the field value or a compatible type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct Product { pub Category: String }
+    ///
+    /// // Filter for products NOT in the categories "Clothing", "Shoes", or "Accessories"
+    /// empty::<Product>().nin::<product_fields::Category, _>(vec![
+    ///     "Clothing".to_string(),
+    ///     "Shoes".to_string(),
+    ///     "Accessories".to_string()
+    /// ]);
+    /// // Resulting BSON: { "Category": { "$nin": ["Clothing", "Shoes", "Accessories"] } }
+    /// ```
+    pub fn nin<F, V>(&mut self, values: Vec<V>) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F> + MongoComparable<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        let path = self.field_path::<F>();
+        let bson_values: Vec<bson::Bson> = values.into_iter().map(|v| v.into()).collect();
+
+        self.clauses
+            .push(bson::doc! { path: { "$nin": bson_values } });
+
+        self
+    }
+
+    /// Creates a filter matching the documents whose field differs from all the given values.
+    ///
+    /// This is an alias of `nin`, as the counterpart of `eq_any`.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Category`)
+    /// * `V` - The type of the field value or a compatible type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Product { pub category: String }
+    ///
+    /// let filter = empty::<Product>()
+    ///     .ne_all::<product_fields::Category, _>(vec!["books", "music"])
+    ///     .and();
+    ///
+    /// assert_eq!(filter, bson::doc! { "category": { "$nin": ["books", "music"] } });
+    /// ```
+    pub fn ne_all<F, V>(&mut self, values: Vec<V>) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F> + MongoComparable<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        self.nin::<F, V>(values)
+    }
+
+    /// Same as `nin`, except that no clause is added if the values are empty.
+    ///
+    /// An empty `$nin` already matches every document, so this only keeps the filter
+    /// free of a useless clause, consistently with `in_or_skip`.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Category`)
+    /// * `V` - The type of the field value or a compatible type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Product { pub Category: String }
+    ///
+    /// let filter = empty::<Product>()
+    ///     .nin_or_skip::<product_fields::Category, String>(vec![])
+    ///     .and();
+    /// // Resulting BSON: {}
+    /// assert!(filter.is_empty());
+    /// ```
+    pub fn nin_or_skip<F, V>(&mut self, values: Vec<V>) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F> + MongoComparable<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        if values.is_empty() {
+            self
+        } else {
+            self.nin::<F, V>(values)
+        }
+    }
+
+    /// Creates an untyped filter for a field using a raw BSON document.
+    ///
+    /// This method allows you to partially bypass the type safety mechanisms for the sake of flexibility,
+    /// and directly insert a BSON document as the filter condition for a specific field. While this provides
+    /// maximum flexibility for complex queries, it should be used with caution as it
+    /// sacrifices the compile-time type checking that other methods provide.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Price`)
+    ///
+    /// # Arguments
+    /// * `value` - A BSON document containing the MongoDB operators and values to apply to the field
+    ///
+    /// # Use Cases
+    ///
+    /// This method is particularly useful for:
+    ///
+    /// - Advanced MongoDB operators not yet covered by typed methods
+    /// - Complex nested conditions that require manual BSON construction
+    /// - Migration scenarios where you need to use existing raw query fragments
+    /// - Testing and experimentation with MongoDB features
+    ///
+    /// # Safety Considerations
+    ///
+    /// - No compile-time validation of field types or operator compatibility
+    /// - Potential runtime errors if the BSON structure is invalid
+    /// - Field existence is still verified at compile time through `HasField<F>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use serde::{Serialize, Deserialize};
+    /// use bson::{doc, Document};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct Product {
+    ///     pub name: String,
+    ///     pub price: f64,
+    ///     pub tags: Vec<String>,
+    /// }
+    ///
+    /// // Using advanced text search operators
     /// let text_search = doc! {
     ///     "$regex": "laptop",
     ///     "$options": "i"
@@ -554,148 +1542,1158 @@ impl<T> FilterBuilder<T> {
     /// empty::<Product>().untyped::<product_fields::Name>(text_search);
     /// // Resulting BSON: { "name": { "$regex": "laptop", "$options": "i" } }
     ///
-    /// // Using complex array operators
-    /// let array_condition = doc! {
-    ///     "$elemMatch": {
-    ///         "$regex": "electronics",
-    ///         "$options": "i"
+    /// // Using complex array operators
+    /// let array_condition = doc! {
+    ///     "$elemMatch": {
+    ///         "$regex": "electronics",
+    ///         "$options": "i"
+    ///     }
+    /// };
+    /// empty::<Product>().untyped::<product_fields::Tags>(array_condition);
+    /// // Resulting BSON: { "tags": { "$elemMatch": { "$regex": "electronics", "$options": "i" } } }
+    ///
+    /// // Combining with typed methods
+    /// empty::<Product>()
+    ///     .gt::<product_fields::Price, _>(100.0)  // Type-safe
+    ///     .untyped::<product_fields::Name>(doc! { "$exists": true });  // Untyped
+    /// ```
+    ///
+    /// # MongoDB Expression Support
+    ///
+    /// This method can be used with any MongoDB query operator, including:
+    /// - Text search: `$regex`, `$text`
+    /// - Array operations: `$elemMatch`, `$size`, `$all`
+    /// - Geospatial: `$near`, `$geoWithin`, `$geoIntersects`
+    /// - Advanced comparison: `$mod`, `$type`
+    /// - Custom aggregation expressions in `$expr`
+    pub fn untyped<F: FieldName>(&mut self, value: bson::Document) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses.push(bson::doc! { path: value });
+
+        self
+    }
+
+    /// Creates a filter for a field using a custom MongoDB query operator.
+    ///
+    /// This is the filter-side analog of `UpdateBuilder::untyped`: it gives access to operators
+    /// the crate doesn't wrap yet, while keeping the field path type-checked.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Flags`)
+    ///
+    /// # Arguments
+    /// * `operator` - The MongoDB operator name, including the `$` prefix (e.g., `"$bitsAllClear"`)
+    /// * `value` - The BSON operand for the operator
+    ///
+    /// # Safety Considerations
+    ///
+    /// - No compile-time validation of the operator name or of the operand type
+    /// - Field existence is still verified at compile time through `HasField<F>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct Product { pub flags: i32 }
+    ///
+    /// let filter = empty::<Product>()
+    ///     .op::<product_fields::Flags>("$bitsAllClear", bson::Bson::Int32(35))
+    ///     .and();
+    ///
+    /// assert_eq!(filter, bson::doc! { "flags": { "$bitsAllClear": 35 } });
+    /// ```
+    pub fn op<F: FieldName>(&mut self, operator: &str, value: bson::Bson) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses.push(bson::doc! { path: { operator: value } });
+
+        self
+    }
+
+    /// Creates an equality filter from an already dynamic `bson::Bson` value.
+    ///
+    /// This is meant for fully dynamic values (e.g. decoded from a request), with reduced
+    /// type safety: as the value type is erased, `MongoComparable` is bypassed and only the
+    /// field existence is verified at compile time through `HasField<F>`. The `gt_bson`,
+    /// `gte_bson`, `lt_bson`, `lte_bson` and `ne_bson` variants follow the same rules.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Stock`)
+    ///
+    /// # Arguments
+    /// * `value` - The BSON value the field must be equal to
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Product { pub stock: i32 }
+    ///
+    /// let filter = empty::<Product>()
+    ///     .eq_bson::<product_fields::Stock>(bson::Bson::Int32(5))
+    ///     .gt_bson::<product_fields::Stock>(bson::Bson::Int32(1))
+    ///     .and();
+    /// // Resulting BSON: { "$and": [{ "stock": 5 }, { "stock": { "$gt": 1 } }] }
+    /// ```
+    pub fn eq_bson<F: FieldName>(&mut self, value: bson::Bson) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses.push(bson::doc! { path: value });
+
+        self
+    }
+
+    /// Creates a not-equal filter (`$ne`) from an already dynamic `bson::Bson` value.
+    ///
+    /// Same as `ne`, but bypassing `MongoComparable` as the value type is erased:
+    /// the field existence is still verified through `HasField<F>`, not the value type.
+    pub fn ne_bson<F: FieldName>(&mut self, value: bson::Bson) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        self.op::<F>("$ne", value)
+    }
+
+    /// Creates a greater-than filter (`$gt`) from an already dynamic `bson::Bson` value.
+    ///
+    /// Same as `gt`, but bypassing `MongoComparable` as the value type is erased:
+    /// the field existence is still verified through `HasField<F>`, not the value type.
+    pub fn gt_bson<F: FieldName>(&mut self, value: bson::Bson) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        self.op::<F>("$gt", value)
+    }
+
+    /// Creates a greater-than-or-equal filter (`$gte`) from an already dynamic `bson::Bson` value.
+    ///
+    /// Same as `gte`, but bypassing `MongoComparable` as the value type is erased:
+    /// the field existence is still verified through `HasField<F>`, not the value type.
+    pub fn gte_bson<F: FieldName>(&mut self, value: bson::Bson) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        self.op::<F>("$gte", value)
+    }
+
+    /// Creates a less-than filter (`$lt`) from an already dynamic `bson::Bson` value.
+    ///
+    /// Same as `lt`, but bypassing `MongoComparable` as the value type is erased:
+    /// the field existence is still verified through `HasField<F>`, not the value type.
+    pub fn lt_bson<F: FieldName>(&mut self, value: bson::Bson) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        self.op::<F>("$lt", value)
+    }
+
+    /// Creates a less-than-or-equal filter (`$lte`) from an already dynamic `bson::Bson` value.
+    ///
+    /// Same as `lte`, but bypassing `MongoComparable` as the value type is erased:
+    /// the field existence is still verified through `HasField<F>`, not the value type.
+    pub fn lte_bson<F: FieldName>(&mut self, value: bson::Bson) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        self.op::<F>("$lte", value)
+    }
+
+    /// Creates an equality filter on a field only known at runtime, bypassing all the type
+    /// witnesses (`{ field: value }`).
+    ///
+    /// This is the fully untyped escape hatch, for the generic code where the field type is not
+    /// statically known (e.g. reflection-driven layers): neither the field existence nor the
+    /// value type are verified, so prefer `eq_bson` whenever a field marker type is available.
+    /// As with [`raw_and`](Self::raw_and), the field path is added as is (i.e. not prefixed
+    /// in nested contexts such as `with_lookup`).
+    ///
+    /// # Arguments
+    /// * `field` - The MongoDB field path (e.g. `"stock"` or `"address.city"`)
+    /// * `value` - The BSON value the field must be equal to
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Product { pub stock: i32 }
+    ///
+    /// let field = "stock".to_string(); // e.g. from a runtime schema
+    ///
+    /// let filter = empty::<Product>()
+    ///     .eq_dynamic(&field, bson::Bson::Int32(5))
+    ///     .and();
+    ///
+    /// assert_eq!(filter, bson::doc! { "stock": 5 });
+    /// ```
+    pub fn eq_dynamic(&mut self, field: &str, value: bson::Bson) -> &mut Self {
+        self.clauses.push(bson::doc! { field: value });
+
+        self
+    }
+
+    /// Adds a MongoDB `$expr` clause built from a typed expression.
+    pub fn expr(&mut self, expr: Expr<T, bool>) -> &mut Self {
+        self.clauses.push(bson::doc! { "$expr": expr.into_bson() });
+
+        self
+    }
+
+    /// Adds a `$expr` clause comparing a field with a variable
+    /// (`{ "$expr": { "$eq": ["$field", "$$var"] } }`).
+    ///
+    /// This is the core of the correlated `$lookup` joins: in the `$match` stage of the
+    /// `pipeline`, the fields of the joined collection are compared with the variables
+    /// defined by `let`, which requires `$expr`. The field existence is verified at compile
+    /// time, while the variable is referenced by name (with or without its `$$` prefix).
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `order_fields::CustomerId`)
+    ///
+    /// # Arguments
+    /// * `var_name` - The name of the variable, as defined by the `$lookup` `let`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Order { pub customer_id: String }
+    ///
+    /// let filter = empty::<Order>()
+    ///     .expr_eq_var::<order_fields::CustomerId>("customer_id")
+    ///     .and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! { "$expr": { "$eq": ["$customer_id", "$$customer_id"] } }
+    /// );
+    ///
+    /// // { "$lookup": {
+    /// //     "from": "orders",
+    /// //     "let": { "customer_id": "$_id" },
+    /// //     "pipeline": [{ "$match": filter }],
+    /// //     "as": "orders"
+    /// // } }
+    /// ```
+    pub fn expr_eq_var<F: FieldName>(&mut self, var_name: &str) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        let field = format!("${}", self.field_path::<F>());
+        let var = format!("$${}", var_name.trim_start_matches('$'));
+
+        self.clauses
+            .push(bson::doc! { "$expr": { "$eq": [field, var] } });
+
+        self
+    }
+
+    /// Adds raw BSON clauses, combined with the other clauses with the `$and` semantics.
+    ///
+    /// This is intended to incrementally migrate legacy queries to the typed builder.
+    /// Neither the field names nor the value types of these clauses are checked at compile
+    /// time, and they are added as is (i.e. not prefixed in nested contexts such as
+    /// `with_lookup`), so the typed methods should be preferred whenever possible.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User {
+    ///     pub name: String,
+    /// }
+    ///
+    /// let legacy_clauses = vec![bson::doc! { "legacy_flag": true }];
+    ///
+    /// let filter = empty::<User>()
+    ///     .eq::<user_fields::Name, _>("John")
+    ///     .raw_and(legacy_clauses)
+    ///     .and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! { "$and": [{ "name": "John" }, { "legacy_flag": true }] }
+    /// );
+    /// ```
+    pub fn raw_and(&mut self, clauses: Vec<bson::Document>) -> &mut Self {
+        self.clauses.extend(clauses);
+
+        self
+    }
+
+    /// Adds a `$or` clause of raw BSON alternatives.
+    ///
+    /// As with [`raw_and`](Self::raw_and), the alternatives are neither checked nor prefixed.
+    /// No clause is added if there's no alternative (MongoDB rejects an empty `$or` array).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User {
+    ///     pub name: String,
+    /// }
+    ///
+    /// let filter = empty::<User>()
+    ///     .eq::<user_fields::Name, _>("John")
+    ///     .raw_or(vec![bson::doc! { "role": "admin" }, bson::doc! { "role": "owner" }])
+    ///     .and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! {
+    ///         "$and": [
+    ///             { "name": "John" },
+    ///             { "$or": [{ "role": "admin" }, { "role": "owner" }] }
+    ///         ]
+    ///     }
+    /// );
+    /// ```
+    pub fn raw_or(&mut self, clauses: Vec<bson::Document>) -> &mut Self {
+        if !clauses.is_empty() {
+            self.clauses.push(bson::doc! { "$or": clauses });
+        }
+
+        self
+    }
+
+    /// Creates filters for nested fields within documents using a path-based lookup approach.
+    /// This method provides explicit control over field path construction,
+    /// allowing you to specify exactly which nested field to target through a lookup function.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type for the base field (e.g., `user_fields::HomeAddress`)
+    /// * `L` - The lookup function type that resolves the field path
+    /// * `G` - The field name marker type for the target nested field (e.g., `address_fields::City`)
+    /// * `U` - The type of the nested structure containing the target field
+    /// * `N` - The closure that builds filters on the nested FilterBuilder
+    ///
+    /// # Arguments
+    /// * `lookup` - A function that takes a `Path<F, T, T>` and returns a `Path<G, U, T>` to specify the target field
+    /// * `f` - A closure that builds filter conditions on the resolved nested field
+    ///
+    /// # Note
+    /// For simpler cases where you want to filter on the field itself (identity lookup),
+    /// consider using the `with_field` method instead, which is more concise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct Address {
+    ///     pub Street: String,
+    ///     pub City: String,
+    ///     pub ZipCode: String,
+    /// }
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct User {
+    ///     pub Name: String,
+    ///     pub HomeAddress: Address,
+    /// }
+    ///
+    /// // Using field navigation for accessing nested fields (G≠F, U≠T)
+    /// let mut builder = empty::<User>().with_lookup::<user_fields::HomeAddress, _, address_fields::City, Address, _>(
+    ///     |path| path.field::<address_fields::City>(),
+    ///     |nested| {
+    ///         nested.eq::<address_fields::City, _>("New York".to_string())
+    ///     }
+    /// );
+    /// // Resulting BSON: { "HomeAddress.City": "New York" }
+    ///
+    /// // For identity cases (filtering on the field itself), prefer with_field():
+    /// // builder.with_field::<user_fields::HomeAddress, _>(|nested| {
+    /// //     nested.exists::<user_fields::HomeAddress>(true)
+    /// // });
+    /// // Resulting BSON: { "HomeAddress": { "$exists": true } }
+    /// ```
+    pub fn with_lookup<F: FieldName, L, G: FieldName, U: HasField<G>, N>(
+        &mut self,
+        lookup: L,
+        f: N,
+    ) -> &mut Self
+    where
+        T: HasField<F>,
+        L: FnOnce(&Path<F, T, T>) -> Path<G, U, T>,
+        N: FnOnce(&mut FilterBuilder<U>) -> &mut FilterBuilder<U>,
+    {
+        // Create a base field path for the lookup
+        let base_field: Path<F, T, T> = Path {
+            prefix: self.prefix.clone(),
+            _marker: std::marker::PhantomData,
+        };
+
+        // Resolve the field path using the provided lookup function
+        let resolved_field = lookup(&base_field);
+
+        // Create a new FilterBuilder for the nested field
+        let mut nested_builder = FilterBuilder::<U> {
+            prefix: resolved_field.prefix.clone(),
+            clauses: vec![],
+            _marker: std::marker::PhantomData,
+        };
+
+        f(&mut nested_builder);
+
+        // Add the nested clauses individually to the main builder
+        self.clauses.extend(nested_builder.clauses);
+
+        self
+    }
+
+    /// Same as `with_lookup`, except that the nested conditions are grouped as a single clause.
+    ///
+    /// The nested builder is combined using `and()`, and the result is added as one clause
+    /// (if not empty) instead of flattening each nested condition into this builder. This
+    /// keeps the nested conditions together as one sub-document, e.g. to be composed with
+    /// other clauses.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type for the base field (e.g., `user_fields::HomeAddress`)
+    /// * `L` - The lookup function type that resolves the field path
+    /// * `G` - The field name marker type for the target nested field (e.g., `address_fields::City`)
+    /// * `U` - The type of the nested structure containing the target field
+    /// * `N` - The closure that builds filters on the nested FilterBuilder
+    ///
+    /// # Arguments
+    /// * `lookup` - A function that takes a `Path<F, T, T>` and returns a `Path<G, U, T>` to specify the target field
+    /// * `f` - A closure that builds filter conditions on the resolved nested field
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Address {
+    ///     pub City: String,
+    ///     pub ZipCode: String,
+    /// }
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User {
+    ///     pub Name: String,
+    ///     pub HomeAddress: Address,
+    /// }
+    ///
+    /// let filter = empty::<User>()
+    ///     .eq::<user_fields::Name, _>("John".to_string())
+    ///     .with_lookup_grouped::<user_fields::HomeAddress, _, address_fields::City, Address, _>(
+    ///         |path| path.field::<address_fields::City>(),
+    ///         |nested| {
+    ///             nested
+    ///                 .eq::<address_fields::City, _>("New York".to_string())
+    ///                 .eq::<address_fields::ZipCode, _>("10001".to_string())
+    ///         },
+    ///     )
+    ///     .and();
+    /// // Resulting BSON: { "$and": [
+    /// //   { "Name": "John" },
+    /// //   { "$and": [{ "HomeAddress.City": "New York" }, { "HomeAddress.ZipCode": "10001" }] }
+    /// // ] }
+    /// ```
+    pub fn with_lookup_grouped<F: FieldName, L, G: FieldName, U: HasField<G>, N>(
+        &mut self,
+        lookup: L,
+        f: N,
+    ) -> &mut Self
+    where
+        T: HasField<F>,
+        L: FnOnce(&Path<F, T, T>) -> Path<G, U, T>,
+        N: FnOnce(&mut FilterBuilder<U>) -> &mut FilterBuilder<U>,
+    {
+        let base_field: Path<F, T, T> = Path {
+            prefix: self.prefix.clone(),
+            _marker: std::marker::PhantomData,
+        };
+
+        let resolved_field = lookup(&base_field);
+
+        let mut nested_builder = FilterBuilder::<U>::with_prefix(resolved_field.prefix.clone());
+
+        f(&mut nested_builder);
+
+        let grouped = nested_builder.and();
+
+        if !grouped.is_empty() {
+            self.clauses.push(grouped);
+        }
+
+        self
+    }
+
+    /// Convenience method for filtering on a field directly (using identity lookup).
+    ///
+    /// Notes: This is a specialized version of `with_lookup` that uses `std::convert::identity`
+    /// as the lookup function, making it easier to apply filters directly to a field
+    /// without needing to specify the identity function explicitly.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `user_fields::HomeAddress`)
+    /// * `N` - The closure that builds filters on the field
+    ///
+    /// # Arguments
+    /// * `f` - A closure that builds filter conditions on the field
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct User {
+    ///     pub Name: String,
+    ///     pub HomeAddress: String,
+    /// }
+    ///
+    /// let mut builder = empty::<User>().with_field::<user_fields::HomeAddress, _>(|nested| {
+    ///     nested.exists::<user_fields::HomeAddress>(true)
+    /// });
+    /// // Resulting BSON: { "HomeAddress": { "$exists": true } }
+    /// ```
+    pub fn with_field<F: FieldName, N>(&mut self, f: N) -> &mut Self
+    where
+        T: HasField<F>,
+        N: FnOnce(&mut FilterBuilder<T>) -> &mut FilterBuilder<T>,
+    {
+        self.with_lookup::<F, _, F, T, _>(
+            |path| Path {
+                prefix: path.prefix.clone(),
+                _marker: std::marker::PhantomData,
+            },
+            f,
+        )
+    }
+
+    /// Selects a field to add a single condition on, as a fluent alternative to `with_field`.
+    ///
+    /// The returned [`FieldQuery`] exposes the comparison operators for the field `F`, which
+    /// add their clause to this builder and return it, so the chaining can continue.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `user_fields::Name`)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub age: i32,
+    /// }
+    ///
+    /// let filter = empty::<User>()
+    ///     .on::<user_fields::Name>().eq("John")
+    ///     .on::<user_fields::Age>().gte(18)
+    ///     .and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! { "$and": [{ "name": "John" }, { "age": { "$gte": 18 } }] }
+    /// );
+    /// ```
+    pub fn on<F: FieldName>(&mut self) -> FieldQuery<'_, T, F>
+    where
+        T: HasField<F>,
+    {
+        FieldQuery {
+            builder: self,
+            _field: std::marker::PhantomData,
+        }
+    }
+
+    /// Selects a field to add a multi-operator condition on, committed with `apply`.
+    ///
+    /// Unlike `on`, whose operators each add a clause, the operators of the returned
+    /// [`FieldFilterBuilder`] are accumulated (as for `not`), and added as a single
+    /// `{ field: { ...ops... } }` clause on [`apply`](FieldFilterBuilder::apply) (the builder
+    /// is `#[must_use]`, so forgetting it is warned about).
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Price`)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Product {
+    ///     pub name: String,
+    ///     pub price: f64,
+    /// }
+    ///
+    /// let filter = empty::<Product>()
+    ///     .field::<product_fields::Price>().gte(1.0).lt(10.0).apply()
+    ///     .eq::<product_fields::Name, _>("Pen")
+    ///     .and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! { "$and": [{ "price": { "$gte": 1.0, "$lt": 10.0 } }, { "name": "Pen" }] }
+    /// );
+    /// ```
+    pub fn field<F: FieldName>(&mut self) -> FieldFilterBuilder<F, T, &mut Self>
+    where
+        T: HasField<F>,
+    {
+        FieldFilterBuilder::with_parent(self)
+    }
+
+    /// Create a type-safe version of MongoDB's "$or" operator,
+    /// where each clause is generated by applying a closure to each item in the input iterable.
+    ///
+    /// As a branch without any clause would match all the documents (making the whole `$or`
+    /// match everything), the items for which the closure adds no clause are skipped, and
+    /// no clause is added if there is none at all (as for `or_each` and `any_of`).
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Category`)
+    /// * `V` - An iterable type containing values to process
+    /// * `N` - A closure that takes a FilterBuilder and an item from V, and returns the FilterBuilder
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - An iterable collection of values to process
+    /// * `f` - A closure that builds filter conditions for each value in the input
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct Product {
+    ///     pub name: String,
+    ///     pub category: String,
+    ///     pub price: f64,
+    /// }
+    ///
+    /// // Filter for products that match any of the given names
+    /// let names = vec!["Laptop", "Smartphone", "Tablet"];
+    ///
+    /// empty::<Product>().or::<product_fields::Name, _, _>(names, |filter, name| {
+    ///     filter.eq::<product_fields::Name, _>(name.to_string())
+    /// });
+    ///
+    /// // Resulting BSON:
+    /// // { "$or": [
+    /// //     { "name": "Laptop" },
+    /// //     { "name": "Smartphone" },
+    /// //     { "name": "Tablet" }
+    /// // ]}
+    /// ```
+    ///
+    /// # Complex Example with Multiple Conditions
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct Product {
+    ///     pub name: String,
+    ///     pub category: String,
+    ///     pub price: f64,
+    /// }
+    ///
+    /// // Filter for products in specific price ranges
+    /// let price_ranges = vec![(0.0, 100.0), (500.0, 1000.0), (2000.0, 5000.0)];
+    ///
+    /// empty::<Product>().or::<product_fields::Price, _, _>(price_ranges, |filter, (min, max)| {
+    ///     filter.gte::<product_fields::Price, _>(min)
+    ///           .lte::<product_fields::Price, _>(max)
+    /// });
+    ///
+    /// // Resulting BSON (note: multiple clauses from each iteration are flattened):
+    /// // { "$or": [
+    /// //     { "price": { "$gte": 0.0 } },
+    /// //     { "price": { "$lte": 100.0 } },
+    /// //     { "price": { "$gte": 500.0 } },
+    /// //     { "price": { "$lte": 1000.0 } },
+    /// //     { "price": { "$gte": 2000.0 } },
+    /// //     { "price": { "$lte": 5000.0 } }
+    /// // ]}
+    /// ```
+    pub fn or<F, V: IntoIterator, N>(&mut self, input: V, f: N) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F>,
+        N: Fn(&mut FilterBuilder<T>, V::Item) -> &mut FilterBuilder<T>,
+    {
+        let mut nested = empty::<T>();
+        let mut or_clauses: Vec<bson::Document> = vec![];
+
+        for value in input {
+            f(&mut nested, value);
+
+            match nested.clauses.len() {
+                0 => continue, // Skip empty nested clauses
+                1 => or_clauses.push(nested.clauses.clone().into_iter().next().unwrap()),
+                _ => or_clauses.extend(nested.clauses.clone()),
+            }
+
+            nested.clauses.clear(); // Clear for next iteration
+        }
+
+        if !or_clauses.is_empty() {
+            self.clauses.push(bson::doc! { "$or": or_clauses });
+        }
+
+        self
+    }
+
+    /// Creates a `$or` filter of equality conditions on the same field, one per value.
+    ///
+    /// This is a shortcut for the common `or` usage where each alternative is an `eq`
+    /// on the same field. Note that for plain equality, `$in` is equivalent and generally
+    /// preferred by MongoDB; this form is useful when the alternatives must remain `$or`
+    /// clauses (e.g. to be combined with other `$or` branches). As MongoDB rejects an empty
+    /// `$or` array, no clause is added if there is no value (as for `or` and `any_of`).
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Category`)
+    /// * `V` - The type of the field value or a compatible type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Product {
+    ///     pub category: String,
+    /// }
+    ///
+    /// let filter = empty::<Product>()
+    ///     .or_values::<product_fields::Category, _>(vec!["books", "music"])
+    ///     .and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! { "$or": [{ "category": "books" }, { "category": "music" }] }
+    /// );
+    /// ```
+    pub fn or_values<F, V>(&mut self, values: Vec<V>) -> &mut Self
+    where
+        F: FieldName,
+        T: HasField<F> + MongoComparable<T::Value, V>,
+        V: Into<bson::Bson>,
+    {
+        let path = self.field_path::<F>();
+        let or_clauses: Vec<bson::Document> = values
+            .into_iter()
+            .map(|value| bson::doc! { path.clone(): value.into() })
+            .collect();
+
+        if !or_clauses.is_empty() {
+            self.clauses.push(bson::doc! { "$or": or_clauses });
+        }
+
+        self
+    }
+
+    /// Creates a `$or` filter matching the documents which match any of the given branches.
+    ///
+    /// Each branch configures its own builder, whose clauses are grouped as `and()` does,
+    /// so a branch with several conditions is a single alternative (unlike with `or`, which
+    /// flattens the clauses). Empty branches are skipped, and no clause is added if all the
+    /// branches are empty.
+    ///
+    /// The branches can be given by any iterator (e.g. closures mapped from some values).
+    /// As all its items have the same type, distinct closures must be boxed
+    /// (e.g. as `Box<dyn FnOnce(&mut FilterBuilder<T>)>`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::{FilterBuilder, empty};
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Product {
+    ///     pub name: String,
+    ///     pub price: f64,
+    /// }
+    ///
+    /// let branches: Vec<Box<dyn FnOnce(&mut FilterBuilder<Product>)>> = vec![
+    ///     Box::new(|f| {
+    ///         f.eq::<product_fields::Name, _>("Laptop").lt::<product_fields::Price, _>(1000.0);
+    ///     }),
+    ///     Box::new(|f| {
+    ///         f.eq::<product_fields::Name, _>("Phone");
+    ///     }),
+    /// ];
+    ///
+    /// let filter = empty::<Product>().any_of(branches).and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! {
+    ///         "$or": [
+    ///             { "$and": [{ "name": "Laptop" }, { "price": { "$lt": 1000.0 } }] },
+    ///             { "name": "Phone" }
+    ///         ]
+    ///     }
+    /// );
+    /// ```
+    pub fn any_of<I, B>(&mut self, branches: I) -> &mut Self
+    where
+        I: IntoIterator<Item = B>,
+        B: FnOnce(&mut FilterBuilder<T>),
+    {
+        let grouped = self.grouped_branches(branches);
+
+        if !grouped.is_empty() {
+            self.clauses.push(bson::doc! { "$or": grouped });
+        }
+
+        self
+    }
+
+    /// Creates a `$or` filter with a branch per closure: an alias of [`any_of`](Self::any_of).
+    ///
+    /// This is the most general OR: each closure independently builds a branch, on any fields
+    /// and operators, and the clauses of each branch form one `$or` element (grouped as `and()`
+    /// does). Empty branches are skipped, and no clause is added if all the branches are empty.
+    /// As the closures are each called once, they can either be `FnOnce` or `FnMut` ones
+    /// (e.g. the boxed closures of an array, or closures mapped from some values).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Product {
+    ///     pub name: String,
+    ///     pub price: f64,
+    /// }
+    ///
+    /// let names = ["Laptop", "Phone"];
+    ///
+    /// let filter = empty::<Product>()
+    ///     .or_each(names.map(|name| {
+    ///         move |f: &mut tnuctipun::filters::FilterBuilder<Product>| {
+    ///             f.eq::<product_fields::Name, _>(name).lt::<product_fields::Price, _>(1000.0);
+    ///         }
+    ///     }))
+    ///     .and();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! {
+    ///         "$or": [
+    ///             { "$and": [{ "name": "Laptop" }, { "price": { "$lt": 1000.0 } }] },
+    ///             { "$and": [{ "name": "Phone" }, { "price": { "$lt": 1000.0 } }] }
+    ///         ]
     ///     }
+    /// );
+    /// ```
+    pub fn or_each<N>(&mut self, closures: impl IntoIterator<Item = N>) -> &mut Self
+    where
+        N: FnOnce(&mut FilterBuilder<T>),
+    {
+        self.any_of(closures)
+    }
+
+    /// Creates a `$or` filter with a branch per closure as `any_of` does, but strictly.
+    ///
+    /// An empty branch would match all the documents, which is usually a bug (e.g. a closure
+    /// only adding conditional clauses), so rather than skipping it, this fails and leaves
+    /// the builder unchanged. A `$or` without any branch is also rejected (as by MongoDB).
+    ///
+    /// # Errors
+    ///
+    /// Returns `EmptyBranchError::EmptyBranch` with the index of the first branch without
+    /// any clause, or `EmptyBranchError::NoBranch` if there is no branch at all.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::{EmptyBranchError, FilterBuilder, empty};
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Product {
+    ///     pub name: String,
+    ///     pub price: f64,
+    /// }
+    ///
+    /// let max_price: Option<f64> = None;
+    /// let branches: Vec<Box<dyn FnOnce(&mut FilterBuilder<Product>)>> = vec![
+    ///     Box::new(|f| {
+    ///         f.eq::<product_fields::Name, _>("Laptop");
+    ///     }),
+    ///     Box::new(move |f| {
+    ///         if let Some(max) = max_price {
+    ///             f.lt::<product_fields::Price, _>(max);
+    ///         }
+    ///     }),
+    /// ];
+    ///
+    /// let mut builder = empty::<Product>();
+    /// let Err(error) = builder.try_any_of(branches) else {
+    ///     panic!("the second branch is empty");
     /// };
-    /// empty::<Product>().untyped::<product_fields::Tags>(array_condition);
-    /// // Resulting BSON: { "tags": { "$elemMatch": { "$regex": "electronics", "$options": "i" } } }
     ///
-    /// // Combining with typed methods
-    /// empty::<Product>()
-    ///     .gt::<product_fields::Price, _>(100.0)  // Type-safe
-    ///     .untyped::<product_fields::Name>(doc! { "$exists": true });  // Untyped
+    /// assert_eq!(error, EmptyBranchError::EmptyBranch(1));
+    /// assert_eq!(builder.and(), bson::doc! {});
+    /// ```
+    pub fn try_any_of<I, B>(&mut self, branches: I) -> Result<&mut Self, EmptyBranchError>
+    where
+        I: IntoIterator<Item = B>,
+        B: FnOnce(&mut FilterBuilder<T>),
+    {
+        let mut grouped = Vec::new();
+
+        for (index, branch) in branches.into_iter().enumerate() {
+            match self.grouped_branches([branch]).pop() {
+                Some(clause) => grouped.push(clause),
+                None => return Err(EmptyBranchError::EmptyBranch(index)),
+            }
+        }
+
+        if grouped.is_empty() {
+            return Err(EmptyBranchError::NoBranch);
+        }
+
+        self.clauses.push(bson::doc! { "$or": grouped });
+
+        Ok(self)
+    }
+
+    /// Creates a `$and` filter matching the documents which match all of the given branches.
+    ///
+    /// Each branch configures its own builder, whose clauses are grouped as `and()` does.
+    /// Empty branches are skipped, and no clause is added if all the branches are empty.
+    /// The branches can be given by any iterator: see [`any_of`](Self::any_of) about passing
+    /// distinct closures.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::{FilterBuilder, empty};
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Product {
+    ///     pub name: String,
+    ///     pub price: f64,
+    /// }
+    ///
+    /// let filter = empty::<Product>()
+    ///     .all_of(vec![
+    ///         |f: &mut FilterBuilder<Product>| {
+    ///             f.gte::<product_fields::Price, _>(10.0);
+    ///         },
+    ///     ])
+    ///     .and();
+    ///
+    /// assert_eq!(filter, bson::doc! { "$and": [{ "price": { "$gte": 10.0 } }] });
+    /// ```
+    pub fn all_of<I, B>(&mut self, branches: I) -> &mut Self
+    where
+        I: IntoIterator<Item = B>,
+        B: FnOnce(&mut FilterBuilder<T>),
+    {
+        let grouped = self.grouped_branches(branches);
+
+        if !grouped.is_empty() {
+            self.clauses.push(bson::doc! { "$and": grouped });
+        }
+
+        self
+    }
+
+    // Applies each branch to a builder with the same prefix, and groups its clauses
+    fn grouped_branches<I, B>(&self, branches: I) -> Vec<bson::Document>
+    where
+        I: IntoIterator<Item = B>,
+        B: FnOnce(&mut FilterBuilder<T>),
+    {
+        branches
+            .into_iter()
+            .filter_map(|branch| {
+                let mut nested = FilterBuilder::<T>::with_prefix(self.prefix.clone());
+
+                branch(&mut nested);
+
+                match nested.clauses.len() {
+                    0 => None,
+                    1 => nested.clauses.pop(),
+                    _ => Some(bson::doc! { "$and": nested.clauses }),
+                }
+            })
+            .collect()
+    }
+
+    /// Create a type-safe version of MongoDB's "$not" operator.
+    ///
+    /// Such MongoDB filter negates operations on a specific field.
+    /// This method uses a `FieldFilterBuilder` to construct the operations to be negated.
+    ///
+    /// # Type parameters:
+    /// * `F` - The field name marker type (e.g., `product_fields::Price`)
+    /// * `B` - A closure that takes a `FieldFilterBuilder` and returns it with configured operations
+    ///
+    /// # Arguments
+    /// * `f` - A closure that builds the operations to be negated using the `FieldFilterBuilder`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// struct Product {
+    ///     pub name: String,
+    ///     pub price: f64,
+    ///     pub category: String,
+    /// }
+    ///
+    /// // Filter for products where the name is NOT "Smartphone"
+    /// empty::<Product>().not::<product_fields::Name, _>(|op| {
+    ///     op.eq("Smartphone".to_string())
+    /// });
+    /// // Resulting BSON: { "name": { "$not": { "$eq": "Smartphone" } } }
+    ///
+    /// // Filter for products where the price is NOT equal to 500.0
+    /// empty::<Product>().not::<product_fields::Price, _>(|op| {
+    ///     op.eq(500.0)
+    /// });
+    /// // Resulting BSON: { "price": { "$not": { "$eq": 500.0 } } }
     /// ```
     ///
-    /// # MongoDB Expression Support
+    /// # MongoDB Behavior
+    ///
+    /// The `$not` operator in MongoDB performs logical NOT operation on the specified expression.
+    /// It can be used to negate the result of any MongoDB expression, including:
     ///
-    /// This method can be used with any MongoDB query operator, including:
-    /// - Text search: `$regex`, `$text`
-    /// - Array operations: `$elemMatch`, `$size`, `$all`
-    /// - Geospatial: `$near`, `$geoWithin`, `$geoIntersects`
-    /// - Advanced comparison: `$mod`, `$type`
-    /// - Custom aggregation expressions in `$expr`
-    pub fn untyped<F: FieldName>(&mut self, value: bson::Document) -> &mut Self
+    /// - Equality checks
+    /// - Range queries  
+    /// - Pattern matching
+    /// - Other conditional expressions
+    ///
+    /// Note that `$not` affects the semantics of the query and can behave differently than
+    /// using `$ne` (not equal) for simple equality checks, especially with missing fields.
+    pub fn not<F, B>(&mut self, f: B) -> &mut Self
     where
+        F: FieldName,
         T: HasField<F>,
+        B: FnOnce(FieldFilterBuilder<F, T>) -> FieldFilterBuilder<F, T>,
     {
-        let path = self.field_path::<F>();
-
-        self.clauses.push(bson::doc! { path: value });
+        let (operations, _) = f(FieldFilterBuilder::new()).into_parts();
 
-        self
-    }
+        if let Some(operations) = operations {
+            let bson_path = self.field_path::<F>();
 
-    /// Adds a MongoDB `$expr` clause built from a typed expression.
-    pub fn expr(&mut self, expr: Expr<T, bool>) -> &mut Self {
-        self.clauses.push(bson::doc! { "$expr": expr.into_bson() });
+            self.clauses
+                .push(bson::doc! { bson_path: bson::doc! { "$not": operations } });
+        }
 
         self
     }
 
-    /// Creates filters for nested fields within documents using a path-based lookup approach.
-    /// This method provides explicit control over field path construction,
-    /// allowing you to specify exactly which nested field to target through a lookup function.
+    /// Creates a `$not` filter negating a custom MongoDB query operator.
+    ///
+    /// This is the negated counterpart of `op`, for the operators the crate doesn't wrap yet
+    /// (e.g. `$mod`), which `not` can't express as it only accepts the typed operations.
     ///
     /// # Type parameters:
-    /// * `F` - The field name marker type for the base field (e.g., `user_fields::HomeAddress`)
-    /// * `L` - The lookup function type that resolves the field path
-    /// * `G` - The field name marker type for the target nested field (e.g., `address_fields::City`)
-    /// * `U` - The type of the nested structure containing the target field
-    /// * `N` - The closure that builds filters on the nested FilterBuilder
+    /// * `F` - The field name marker type (e.g., `product_fields::Stock`)
     ///
     /// # Arguments
-    /// * `lookup` - A function that takes a `Path<F, T, T>` and returns a `Path<G, U, T>` to specify the target field
-    /// * `f` - A closure that builds filter conditions on the resolved nested field
+    /// * `operator` - The MongoDB operator name, including the `$` prefix (e.g., `"$mod"`)
+    /// * `value` - The BSON operand for the operator
     ///
-    /// # Note
-    /// For simpler cases where you want to filter on the field itself (identity lookup),
-    /// consider using the `with_field` method instead, which is more concise.
+    /// # Safety Considerations
+    ///
+    /// - No compile-time validation of the operator name or of the operand type
+    /// - Field existence is still verified at compile time through `HasField<F>`
     ///
     /// # Example
     ///
     /// ```rust
     /// use tnuctipun::filters::empty;
     /// use tnuctipun::{FieldWitnesses, MongoComparable};
-    /// use serde::{Serialize, Deserialize};
-    ///
-    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// struct Address {
-    ///     pub Street: String,
-    ///     pub City: String,
-    ///     pub ZipCode: String,
-    /// }
     ///
-    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// struct User {
-    ///     pub Name: String,
-    ///     pub HomeAddress: Address,
-    /// }
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Product { pub stock: i32 }
     ///
-    /// // Using field navigation for accessing nested fields (G≠F, U≠T)
-    /// let mut builder = empty::<User>().with_lookup::<user_fields::HomeAddress, _, address_fields::City, Address, _>(
-    ///     |path| path.field::<address_fields::City>(),
-    ///     |nested| {
-    ///         nested.eq::<address_fields::City, _>("New York".to_string())
-    ///     }
-    /// );
-    /// // Resulting BSON: { "HomeAddress.City": "New York" }
+    /// // Filter for products whose stock is not a multiple of 4
+    /// let filter = empty::<Product>()
+    ///     .not_op::<product_fields::Stock>("$mod", bson::bson!([4, 0]))
+    ///     .and();
     ///
-    /// // For identity cases (filtering on the field itself), prefer with_field():
-    /// // builder.with_field::<user_fields::HomeAddress, _>(|nested| {
-    /// //     nested.exists::<user_fields::HomeAddress>(true)
-    /// // });
-    /// // Resulting BSON: { "HomeAddress": { "$exists": true } }
+    /// assert_eq!(filter, bson::doc! { "stock": { "$not": { "$mod": [4, 0] } } });
     /// ```
-    pub fn with_lookup<F: FieldName, L, G: FieldName, U: HasField<G>, N>(
-        &mut self,
-        lookup: L,
-        f: N,
-    ) -> &mut Self
+    pub fn not_op<F: FieldName>(&mut self, operator: &str, value: bson::Bson) -> &mut Self
     where
         T: HasField<F>,
-        L: FnOnce(&Path<F, T, T>) -> Path<G, U, T>,
-        N: FnOnce(&mut FilterBuilder<U>) -> &mut FilterBuilder<U>,
     {
-        // Create a base field path for the lookup
-        let base_field: Path<F, T, T> = Path {
-            prefix: self.prefix.clone(),
-            _marker: std::marker::PhantomData,
-        };
-
-        // Resolve the field path using the provided lookup function
-        let resolved_field = lookup(&base_field);
-
-        // Create a new FilterBuilder for the nested field
-        let mut nested_builder = FilterBuilder::<U> {
-            prefix: resolved_field.prefix.clone(),
-            clauses: vec![],
-            _marker: std::marker::PhantomData,
-        };
-
-        f(&mut nested_builder);
+        let path = self.field_path::<F>();
 
-        // Add the nested clauses individually to the main builder
-        self.clauses.extend(nested_builder.clauses);
+        self.clauses
+            .push(bson::doc! { path: { "$not": { operator: value } } });
 
         self
     }
 
-    /// Convenience method for filtering on a field directly (using identity lookup).
-    ///
-    /// Notes: This is a specialized version of `with_lookup` that uses `std::convert::identity`
-    /// as the lookup function, making it easier to apply filters directly to a field
-    /// without needing to specify the identity function explicitly.
+    /// Combines all clauses into a single BSON document,
+    /// according to the MongoDB `$and` semantics.
     ///
-    /// # Type parameters:
-    /// * `F` - The field name marker type (e.g., `user_fields::HomeAddress`)
-    /// * `N` - The closure that builds filters on the field
+    /// - If no clauses exist, returns an empty document `{}`
+    /// - If only one clause exists, returns that clause directly
+    /// - If multiple clauses exist, wraps them in a `$and` array
     ///
-    /// # Arguments
-    /// * `f` - A closure that builds filter conditions on the field
+    /// This method borrows the builder, allowing you to continue using it afterwards.
     ///
     /// # Example
     ///
@@ -706,240 +2704,294 @@ impl<T> FilterBuilder<T> {
     ///
     /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
     /// struct User {
-    ///     pub Name: String,
-    ///     pub HomeAddress: String,
+    ///     pub name: String,
+    ///     pub age: i32,
+    ///     pub email: String,
     /// }
     ///
-    /// let mut builder = empty::<User>().with_field::<user_fields::HomeAddress, _>(|nested| {
-    ///     nested.exists::<user_fields::HomeAddress>(true)
-    /// });
-    /// // Resulting BSON: { "HomeAddress": { "$exists": true } }
+    /// let filter = empty::<User>()
+    ///     .eq::<user_fields::Name, _>("John Doe".to_string())
+    ///     .gt::<user_fields::Age, _>(18)
+    ///     .exists::<user_fields::Email>(true)
+    ///     .and();
+    /// // builder can still be used here
     /// ```
-    pub fn with_field<F: FieldName, N>(&mut self, f: N) -> &mut Self
-    where
-        T: HasField<F>,
-        N: FnOnce(&mut FilterBuilder<T>) -> &mut FilterBuilder<T>,
-    {
-        self.with_lookup::<F, _, F, T, _>(
-            |path| Path {
-                prefix: path.prefix.clone(),
-                _marker: std::marker::PhantomData,
-            },
-            f,
-        )
+    ///
+    /// Resulting BSON:
+    /// ```text
+    /// { "$and": [{ "name": "John Doe" }, { "age": { "$gt": 18 } }, { "email": { "$exists": true } }] }
+    /// ```
+    #[must_use]
+    pub fn and(&self) -> bson::Document {
+        let doc = if self.clauses.is_empty() {
+            bson::doc! {}
+        } else if self.clauses.len() == 1 {
+            self.clauses[0].clone()
+        } else {
+            bson::doc! { "$and": self.clauses.clone() }
+        };
+
+        #[cfg(feature = "tracing")]
+        crate::instrumentation::trace_built("filter", &doc);
+
+        doc
     }
 
-    /// Create a type-safe version of MongoDB's "$or" operator,
-    /// where each clause is generated by applying a closure to each item in the input iterable.
-    ///
-    /// # Type parameters:
-    /// * `F` - The field name marker type (e.g., `product_fields::Category`)
-    /// * `V` - An iterable type containing values to process
-    /// * `N` - A closure that takes a FilterBuilder and an item from V, and returns the FilterBuilder
-    ///
-    /// # Arguments
+    /// Combines all clauses as `and()` does, but returns `None` if no clause was added.
     ///
-    /// * `input` - An iterable collection of values to process
-    /// * `f` - A closure that builds filter conditions for each value in the input
+    /// An empty filter matches all the documents, so this allows skipping the query
+    /// entirely instead (e.g. when all the conditions are optional), with the `Option`
+    /// combinators at the call site.
     ///
     /// # Example
     ///
     /// ```rust
     /// use tnuctipun::filters::empty;
     /// use tnuctipun::{FieldWitnesses, MongoComparable};
-    /// use serde::{Serialize, Deserialize};
     ///
-    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// struct Product {
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User {
     ///     pub name: String,
-    ///     pub category: String,
-    ///     pub price: f64,
     /// }
     ///
-    /// // Filter for products that match any of the given names
-    /// let names = vec!["Laptop", "Smartphone", "Tablet"];
+    /// let name: Option<&str> = None;
+    /// let mut builder = empty::<User>();
     ///
-    /// empty::<Product>().or::<product_fields::Name, _, _>(names, |filter, name| {
-    ///     filter.eq::<product_fields::Name, _>(name.to_string())
-    /// });
+    /// if let Some(name) = name {
+    ///     builder.eq::<user_fields::Name, _>(name);
+    /// }
     ///
-    /// // Resulting BSON:
-    /// // { "$or": [
-    /// //     { "name": "Laptop" },
-    /// //     { "name": "Smartphone" },
-    /// //     { "name": "Tablet" }
-    /// // ]}
+    /// assert_eq!(builder.and_opt(), None);
+    ///
+    /// builder.eq::<user_fields::Name, _>("John Doe");
+    ///
+    /// assert_eq!(builder.and_opt(), Some(bson::doc! { "name": "John Doe" }));
+    /// // if let Some(filter) = builder.and_opt() { collection.find(filter).await?; }
     /// ```
+    #[must_use]
+    pub fn and_opt(&self) -> Option<bson::Document> {
+        if self.clauses.is_empty() {
+            None
+        } else {
+            Some(self.and())
+        }
+    }
+
+    /// Combines all clauses into a single flat document, when their top-level keys are distinct.
     ///
-    /// # Complex Example with Multiple Conditions
+    /// MongoDB implicitly combines the conditions of a document with the `$and` semantics, so
+    /// `{ "name": "x", "age": { "$gt": 1 } }` is the idiomatic (and equivalent) form of the
+    /// `$and` of both clauses. If some clauses have a key in common (e.g. two range conditions
+    /// on the same field), merging them would drop conditions, so this falls back to `and()`.
+    ///
+    /// # Example
     ///
     /// ```rust
     /// use tnuctipun::filters::empty;
     /// use tnuctipun::{FieldWitnesses, MongoComparable};
-    /// use serde::{Serialize, Deserialize};
     ///
-    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// struct Product {
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User {
     ///     pub name: String,
-    ///     pub category: String,
-    ///     pub price: f64,
+    ///     pub age: i32,
     /// }
     ///
-    /// // Filter for products in specific price ranges
-    /// let price_ranges = vec![(0.0, 100.0), (500.0, 1000.0), (2000.0, 5000.0)];
+    /// let filter = empty::<User>()
+    ///     .eq::<user_fields::Name, _>("John Doe")
+    ///     .gt::<user_fields::Age, _>(18)
+    ///     .and_flat();
     ///
-    /// empty::<Product>().or::<product_fields::Price, _, _>(price_ranges, |filter, (min, max)| {
-    ///     filter.gte::<product_fields::Price, _>(min)
-    ///           .lte::<product_fields::Price, _>(max)
-    /// });
+    /// assert_eq!(filter, bson::doc! { "name": "John Doe", "age": { "$gt": 18 } });
     ///
-    /// // Resulting BSON (note: multiple clauses from each iteration are flattened):
-    /// // { "$or": [
-    /// //     { "price": { "$gte": 0.0 } },
-    /// //     { "price": { "$lte": 100.0 } },
-    /// //     { "price": { "$gte": 500.0 } },
-    /// //     { "price": { "$lte": 1000.0 } },
-    /// //     { "price": { "$gte": 2000.0 } },
-    /// //     { "price": { "$lte": 5000.0 } }
-    /// // ]}
+    /// let filter = empty::<User>()
+    ///     .gt::<user_fields::Age, _>(18)
+    ///     .lt::<user_fields::Age, _>(65)
+    ///     .and_flat();
+    ///
+    /// assert_eq!(
+    ///     filter,
+    ///     bson::doc! { "$and": [{ "age": { "$gt": 18 } }, { "age": { "$lt": 65 } }] }
+    /// );
     /// ```
-    pub fn or<F, V: IntoIterator, N>(&mut self, input: V, f: N) -> &mut Self
-    where
-        F: FieldName,
-        T: HasField<F>,
-        N: Fn(&mut FilterBuilder<T>, V::Item) -> &mut FilterBuilder<T>,
-    {
-        let mut nested = empty::<T>();
-        let mut or_clauses: Vec<bson::Document> = vec![];
+    #[must_use]
+    pub fn and_flat(&self) -> bson::Document {
+        let mut doc = bson::Document::new();
 
-        for value in input {
-            f(&mut nested, value);
+        for clause in &self.clauses {
+            for (key, value) in clause {
+                if doc.contains_key(key) {
+                    return self.and();
+                }
 
-            match nested.clauses.len() {
-                0 => continue, // Skip empty nested clauses
-                1 => or_clauses.push(nested.clauses.clone().into_iter().next().unwrap()),
-                _ => or_clauses.extend(nested.clauses.clone()),
+                doc.insert(key.clone(), value.clone());
             }
-
-            nested.clauses.clear(); // Clear for next iteration
         }
 
-        self.clauses.push(bson::doc! { "$or": or_clauses });
+        #[cfg(feature = "tracing")]
+        crate::instrumentation::trace_built("filter", &doc);
 
-        self
+        doc
     }
 
-    /// Create a type-safe version of MongoDB's "$not" operator.
+    /// Combines all clauses into a `$and` document, whatever the number of clauses.
+    ///
+    /// Unlike `and()`, this always returns the uniform `{ "$and": [...] }` shape (even for
+    /// zero or one clause), as required by some aggregation contexts. Note that MongoDB
+    /// rejects an empty `$and` array, so callers should make sure at least one clause exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User {
+    ///     pub name: String,
+    /// }
     ///
-    /// Such MongoDB filter negates operations on a specific field.
-    /// This method uses a `FieldFilterBuilder` to construct the operations to be negated.
+    /// let filter = empty::<User>()
+    ///     .eq::<user_fields::Name, _>("John Doe")
+    ///     .and_explicit();
     ///
-    /// # Type parameters:
-    /// * `F` - The field name marker type (e.g., `product_fields::Price`)
-    /// * `B` - A closure that takes a `FieldFilterBuilder` and returns it with configured operations
+    /// assert_eq!(filter, bson::doc! { "$and": [{ "name": "John Doe" }] });
+    /// ```
+    #[must_use]
+    pub fn and_explicit(&self) -> bson::Document {
+        bson::doc! { "$and": self.clauses.clone() }
+    }
+
+    /// Combines all clauses as `and()` does, once for all, into a cheap-to-clone `FrozenFilter`.
     ///
-    /// # Arguments
-    /// * `f` - A closure that builds the operations to be negated using the `FieldFilterBuilder`
+    /// It suits a filter reused for many queries (e.g. broadcasted to several collections),
+    /// as the clauses are neither combined nor cloned again for each use.
     ///
     /// # Example
     ///
     /// ```rust
     /// use tnuctipun::filters::empty;
     /// use tnuctipun::{FieldWitnesses, MongoComparable};
-    /// use serde::{Serialize, Deserialize};
     ///
-    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
-    /// struct Product {
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User {
     ///     pub name: String,
-    ///     pub price: f64,
-    ///     pub category: String,
     /// }
     ///
-    /// // Filter for products where the name is NOT "Smartphone"
-    /// empty::<Product>().not::<product_fields::Name, _>(|op| {
-    ///     op.eq("Smartphone".to_string())
-    /// });
-    /// // Resulting BSON: { "name": { "$not": { "$eq": "Smartphone" } } }
+    /// let frozen = empty::<User>()
+    ///     .eq::<user_fields::Name, _>("John Doe")
+    ///     .freeze();
     ///
-    /// // Filter for products where the price is NOT equal to 500.0
-    /// empty::<Product>().not::<product_fields::Price, _>(|op| {
-    ///     op.eq(500.0)
-    /// });
-    /// // Resulting BSON: { "price": { "$not": { "$eq": 500.0 } } }
+    /// let shared = frozen.clone(); // Only the reference count is incremented
+    ///
+    /// assert_eq!(shared.document(), &bson::doc! { "name": "John Doe" });
+    ///
+    /// let filter: bson::Document = shared.into();
+    ///
+    /// assert_eq!(filter, bson::doc! { "name": "John Doe" });
     /// ```
+    #[must_use]
+    pub fn freeze(&self) -> FrozenFilter {
+        FrozenFilter(std::sync::Arc::new(self.and()))
+    }
+
+    /// Renders the shape of the filter (as combined by `and()`): its fields and operators,
+    /// with all the values replaced by a `?` placeholder.
     ///
-    /// # MongoDB Behavior
+    /// Two filters only differing by their values have the same shape, which makes it
+    /// suitable as a grouping key (e.g. for the query metrics or a query-plan cache).
+    /// The embedded documents and the `$and`/`$or`/`$nor` branches are kept structural,
+    /// while any other array (e.g. the `$in` values) is a single placeholder, whatever its length.
     ///
-    /// The `$not` operator in MongoDB performs logical NOT operation on the specified expression.
-    /// It can be used to negate the result of any MongoDB expression, including:
+    /// # Example
     ///
-    /// - Equality checks
-    /// - Range queries  
-    /// - Pattern matching
-    /// - Other conditional expressions
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
     ///
-    /// Note that `$not` affects the semantics of the query and can behave differently than
-    /// using `$ne` (not equal) for simple equality checks, especially with missing fields.
-    pub fn not<F, B>(&mut self, f: B) -> &mut Self
-    where
-        F: FieldName,
-        T: HasField<F>,
-        B: FnOnce(FieldFilterBuilder<F, T>) -> FieldFilterBuilder<F, T>,
-    {
-        let prepared_ops = f(FieldFilterBuilder::new()).build();
-        let bson_path = self.field_path::<F>();
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub age: i32,
+    /// }
+    ///
+    /// let shape = empty::<User>()
+    ///     .eq::<user_fields::Name, _>("John Doe")
+    ///     .gt::<user_fields::Age, _>(18)
+    ///     .shape();
+    ///
+    /// assert_eq!(shape, "{ $and: [{ name: ? }, { age: { $gt: ? } }] }");
+    /// ```
+    #[must_use]
+    pub fn shape(&self) -> String {
+        let mut out = String::new();
 
-        if let Ok(ops) = prepared_ops.get_document(F::field_name()) {
-            self.clauses
-                .push(bson::doc! { bson_path: bson::doc! { "$not": ops.clone() } });
-        }
+        write_shape_document(&self.and(), &mut out);
 
-        self
+        out
     }
 
-    /// Combines all clauses into a single BSON document,
-    /// according to the MongoDB `$and` semantics.
+    /// Combines all clauses as `and()` does, and encodes the result as a raw BSON buffer.
     ///
-    /// - If no clauses exist, returns an empty document `{}`
-    /// - If only one clause exists, returns that clause directly
-    /// - If multiple clauses exist, wraps them in a `$and` array
+    /// The clauses are combined as a `bson::Document` which is then encoded once, so it's a
+    /// convenience for the APIs accepting a `RawDocumentBuf` (e.g. to compute the encoded size
+    /// or to reuse the same bytes for several requests), not a way to skip the encoding.
     ///
-    /// This method borrows the builder, allowing you to continue using it afterwards.
+    /// # Errors
+    ///
+    /// Returns an error if the document cannot be encoded (e.g. a key containing a null byte).
     ///
     /// # Example
     ///
     /// ```rust
     /// use tnuctipun::filters::empty;
     /// use tnuctipun::{FieldWitnesses, MongoComparable};
-    /// use serde::{Serialize, Deserialize};
     ///
-    /// #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    /// #[derive(FieldWitnesses, MongoComparable)]
     /// struct User {
     ///     pub name: String,
-    ///     pub age: i32,
-    ///     pub email: String,
     /// }
     ///
-    /// let filter = empty::<User>()
-    ///     .eq::<user_fields::Name, _>("John Doe".to_string())
-    ///     .gt::<user_fields::Age, _>(18)
-    ///     .exists::<user_fields::Email>(true)
-    ///     .and();
-    /// // builder can still be used here
+    /// let raw = empty::<User>()
+    ///     .eq::<user_fields::Name, _>("John Doe")
+    ///     .and_raw_buf()
+    ///     .expect("encodable filter");
+    ///
+    /// assert_eq!(raw.get_str("name").ok(), Some("John Doe"));
     /// ```
+    pub fn and_raw_buf(&self) -> bson::error::Result<bson::RawDocumentBuf> {
+        bson::RawDocumentBuf::try_from(&self.and())
+    }
+
+    /// Renders the filter combined as `and()` does as canonical JSON, e.g. for snapshot tests.
     ///
-    /// Resulting BSON:
-    /// ```text
-    /// { "$and": [{ "name": "John Doe" }, { "age": { "$gt": 18 } }, { "email": { "$exists": true } }] }
+    /// The keys of all the nested documents are sorted and the JSON is compact, so the output
+    /// is the same whatever the order the clauses were built in (except for the order of the
+    /// array elements, such as the `$and` clauses). The values are rendered in relaxed extended
+    /// JSON. As sorting the keys can change the meaning of some documents (e.g. a `$sort`
+    /// specification), the output is for comparison only. Requires the `serde_json` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::filters::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub age: i32,
+    /// }
+    ///
+    /// let json = empty::<User>()
+    ///     .field::<user_fields::Age>().lt(65).gte(18).apply()
+    ///     .to_canonical_json();
+    ///
+    /// assert_eq!(json, r#"{"age":{"$gte":18,"$lt":65}}"#);
     /// ```
-    pub fn and(&self) -> bson::Document {
-        if self.clauses.is_empty() {
-            bson::doc! {}
-        } else if self.clauses.len() == 1 {
-            self.clauses[0].clone()
-        } else {
-            bson::doc! { "$and": self.clauses.clone() }
-        }
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    pub fn to_canonical_json(&self) -> String {
+        crate::canonical_json::to_canonical_json(&self.and())
     }
 }
 
@@ -1013,6 +3065,241 @@ impl<T> From<FilterBuilder<T>> for bson::Document {
     }
 }
 
+/// Abstraction over values that can be turned into a MongoDB filter document.
+///
+/// It allows generic code (e.g. repositories over `T`) to accept `impl IntoMongoFilter`
+/// without depending on the concrete builder kind. It's implemented by `FilterBuilder<T>`,
+/// by `&mut FilterBuilder<T>` so a fluent chain can be passed directly, and by `FrozenFilter`.
+///
+/// # Example
+///
+/// ```rust
+/// use tnuctipun::filters::{IntoMongoFilter, empty};
+/// use tnuctipun::{FieldWitnesses, MongoComparable};
+///
+/// #[derive(FieldWitnesses, MongoComparable)]
+/// struct User {
+///     pub name: String,
+/// }
+///
+/// fn find_one(filter: impl IntoMongoFilter) -> bson::Document {
+///     filter.into_document()
+/// }
+///
+/// let filter = find_one(empty::<User>().eq::<user_fields::Name, _>("John"));
+///
+/// assert_eq!(filter, bson::doc! { "name": "John" });
+/// ```
+pub trait IntoMongoFilter {
+    /// Returns the filter document, with the same semantics as `FilterBuilder::and`.
+    fn into_document(self) -> bson::Document;
+}
+
+impl<T> IntoMongoFilter for FilterBuilder<T> {
+    fn into_document(self) -> bson::Document {
+        self.and()
+    }
+}
+
+impl<T> IntoMongoFilter for &mut FilterBuilder<T> {
+    fn into_document(self) -> bson::Document {
+        self.and()
+    }
+}
+
+/// A filter document computed once by [`FilterBuilder::freeze`], shared on clone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrozenFilter(std::sync::Arc<bson::Document>);
+
+impl FrozenFilter {
+    /// Returns the filter document
+    pub fn document(&self) -> &bson::Document {
+        &self.0
+    }
+}
+
+impl From<FrozenFilter> for bson::Document {
+    /// Returns the filter document, only cloned if the frozen filter is still shared.
+    fn from(val: FrozenFilter) -> Self {
+        std::sync::Arc::unwrap_or_clone(val.0)
+    }
+}
+
+impl IntoMongoFilter for FrozenFilter {
+    fn into_document(self) -> bson::Document {
+        self.into()
+    }
+}
+
+impl IntoMongoFilter for &FrozenFilter {
+    fn into_document(self) -> bson::Document {
+        self.document().clone()
+    }
+}
+
+impl<'a, F: FieldName, T: HasField<F>> FieldFilterBuilder<F, T, &'a mut FilterBuilder<T>> {
+    /// Adds the accumulated operations as a single clause for the field to the filter builder
+    /// it was selected on (see [`FilterBuilder::field`]), and returns this builder.
+    ///
+    /// If no operation was added, the filter builder is left unchanged.
+    pub fn apply(self) -> &'a mut FilterBuilder<T> {
+        let (operations, builder) = self.into_parts();
+
+        if let Some(operations) = operations {
+            let path = builder.field_path::<F>();
+
+            builder.clauses.push(bson::doc! { path: operations });
+        }
+
+        builder
+    }
+}
+
+/// A field selected on a filter builder with [`FilterBuilder::on`].
+///
+/// Each operator adds a single clause for the field to the builder, and returns the builder.
+pub struct FieldQuery<'a, T, F: FieldName> {
+    builder: &'a mut FilterBuilder<T>,
+    _field: std::marker::PhantomData<F>,
+}
+
+impl<'a, T: HasField<F>, F: FieldName> FieldQuery<'a, T, F> {
+    /// Adds a `$eq` condition on the field (see [`FilterBuilder::eq`]).
+    pub fn eq<V>(self, value: V) -> &'a mut FilterBuilder<T>
+    where
+        T: MongoComparable<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        self.builder.eq::<F, V>(value)
+    }
+
+    /// Adds a `$ne` condition on the field (see [`FilterBuilder::ne`]).
+    pub fn ne<V>(self, value: V) -> &'a mut FilterBuilder<T>
+    where
+        T: MongoComparable<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        self.builder.ne::<F, V>(value)
+    }
+
+    /// Adds a `$gt` condition on the field (see [`FilterBuilder::gt`]).
+    pub fn gt<V>(self, value: V) -> &'a mut FilterBuilder<T>
+    where
+        T: MongoComparable<T::Value, V> + MongoOrdered<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        self.builder.gt::<F, V>(value)
+    }
+
+    /// Adds a `$gte` condition on the field (see [`FilterBuilder::gte`]).
+    pub fn gte<V>(self, value: V) -> &'a mut FilterBuilder<T>
+    where
+        T: MongoComparable<T::Value, V> + MongoOrdered<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        self.builder.gte::<F, V>(value)
+    }
+
+    /// Adds a `$lt` condition on the field (see [`FilterBuilder::lt`]).
+    pub fn lt<V>(self, value: V) -> &'a mut FilterBuilder<T>
+    where
+        T: MongoComparable<T::Value, V> + MongoOrdered<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        self.builder.lt::<F, V>(value)
+    }
+
+    /// Adds a `$lte` condition on the field (see [`FilterBuilder::lte`]).
+    pub fn lte<V>(self, value: V) -> &'a mut FilterBuilder<T>
+    where
+        T: MongoComparable<T::Value, V> + MongoOrdered<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        self.builder.lte::<F, V>(value)
+    }
+
+    /// Adds a `$in` condition on the field (see [`FilterBuilder::in`](FilterBuilder::in)).
+    pub fn r#in<V>(self, values: Vec<V>) -> &'a mut FilterBuilder<T>
+    where
+        T: MongoComparable<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        self.builder.r#in::<F, V>(values)
+    }
+
+    /// Adds a `$nin` condition on the field (see [`FilterBuilder::nin`]).
+    pub fn nin<V>(self, values: Vec<V>) -> &'a mut FilterBuilder<T>
+    where
+        T: MongoComparable<T::Value, V>,
+        V: Into<bson::Bson> + Clone,
+    {
+        self.builder.nin::<F, V>(values)
+    }
+
+    /// Adds a `$exists` condition on the field (see [`FilterBuilder::exists`]).
+    pub fn exists(self, exists: bool) -> &'a mut FilterBuilder<T> {
+        self.builder.exists::<F>(exists)
+    }
+}
+
+// Escapes the regex metacharacters, so the text is matched literally
+fn escape_regex(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if "\\^$.|?*+()[]{}".contains(c) {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+// Renders the keys of the document, with the values normalized as placeholders
+fn write_shape_document(doc: &bson::Document, out: &mut String) {
+    if doc.is_empty() {
+        out.push_str("{}");
+
+        return;
+    }
+
+    out.push_str("{ ");
+
+    for (i, (key, value)) in doc.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+
+        out.push_str(key);
+        out.push_str(": ");
+
+        match value {
+            bson::Bson::Document(nested) => write_shape_document(nested, out),
+            bson::Bson::Array(branches) if matches!(key.as_str(), "$and" | "$or" | "$nor") => {
+                out.push('[');
+
+                for (j, branch) in branches.iter().enumerate() {
+                    if j > 0 {
+                        out.push_str(", ");
+                    }
+
+                    match branch {
+                        bson::Bson::Document(branch) => write_shape_document(branch, out),
+                        _ => out.push('?'),
+                    }
+                }
+
+                out.push(']');
+            }
+            _ => out.push('?'),
+        }
+    }
+
+    out.push_str(" }");
+}
+
 /// Creates an empty FilterBuilder instance.
 ///
 /// This is a convenience function that creates a new FilterBuilder.
@@ -1031,10 +3318,108 @@ impl<T> From<FilterBuilder<T>> for bson::Document {
 /// // Create and use a filter builder in one chain
 /// empty::<User>().eq::<user_fields::Name, _>("John".to_string());
 /// ```
+#[must_use]
 pub fn empty<T>() -> FilterBuilder<T> {
     FilterBuilder::new()
 }
 
+/// Combines independently built filters according to the MongoDB `$and` semantics.
+///
+/// The clauses of all the builders are concatenated, then combined as `FilterBuilder::and`
+/// does (bare document for a single clause, `$and` array otherwise).
+///
+/// # Example
+///
+/// ```rust
+/// use tnuctipun::filters::{and_all, empty};
+/// use tnuctipun::{FieldWitnesses, MongoComparable};
+///
+/// #[derive(FieldWitnesses, MongoComparable)]
+/// struct User { pub name: String, pub age: i32 }
+///
+/// let mut by_name = empty::<User>();
+/// by_name.eq::<user_fields::Name, _>("John");
+///
+/// let mut adult = empty::<User>();
+/// adult.gte::<user_fields::Age, _>(18);
+///
+/// let filter = and_all(vec![by_name, adult]);
+/// // Resulting BSON: { "$and": [{ "name": "John" }, { "age": { "$gte": 18 } }] }
+/// ```
+pub fn and_all<T>(builders: Vec<FilterBuilder<T>>) -> bson::Document {
+    let mut combined = empty::<T>();
+
+    for builder in builders {
+        combined.clauses.extend(builder.clauses);
+    }
+
+    combined.and()
+}
+
+/// Combines independently built filters according to the MongoDB `$or` semantics.
+///
+/// Each builder is combined with `FilterBuilder::and`, and the results are wrapped in a
+/// `$or` array. As for `FilterBuilder::or`, builders without any clause are skipped,
+/// and an empty document (matching all the documents, as `and_all`) is returned if none is left
+/// (MongoDB rejects an empty `$or` array).
+///
+/// # Example
+///
+/// ```rust
+/// use tnuctipun::filters::{empty, or_all};
+/// use tnuctipun::{FieldWitnesses, MongoComparable};
+///
+/// #[derive(FieldWitnesses, MongoComparable)]
+/// struct User { pub name: String, pub age: i32 }
+///
+/// let mut john = empty::<User>();
+/// john.eq::<user_fields::Name, _>("John");
+///
+/// let mut minor = empty::<User>();
+/// minor.lt::<user_fields::Age, _>(18);
+///
+/// let filter = or_all(vec![john, minor]);
+/// // Resulting BSON: { "$or": [{ "name": "John" }, { "age": { "$lt": 18 } }] }
+/// ```
+pub fn or_all<T>(builders: Vec<FilterBuilder<T>>) -> bson::Document {
+    let or_clauses: Vec<bson::Document> = builders
+        .iter()
+        .filter(|builder| !builder.clauses.is_empty())
+        .map(FilterBuilder::and)
+        .collect();
+
+    if or_clauses.is_empty() {
+        return bson::Document::new();
+    }
+
+    bson::doc! { "$or": or_clauses }
+}
+
+/// Error raised by [`FilterBuilder::try_any_of`] when a branch adds no clause,
+/// or when there is no branch at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmptyBranchError {
+    /// The branch at the given index adds no clause, so would match all the documents.
+    EmptyBranch(usize),
+
+    /// There is no branch, so the `$or` would be rejected by MongoDB.
+    NoBranch,
+}
+
+impl std::fmt::Display for EmptyBranchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmptyBranchError::EmptyBranch(index) => write!(
+                f,
+                "Empty branch: the $or branch {index} has no clause, so would match all the documents"
+            ),
+            EmptyBranchError::NoBranch => write!(f, "No branch: the $or has no branch at all"),
+        }
+    }
+}
+
+impl std::error::Error for EmptyBranchError {}
+
 // Testing internal/private functions
 
 #[cfg(test)]
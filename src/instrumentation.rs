@@ -0,0 +1,51 @@
+//! Tracing events for the built documents (`tracing` feature).
+
+use std::collections::BTreeSet;
+
+/// Emits a debug event describing a document built by the given kind of builder.
+///
+/// The field values are only computed if the event is enabled for a subscriber.
+pub(crate) fn trace_built(kind: &'static str, doc: &bson::Document) {
+    tracing::debug!(
+        target: "tnuctipun",
+        kind,
+        size = doc.to_vec().map(|bytes| bytes.len()).unwrap_or_default(),
+        operators = ?operators(doc),
+        "document built"
+    );
+}
+
+/// Collects the operators (`$`-prefixed keys) used at any depth of the document.
+fn operators(doc: &bson::Document) -> BTreeSet<&str> {
+    fn collect<'a>(value: &'a bson::Bson, operators: &mut BTreeSet<&'a str>) {
+        match value {
+            bson::Bson::Document(doc) => {
+                for (key, nested) in doc {
+                    if key.starts_with('$') {
+                        operators.insert(key.as_str());
+                    }
+
+                    collect(nested, operators);
+                }
+            }
+            bson::Bson::Array(values) => {
+                for nested in values {
+                    collect(nested, operators);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut operators = BTreeSet::new();
+
+    for (key, value) in doc {
+        if key.starts_with('$') {
+            operators.insert(key.as_str());
+        }
+
+        collect(value, &mut operators);
+    }
+
+    operators
+}
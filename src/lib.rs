@@ -46,7 +46,56 @@
 //!
 //! - [`filters`] - Query filter building
 //! - [`projection`] - Field projection building  
+//! - [`sort`] - Sort specification building
 //! - [`updates`] - Update document building
+//! - [`pipeline`] - Aggregation stages from the typed builders
+//!
+//! The builders and the pipeline stages are also re-exported at the crate root, along with
+//! the `empty` constructors of each module, named after their builder:
+//!
+//! ```rust
+//! use tnuctipun::{FieldWitnesses, FilterBuilder, MongoComparable, SortBuilder, UpdateBuilder};
+//! use tnuctipun::{empty_filter, empty_projection, empty_sort, empty_update};
+//! use tnuctipun::{limit_stage, match_stage, project_stage, sort_stage};
+//!
+//! #[derive(FieldWitnesses, MongoComparable)]
+//! struct User {
+//!     pub name: String,
+//!     pub age: i32,
+//! }
+//!
+//! let filter: FilterBuilder<User> = empty_filter();
+//! let mut sort: SortBuilder<User> = empty_sort();
+//! let mut projection = empty_projection::<User>();
+//!
+//! sort.desc::<user_fields::Age>();
+//! projection.includes::<user_fields::Name>();
+//!
+//! let pipeline = vec![
+//!     match_stage(&filter),
+//!     sort_stage(&mut sort),
+//!     project_stage(&mut projection),
+//!     limit_stage(10),
+//! ];
+//!
+//! assert_eq!(pipeline[1], bson::doc! { "$sort": { "age": -1 } });
+//!
+//! let mut update: UpdateBuilder<User> = empty_update();
+//!
+//! assert_eq!(
+//!     update.set::<user_fields::Name, _>("John").build(),
+//!     bson::doc! { "$set": { "name": "John" } }
+//! );
+//! ```
+//!
+//! ## Optional features
+//!
+//! - `bson-chrono` - Convert `chrono::DateTime` values into BSON dates (e.g. with `set_date`)
+//! - `tracing` - Emit a `tracing` debug event for each built filter, projection or update,
+//!   with the encoded document size and the MongoDB operators it uses
+//! - `mongodb` - Convert the builders into the MongoDB driver options (e.g. `into_find_options`)
+//! - `serde_json` - Render filters and updates as canonical JSON for snapshot tests
+//!   (`to_canonical_json`)
 
 // Modules
 pub mod expr;
@@ -55,16 +104,67 @@ pub mod field_witnesses;
 pub mod filters;
 pub mod mongo_comparable;
 pub mod path;
+pub mod pipeline;
 pub mod projection;
+pub mod sort;
 pub mod updates;
 
+#[cfg(feature = "serde_json")]
+mod canonical_json;
+
+#[cfg(feature = "tracing")]
+mod instrumentation;
+
+// Dependencies referenced by the code generated by the derive macros
+#[doc(hidden)]
+pub mod __private {
+    pub use bson;
+}
+
 // Re-export the procedural macros
 pub use tnuctipun_derive::FieldWitnesses;
 pub use tnuctipun_derive::MongoComparable;
+pub use tnuctipun_derive::tnuctipun_module;
 
 // Export the traits
 pub use crate::expr::{Expr, ExprBuilder};
 pub use crate::field_filters::FieldFilterBuilder;
-pub use crate::field_witnesses::{FieldName, HasField, NonEmptyStruct};
-pub use crate::mongo_comparable::{MongoComparable, MongoOrdered};
+pub use crate::field_witnesses::{
+    FieldName, FieldNames, HasField, NonEmptyStruct, OptionalField, UnknownFieldError,
+};
+pub use crate::filters::IntoMongoFilter;
+pub use crate::mongo_comparable::{MongoComparable, MongoDate, MongoOrdered};
 pub use crate::path::Path;
+pub use crate::updates::IntoMongoUpdate;
+
+// Export the builders and the pipeline stages
+pub use crate::filters::{FilterBuilder, FrozenFilter};
+pub use crate::pipeline::{limit_stage, match_stage, project_stage, skip_stage, sort_stage};
+pub use crate::projection::{BasicProjectionBuilder, ProjectionBuilder};
+pub use crate::sort::SortBuilder;
+pub use crate::updates::{UpdateBuilder, UpdateSpec};
+
+// Export the `empty` constructors, named after their builder
+// (as thin wrappers, so the diagnostics about the module functions are unchanged)
+
+/// Creates an empty filter builder, as [`filters::empty`] does.
+#[must_use]
+pub fn empty_filter<T>() -> FilterBuilder<T> {
+    crate::filters::empty()
+}
+
+/// Creates an empty projection builder, as [`projection::empty`] does.
+pub fn empty_projection<T>() -> BasicProjectionBuilder<T> {
+    crate::projection::empty()
+}
+
+/// Creates an empty sort builder, as [`sort::empty`] does.
+pub fn empty_sort<T>() -> SortBuilder<T> {
+    crate::sort::empty()
+}
+
+/// Creates an empty update builder, as [`updates::empty`] does.
+#[must_use]
+pub fn empty_update<T>() -> UpdateBuilder<T> {
+    crate::updates::empty()
+}
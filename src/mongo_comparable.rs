@@ -13,3 +13,16 @@ pub trait MongoComparable<A, B> {}
 /// stricter ordering semantics while keeping broader comparability for
 /// operators like `$eq` and `$ne`.
 pub trait MongoOrdered<A, B> {}
+
+/// Compile-time evidence that a field type stores a MongoDB date.
+///
+/// It's implemented for `bson::DateTime`, the type serialized as a BSON date by default
+/// (and its optional version), and allows APIs to restrict operations to date fields.
+/// Neither `std::time::SystemTime` nor `chrono::DateTime` is one, as serde serializes them
+/// respectively as a `{ secs_since_epoch, nanos_since_epoch }` struct and an RFC 3339 string,
+/// but both can be used as a value for a date field (e.g. with `set_date`).
+pub trait MongoDate {}
+
+impl MongoDate for bson::DateTime {}
+
+impl<D: MongoDate> MongoDate for Option<D> {}
@@ -158,6 +158,97 @@ impl<F: FieldName, T: HasField<F>, Root> Path<F, T, Root> {
             _marker: std::marker::PhantomData,
         }
     }
+
+    /// Navigates to a field of the documents within an array field.
+    ///
+    /// MongoDB dot notation applies to each element of an array of embedded documents,
+    /// so this produces the same path as `field` (e.g. `"items.name"`), but checks that
+    /// `G` is a field of the element type rather than of the array type itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::path::Path;
+    /// use tnuctipun::FieldWitnesses;
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Item {
+    ///     pub name: String,
+    /// }
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Order {
+    ///     pub items: Vec<Item>,
+    /// }
+    ///
+    /// let items_path = Path::<order_fields::Items, Order, Order>::new();
+    ///
+    /// // This creates a path representing "items.name"
+    /// let name_path = items_path.element_field::<item_fields::Name>();
+    /// ```
+    pub fn element_field<G: FieldName>(&self) -> Path<G, <T::Value as IntoIterator>::Item, Root>
+    where
+        T::Value: IntoIterator,
+        <T::Value as IntoIterator>::Item: HasField<G>,
+    {
+        let mut prefix = self.prefix.clone();
+
+        prefix.push(F::field_name().to_string());
+
+        Path {
+            prefix,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Navigates to a field of the array element matched by the query, using the
+    /// positional operator `$`.
+    ///
+    /// It's like `element_field`, but with the `$` segment appended after the array field
+    /// (e.g. `"items.$.name"`), so the updates only apply to the first element matching
+    /// the query filter (which must include a condition on the array field).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Item {
+    ///     pub status: String,
+    /// }
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Order {
+    ///     pub items: Vec<Item>,
+    /// }
+    ///
+    /// let update = empty::<Order>()
+    ///     .with_lookup::<order_fields::Items, _, item_fields::Status, Item, _>(
+    ///         |path| path.positional::<item_fields::Status>(),
+    ///         |nested| {
+    ///             nested.set::<item_fields::Status, _>("shipped".to_string());
+    ///         },
+    ///     )
+    ///     .build();
+    ///
+    /// assert_eq!(update, bson::doc! { "$set": { "items.$.status": "shipped" } });
+    /// ```
+    pub fn positional<G: FieldName>(&self) -> Path<G, <T::Value as IntoIterator>::Item, Root>
+    where
+        T::Value: IntoIterator,
+        <T::Value as IntoIterator>::Item: HasField<G>,
+    {
+        let mut prefix = self.prefix.clone();
+
+        prefix.push(F::field_name().to_string());
+        prefix.push("$".to_string());
+
+        Path {
+            prefix,
+            _marker: std::marker::PhantomData,
+        }
+    }
 }
 
 impl<F: FieldName, T: HasField<F>, Root> Default for Path<F, T, Root> {
@@ -0,0 +1,54 @@
+//! Aggregation pipeline stages built from the typed builders.
+//!
+//! Each function returns a stage `bson::Document`, so a read pipeline can be assembled
+//! as a `Vec<bson::Document>` and passed to `Collection::aggregate`.
+//!
+//! ```rust
+//! use tnuctipun::{FieldWitnesses, MongoComparable, filters, pipeline, projection, sort};
+//!
+//! #[derive(FieldWitnesses, MongoComparable)]
+//! struct User {
+//!     pub name: String,
+//!     pub age: i32,
+//! }
+//!
+//! let stages = vec![
+//!     pipeline::match_stage(filters::empty::<User>().gte::<user_fields::Age, _>(18)),
+//!     pipeline::sort_stage(sort::empty::<User>().asc::<user_fields::Name>()),
+//!     pipeline::skip_stage(20),
+//!     pipeline::limit_stage(10),
+//!     pipeline::project_stage(projection::empty::<User>().includes::<user_fields::Name>()),
+//! ];
+//!
+//! assert_eq!(stages[0], bson::doc! { "$match": { "age": { "$gte": 18 } } });
+//! // collection.aggregate(stages).await?;
+//! ```
+
+use crate::filters::FilterBuilder;
+use crate::projection::BasicProjectionBuilder;
+use crate::sort::SortBuilder;
+
+/// Creates a `$match` stage from the filter combined with `FilterBuilder::and`.
+pub fn match_stage<T>(filter: &FilterBuilder<T>) -> bson::Document {
+    bson::doc! { "$match": filter.and() }
+}
+
+/// Creates a `$sort` stage from the sort keys of the builder.
+pub fn sort_stage<T>(sort: &mut SortBuilder<T>) -> bson::Document {
+    bson::doc! { "$sort": sort.build() }
+}
+
+/// Creates a `$project` stage from the projection builder.
+pub fn project_stage<T>(projection: &mut BasicProjectionBuilder<T>) -> bson::Document {
+    bson::doc! { "$project": projection.build() }
+}
+
+/// Creates a `$limit` stage.
+pub fn limit_stage(limit: i64) -> bson::Document {
+    bson::doc! { "$limit": limit }
+}
+
+/// Creates a `$skip` stage (saturated to `i64::MAX`, as BSON has no unsigned 64-bit integer).
+pub fn skip_stage(skip: u64) -> bson::Document {
+    bson::doc! { "$skip": i64::try_from(skip).unwrap_or(i64::MAX) }
+}
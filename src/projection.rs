@@ -1,6 +1,7 @@
 use crate::expr::Expr;
-use crate::field_witnesses::{FieldName, HasField};
+use crate::field_witnesses::{FieldName, FieldNames, HasField, UnknownFieldError};
 use crate::path::Path;
+use crate::sort::SortBuilder;
 use bson;
 
 /// A builder for creating MongoDB projection documents with compile-time field verification.
@@ -50,7 +51,7 @@ use bson;
 pub struct BasicProjectionBuilder<T> {
     prefix: Vec<String>,
     clauses: Vec<(String, bson::Bson)>,
-    _marker: std::marker::PhantomData<T>,
+    _marker: std::marker::PhantomData<fn() -> T>,
 }
 
 impl<T> Default for BasicProjectionBuilder<T> {
@@ -88,6 +89,58 @@ impl<T> BasicProjectionBuilder<T> {
         }
     }
 
+    /// Creates a `BasicProjectionBuilder` including the given runtime field names.
+    ///
+    /// Each name is validated against the witnessed fields of `T` (see `FieldNames`),
+    /// which makes it suitable for field lists received from clients (e.g. an HTTP API).
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - The MongoDB field names to be included
+    ///
+    /// # Returns
+    ///
+    /// The builder including all the given fields, or an `UnknownFieldError` for the first
+    /// name that is not a witnessed field of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, projection::BasicProjectionBuilder};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub email: String,
+    /// }
+    ///
+    /// let doc = BasicProjectionBuilder::<User>::from_field_names(&["name", "email"])
+    ///     .unwrap()
+    ///     .build();
+    /// // Results in: { "name": 1, "email": 1 }
+    ///
+    /// let error = BasicProjectionBuilder::<User>::from_field_names(&["name", "password"]);
+    ///
+    /// assert_eq!(error.err().map(|e| e.name().to_string()), Some("password".to_string()));
+    /// ```
+    pub fn from_field_names(names: &[&str]) -> Result<Self, UnknownFieldError>
+    where
+        T: FieldNames,
+    {
+        let known = T::field_names();
+        let mut builder = Self::new();
+
+        for name in names {
+            if !known.contains(name) {
+                return Err(UnknownFieldError::new(name));
+            }
+
+            builder.clauses.push((name.to_string(), 1.into()));
+        }
+
+        Ok(builder)
+    }
+
     /// Returns a fully qualified field path for the given field name marker type.
     /// Returns a fully qualified field path for the given field name marker type.
     ///
@@ -242,6 +295,379 @@ impl<T> BasicProjectionBuilder<T> {
         self.project_field::<F>(false)
     }
 
+    /// Limits the number of elements of an array field returned by the query.
+    ///
+    /// This adds a `{ field: { "$slice": count } }` clause: a positive count returns the
+    /// first elements of the array, while a negative one returns the last elements
+    /// (e.g. `-3` for the last 3 elements). It's only allowed on collection fields.
+    /// See `slice_range` to skip some elements first.
+    ///
+    /// For arrays of embedded documents, the slice can be combined with projections on
+    /// the element fields (e.g. using `with_lookup` and `Path::element_field`).
+    ///
+    /// # MongoDB restrictions
+    ///
+    /// * Starting with MongoDB 4.4, a `find` projection cannot contain both a `$slice` of
+    ///   an array and a projection of a field embedded in this array (path collision);
+    ///   such a combination is only accepted by earlier servers, or in an aggregation
+    ///   `$project` stage using the `$slice` expression.
+    /// * In an inclusion projection, `$slice` alone doesn't exclude the other fields.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type for an array field
+    ///
+    /// # Parameters
+    ///
+    /// * `count` - The number of elements to return, from the end if negative
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, projection::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Post {
+    ///     pub title: String,
+    ///     pub comments: Vec<String>,
+    /// }
+    ///
+    /// let doc = empty::<Post>()
+    ///     .includes::<post_fields::Title>()
+    ///     .slice::<post_fields::Comments>(5)
+    ///     .build();
+    /// // Results in: { "title": 1, "comments": { "$slice": 5 } }
+    /// ```
+    ///
+    /// It cannot be used on scalar fields:
+    ///
+    /// ```compile_fail
+    /// use tnuctipun::{FieldWitnesses, projection::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Post {
+    ///     pub title: String,
+    /// }
+    ///
+    /// empty::<Post>().slice::<post_fields::Title>(5);
+    /// ```
+    pub fn slice<F: FieldName>(&mut self, count: i32) -> &mut Self
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses
+            .push((path, bson::doc! { "$slice": count }.into()));
+
+        self
+    }
+
+    /// Returns a range of the elements of an array field, skipping some first.
+    ///
+    /// This adds a `{ field: { "$slice": [skip, limit] } }` clause: the `limit` elements
+    /// following the `skip` first ones are returned. A negative `skip` counts from the end of
+    /// the array (e.g. `-5` starts at the fifth element from the end), but the `limit` must be
+    /// positive, as MongoDB rejects the other values.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type for an array field
+    ///
+    /// # Parameters
+    ///
+    /// * `skip` - The number of elements to skip, from the end if negative
+    /// * `limit` - The number of elements to return, which must be positive
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining, or an `InvalidSliceError`
+    /// if the `limit` is not positive (in which case no clause is added).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, projection::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Post {
+    ///     pub comments: Vec<String>,
+    /// }
+    ///
+    /// let doc = empty::<Post>()
+    ///     .slice_range::<post_fields::Comments>(20, 10)
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(doc, bson::doc! { "comments": { "$slice": [20, 10] } });
+    ///
+    /// assert!(empty::<Post>().slice_range::<post_fields::Comments>(20, 0).is_err());
+    /// ```
+    pub fn slice_range<F: FieldName>(
+        &mut self,
+        skip: i32,
+        limit: i32,
+    ) -> Result<&mut Self, InvalidSliceError>
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator,
+    {
+        if limit <= 0 {
+            return Err(InvalidSliceError { limit });
+        }
+
+        let path = self.field_path::<F>();
+
+        self.clauses
+            .push((path, bson::doc! { "$slice": [skip, limit] }.into()));
+
+        Ok(self)
+    }
+
+    /// Projects the text search relevance score into a field (`{ field: { "$meta": "textScore" } }`).
+    ///
+    /// The score is only available for queries using a `$text` filter.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`, for the score field
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, projection::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Article {
+    ///     pub title: String,
+    ///     pub score: Option<f64>,
+    /// }
+    ///
+    /// let doc = empty::<Article>()
+    ///     .includes::<article_fields::Title>()
+    ///     .text_score::<article_fields::Score>()
+    ///     .build();
+    /// // Results in: { "title": 1, "score": { "$meta": "textScore" } }
+    /// ```
+    pub fn text_score<F: FieldName>(&mut self) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses
+            .push((path, bson::doc! { "$meta": "textScore" }.into()));
+
+        self
+    }
+
+    /// Projects the text search score into a field, and sorts by relevance on the same field.
+    ///
+    /// This combines [`text_score`](Self::text_score) with [`SortBuilder::text_score`] on the
+    /// given sort builder, which is the usual read pattern of text search results (MongoDB
+    /// versions before 4.4 require the `$meta` sort key to match the projected field).
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`, for the score field
+    ///
+    /// # Parameters
+    ///
+    /// * `sort` - The sort builder to add the relevance sort key to
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, projection, sort};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Article {
+    ///     pub title: String,
+    ///     pub score: Option<f64>,
+    /// }
+    ///
+    /// let mut sort_builder = sort::empty::<Article>();
+    /// let projection_doc = projection::empty::<Article>()
+    ///     .includes::<article_fields::Title>()
+    ///     .with_meta_sort::<article_fields::Score>(&mut sort_builder)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     projection_doc,
+    ///     bson::doc! { "title": 1, "score": { "$meta": "textScore" } }
+    /// );
+    /// assert_eq!(
+    ///     sort_builder.build(),
+    ///     bson::doc! { "score": { "$meta": "textScore" } }
+    /// );
+    /// ```
+    pub fn with_meta_sort<F: FieldName>(&mut self, sort: &mut SortBuilder<T>) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        sort.text_score::<F>();
+
+        self.text_score::<F>()
+    }
+
+    /// Includes each witnessed field of `T` whose MongoDB name passes the predicate.
+    ///
+    /// This iterates the names listed by `FieldNames`, in declaration order, which allows
+    /// building a projection from a runtime selection.
+    ///
+    /// # Parameters
+    ///
+    /// * `name_present` - The predicate deciding whether a field name is included
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, projection::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub billing_street: String,
+    ///     pub billing_city: String,
+    /// }
+    ///
+    /// let doc = empty::<User>()
+    ///     .include_if(|name| name.starts_with("billing_"))
+    ///     .build();
+    ///
+    /// assert_eq!(doc, bson::doc! { "billing_street": 1, "billing_city": 1 });
+    /// ```
+    pub fn include_if(&mut self, name_present: impl Fn(&str) -> bool) -> &mut Self
+    where
+        T: FieldNames,
+    {
+        for name in T::field_names() {
+            if name_present(name) {
+                let path = if self.prefix.is_empty() {
+                    name.to_string()
+                } else {
+                    format!("{}.{}", self.prefix.join("."), name)
+                };
+
+                self.clauses.push((path, 1.into()));
+            }
+        }
+
+        self
+    }
+
+    /// Excludes the given runtime field names, returning all the other fields.
+    ///
+    /// Each name is validated against the witnessed fields of `T` (see `FieldNames`),
+    /// as for `from_field_names`, so a typo doesn't silently return the field.
+    /// The builder is unchanged if some name is unknown.
+    ///
+    /// # Parameters
+    ///
+    /// * `names` - The MongoDB field names to be excluded
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining, or an `UnknownFieldError` for the
+    /// first name that is not a witnessed field of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, projection::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub password: String,
+    ///     pub token: String,
+    /// }
+    ///
+    /// let doc = empty::<User>()
+    ///     .exclude_only(&["password", "token"])
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// assert_eq!(doc, bson::doc! { "password": 0, "token": 0 });
+    ///
+    /// let error = empty::<User>().exclude_only(&["secret"]).err();
+    ///
+    /// assert_eq!(error.map(|e| e.name().to_string()), Some("secret".to_string()));
+    /// ```
+    pub fn exclude_only(&mut self, names: &[&str]) -> Result<&mut Self, UnknownFieldError>
+    where
+        T: FieldNames,
+    {
+        let known = T::field_names();
+
+        if let Some(name) = names.iter().find(|name| !known.contains(name)) {
+            return Err(UnknownFieldError::new(name));
+        }
+
+        for name in names {
+            let path = if self.prefix.is_empty() {
+                name.to_string()
+            } else {
+                format!("{}.{}", self.prefix.join("."), name)
+            };
+
+            self.clauses.push((path, 0.into()));
+        }
+
+        Ok(self)
+    }
+
+    /// Restricts the projection to the document `_id` only.
+    ///
+    /// This clears any previously projected field and sets `{ "_id": 1 }`, minimizing the
+    /// transferred data for existence checks. Calling it several times is idempotent.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, projection::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub email: String,
+    /// }
+    ///
+    /// let doc = empty::<User>()
+    ///     .includes::<user_fields::Name>()
+    ///     .only_id()
+    ///     .build();
+    ///
+    /// assert_eq!(doc, bson::doc! { "_id": 1 });
+    /// ```
+    pub fn only_id(&mut self) -> &mut Self {
+        self.clauses.clear();
+        self.clauses.push(("_id".to_string(), 1.into()));
+
+        self
+    }
+
     /// Performs nested field projection using a lookup function.
     ///
     /// This method enables projection on nested object fields by providing a way to
@@ -419,12 +845,17 @@ impl<T> BasicProjectionBuilder<T> {
 
     /// Builds the final MongoDB projection document.
     ///
-    /// This method consumes the builder and produces a `bson::Document` that can be
-    /// used directly with MongoDB queries. All accumulated projection clauses are
-    /// combined into a single document.
+    /// This method produces a `bson::Document` that can be used directly with MongoDB
+    /// queries. All accumulated projection clauses are combined into a single document.
+    ///
+    /// The builder is left unchanged, so this method can be called multiple times,
+    /// always producing the same document.
     ///
-    /// Note that this method takes ownership of the builder (`self`), so it cannot
-    /// be called multiple times on the same builder instance.
+    /// # Key Order
+    ///
+    /// The clauses are kept in insertion order, so the fields of the document are in the
+    /// order of the method calls, whatever the number of builds (e.g. for snapshot tests).
+    /// A field projected again keeps its first position.
     ///
     /// # Returns
     ///
@@ -480,8 +911,34 @@ impl<T> BasicProjectionBuilder<T> {
             doc.insert(field.clone(), value.clone());
         }
 
+        #[cfg(feature = "tracing")]
+        crate::instrumentation::trace_built("projection", &doc);
+
         doc
     }
+
+    /// Builds the projection as `build()` does, and sets it in the driver `FindOptions`.
+    ///
+    /// The other options (e.g. `sort` using a `SortBuilder`) can then be set on the returned
+    /// value. Requires the `mongodb` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let mut options = empty::<User>()
+    ///     .includes::<user_fields::Name>()
+    ///     .into_find_options();
+    ///
+    /// options.sort = Some(sort::empty::<User>().asc::<user_fields::Name>().build());
+    ///
+    /// // collection.find(filter).with_options(options).await?;
+    /// ```
+    #[cfg(feature = "mongodb")]
+    pub fn into_find_options(mut self) -> mongodb::options::FindOptions {
+        mongodb::options::FindOptions::builder()
+            .projection(self.build())
+            .build()
+    }
 }
 
 /// Creates a new empty `BasicProjectionBuilder` instance.
@@ -619,6 +1076,31 @@ impl<T> ProjectionBuilder<T> for BasicProjectionBuilder<T> {
     }
 }
 
+/// Error raised by [`BasicProjectionBuilder::slice_range`] when the limit is not positive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidSliceError {
+    limit: i32,
+}
+
+impl InvalidSliceError {
+    /// Returns the invalid limit
+    pub fn limit(&self) -> i32 {
+        self.limit
+    }
+}
+
+impl std::fmt::Display for InvalidSliceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid slice: the limit must be positive, got {}",
+            self.limit
+        )
+    }
+}
+
+impl std::error::Error for InvalidSliceError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,385 @@
+use crate::field_witnesses::{FieldName, FieldNames, HasField, UnknownFieldError};
+use crate::path::Path;
+use bson;
+
+/// A builder for creating MongoDB sort documents with compile-time field verification.
+///
+/// `SortBuilder` provides a fluent API for constructing MongoDB sort specifications
+/// (e.g. for `find` options or a `$sort` stage), ensuring that only fields that exist
+/// on the target struct `T` can be used as sort keys.
+///
+/// # Type Parameters
+///
+/// * `T` - The target struct type that implements the necessary field witness traits
+///
+/// # Examples
+///
+/// ```rust
+/// use tnuctipun::{FieldWitnesses, sort::empty};
+///
+/// #[derive(FieldWitnesses)]
+/// struct User {
+///     pub name: String,
+///     pub age: i32,
+/// }
+///
+/// let sort_doc = empty::<User>()
+///     .desc::<user_fields::Age>()
+///     .asc::<user_fields::Name>()
+///     .build();
+/// // Results in: { "age": -1, "name": 1 }
+/// ```
+///
+/// # Sort Key Order
+///
+/// MongoDB applies the sort keys in the order of the document, which is the order
+/// of the method calls.
+pub struct SortBuilder<T> {
+    prefix: Vec<String>,
+    clauses: Vec<(String, bson::Bson)>,
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T> Default for SortBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SortBuilder<T> {
+    /// Creates a new `SortBuilder` instance, without any sort key.
+    ///
+    /// # Returns
+    ///
+    /// A new `SortBuilder` instance ready for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::sort::SortBuilder;
+    ///
+    /// struct User {
+    ///     pub name: String,
+    /// }
+    ///
+    /// let builder = SortBuilder::<User>::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            prefix: Vec::new(),
+            clauses: Vec::new(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Creates a `SortBuilder` from a runtime sort specification (e.g. `"-age,name"`).
+    ///
+    /// The specification is a comma-separated list of MongoDB field names, each sorted in
+    /// ascending order unless prefixed by `-` (descending). Each name is validated against
+    /// the witnessed fields of `T` (see `FieldNames`), which makes it suitable for the sort
+    /// parameters received from clients (e.g. an HTTP API).
+    ///
+    /// # Arguments
+    ///
+    /// * `spec` - The sort specification; the whitespaces around the names are ignored
+    ///
+    /// # Returns
+    ///
+    /// The builder with the sort keys in the specification order, or an `UnknownFieldError`
+    /// for the first name that is not a witnessed field of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, sort::SortBuilder};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub age: i32,
+    /// }
+    ///
+    /// let sort_doc = SortBuilder::<User>::parse("-age,name").unwrap().build();
+    ///
+    /// assert_eq!(sort_doc, bson::doc! { "age": -1, "name": 1 });
+    ///
+    /// let error = SortBuilder::<User>::parse("name,-password");
+    ///
+    /// assert_eq!(error.err().map(|e| e.name().to_string()), Some("password".to_string()));
+    /// ```
+    pub fn parse(spec: &str) -> Result<Self, UnknownFieldError>
+    where
+        T: FieldNames,
+    {
+        let known = T::field_names();
+        let mut builder = Self::new();
+
+        for key in spec.split(',').map(str::trim).filter(|key| !key.is_empty()) {
+            let (name, direction) = match key.strip_prefix('-') {
+                Some(name) => (name.trim_start(), -1),
+                None => (key, 1),
+            };
+
+            if !known.contains(&name) {
+                return Err(UnknownFieldError::new(name));
+            }
+
+            builder.clauses.push((name.to_string(), direction.into()));
+        }
+
+        Ok(builder)
+    }
+
+    /// Returns a fully qualified field path for the given field name marker type.
+    fn field_path<F: FieldName>(&self) -> String {
+        if self.prefix.is_empty() {
+            F::field_name().to_string()
+        } else {
+            format!("{}.{}", self.prefix.join("."), F::field_name())
+        }
+    }
+
+    /// Sorts on a field in ascending order (`{ field: 1 }`).
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, sort::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub name: String,
+    /// }
+    ///
+    /// let sort_doc = empty::<User>().asc::<user_fields::Name>().build();
+    /// // Results in: { "name": 1 }
+    /// ```
+    pub fn asc<F: FieldName>(&mut self) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses.push((path, 1.into()));
+
+        self
+    }
+
+    /// Sorts on a field in descending order (`{ field: -1 }`).
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, sort::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub age: i32,
+    /// }
+    ///
+    /// let sort_doc = empty::<User>().desc::<user_fields::Age>().build();
+    /// // Results in: { "age": -1 }
+    /// ```
+    pub fn desc<F: FieldName>(&mut self) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses.push((path, (-1).into()));
+
+        self
+    }
+
+    /// Sorts by text search relevance, on the field projecting the score
+    /// (`{ field: { "$meta": "textScore" } }`).
+    ///
+    /// The score is only available for queries using a `$text` filter, and the results
+    /// are sorted by descending relevance. See also `BasicProjectionBuilder::with_meta_sort`.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`, for the score field
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, sort::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Article {
+    ///     pub score: Option<f64>,
+    ///     pub published_at: i64,
+    /// }
+    ///
+    /// let sort_doc = empty::<Article>()
+    ///     .text_score::<article_fields::Score>()
+    ///     .desc::<article_fields::PublishedAt>()
+    ///     .build();
+    /// // Results in: { "score": { "$meta": "textScore" }, "published_at": -1 }
+    /// ```
+    pub fn text_score<F: FieldName>(&mut self) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        let path = self.field_path::<F>();
+
+        self.clauses
+            .push((path, bson::doc! { "$meta": "textScore" }.into()));
+
+        self
+    }
+
+    /// Sorts on nested fields using a lookup function.
+    ///
+    /// # Parameters
+    ///
+    /// * `lookup` - A function that takes a `Path<F, T, T>` and returns a `Path<G, U, T>`,
+    ///   defining how to navigate from the current context to the target nested field
+    /// * `f` - A function that configures the sort keys on the nested structure
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, sort::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Address {
+    ///     pub city: String,
+    /// }
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub address: Address,
+    /// }
+    ///
+    /// let sort_doc = empty::<User>()
+    ///     .with_lookup::<user_fields::Address, _, address_fields::City, Address, _>(
+    ///         |path| path.field::<address_fields::City>(),
+    ///         |nested| { nested.asc::<address_fields::City>(); }
+    ///     )
+    ///     .build();
+    /// // Results in: { "address.city": 1 }
+    /// ```
+    pub fn with_lookup<F: FieldName, L, G: FieldName, U: HasField<G>, N>(
+        &mut self,
+        lookup: L,
+        f: N,
+    ) -> &mut Self
+    where
+        T: HasField<F>,
+        L: FnOnce(&Path<F, T, T>) -> Path<G, U, T>,
+        N: FnOnce(&mut SortBuilder<U>),
+    {
+        let base_field: Path<F, T, T> = Path {
+            prefix: self.prefix.clone(),
+            _marker: std::marker::PhantomData,
+        };
+
+        let resolved_field = lookup(&base_field);
+
+        let mut nested_builder = SortBuilder::<U> {
+            prefix: resolved_field.prefix.clone(),
+            clauses: vec![],
+            _marker: std::marker::PhantomData,
+        };
+
+        f(&mut nested_builder);
+
+        self.clauses.extend(nested_builder.clauses);
+
+        self
+    }
+
+    /// Builds the final MongoDB sort document.
+    ///
+    /// The sort keys are in the order they were added. If the same field is added
+    /// several times, it keeps its first position with the last direction.
+    ///
+    /// # Returns
+    ///
+    /// A `bson::Document` containing all the sort keys.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, sort::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub age: i32,
+    /// }
+    ///
+    /// let sort_doc = empty::<User>()
+    ///     .asc::<user_fields::Age>()
+    ///     .asc::<user_fields::Name>()
+    ///     .build();
+    ///
+    /// let keys: Vec<&String> = sort_doc.keys().collect();
+    ///
+    /// assert_eq!(keys, vec!["age", "name"]);
+    ///
+    /// // collection.find(filter).sort(sort_doc).await?;
+    /// ```
+    pub fn build(&mut self) -> bson::Document {
+        let mut doc = bson::Document::new();
+
+        for (field, value) in &self.clauses {
+            doc.insert(field.clone(), value.clone());
+        }
+
+        doc
+    }
+}
+
+/// Creates a new empty `SortBuilder` instance.
+///
+/// # Type Parameters
+///
+/// * `T` - The target struct type that implements the necessary field witness traits
+///
+/// # Returns
+///
+/// A new `SortBuilder<T>` instance ready for method chaining.
+///
+/// # Examples
+///
+/// ```rust
+/// use tnuctipun::{FieldWitnesses, sort::empty};
+///
+/// #[derive(FieldWitnesses)]
+/// struct User {
+///     pub name: String,
+/// }
+///
+/// let sort_doc = empty::<User>().asc::<user_fields::Name>().build();
+/// // Results in: { "name": 1 }
+/// ```
+pub fn empty<T>() -> SortBuilder<T> {
+    SortBuilder::new()
+}
@@ -1,15 +1,32 @@
 use bson;
-use num_traits::Num;
+use num_traits::{Num, PrimInt};
 use std::collections::HashMap;
+use std::ops::Neg;
 
 use crate::expr::Expr;
-use crate::field_witnesses::{FieldName, HasField};
+use crate::field_witnesses::{FieldName, HasField, OptionalField};
+use crate::filters::FilterBuilder;
+use crate::mongo_comparable::MongoDate;
 use crate::path::Path;
 
 pub struct UpdateBuilder<T> {
     pub prefix: Vec<String>,
     clauses: HashMap<UpdateOperation, Vec<(String, bson::Bson)>>,
-    _marker: std::marker::PhantomData<T>,
+    array_filters: Vec<bson::Document>,
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+/// A MongoDB update document along with the array filters it requires.
+///
+/// It's returned by `UpdateBuilder::build_spec`, so that both parts can be passed
+/// to the driver (the array filters through `UpdateOptions::array_filters`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateSpec {
+    /// The update document, as returned by `UpdateBuilder::build`
+    pub update: bson::Document,
+
+    /// The filters for the `$[identifier]` positional operators used in the update
+    pub array_filters: Vec<bson::Document>,
 }
 
 impl<T> Default for UpdateBuilder<T> {
@@ -41,14 +58,54 @@ impl<T> UpdateBuilder<T> {
     ///
     /// let builder = UpdateBuilder::<User>::new();
     /// ```
+    #[must_use]
     pub fn new() -> Self {
         UpdateBuilder {
             prefix: Vec::new(),
             clauses: HashMap::new(),
+            array_filters: Vec::new(),
             _marker: std::marker::PhantomData,
         }
     }
 
+    /// Creates a new `UpdateBuilder` whose field paths are prefixed by the given path.
+    ///
+    /// This is for the nested contexts whose dotted path is already known (e.g. an element
+    /// `items.$` matched by the query), instead of mutating `prefix`, while `with_lookup`
+    /// resolves the path from the witnesses. The prefix itself is not verified against `T`.
+    ///
+    /// # Parameters
+    ///
+    /// * `prefix` - The path segments, prepended to each field name (e.g. `["home_address"]`)
+    ///
+    /// # Returns
+    ///
+    /// A new `UpdateBuilder` instance ready for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::UpdateBuilder};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Address {
+    ///     pub city: String,
+    /// }
+    ///
+    /// let update_doc = UpdateBuilder::<Address>::with_prefix(vec!["home_address".to_string()])
+    ///     .set::<address_fields::City, _>("Lyon")
+    ///     .build();
+    ///
+    /// assert_eq!(update_doc, bson::doc! { "$set": { "home_address.city": "Lyon" } });
+    /// ```
+    #[must_use]
+    pub fn with_prefix(prefix: Vec<String>) -> Self {
+        UpdateBuilder {
+            prefix,
+            ..Self::new()
+        }
+    }
+
     /// Returns a fully qualified field path for the given field name marker type.
     ///
     /// This method constructs the complete dot-notation path for a field by combining
@@ -140,28 +197,21 @@ impl<T> UpdateBuilder<T> {
         self
     }
 
-    /// Sets the value of a field using a typed MongoDB expression.
-    ///
-    /// While [`set`](Self::set) can also accept an expression (because `Expr<T, V>`
-    /// converts into `bson::Bson`), this method is often safer and clearer at call sites:
-    /// it explicitly requires `Expr<T, V>`, ensuring the expression root type matches
-    /// this `UpdateBuilder<T>`.
-    pub fn set_expr<F: FieldName, V>(&mut self, expr: Expr<T, V>) -> &mut Self
-    where
-        T: HasField<F>,
-    {
-        self.set::<F, _>(expr)
-    }
-
-    /// Removes a field from the document.
+    /// Sets the value of a field only when an upsert inserts a new document (`$setOnInsert`).
     ///
-    /// This method corresponds to MongoDB's `$unset` operator, which deletes a particular field.
-    /// The operation removes the field completely from the document. If the field does not exist,
-    /// the operation has no effect but is not considered an error.
+    /// The existing documents are left unchanged, which allows initializing the fields of
+    /// an upserted document (e.g. a creation date) along with the `$set` of the updated ones.
+    /// As MongoDB rejects a path both in `$set` and `$setOnInsert` (or conflicting with the
+    /// other, such as `a` and `a.b`), `try_build` reports such conflicts.
     ///
     /// # Type Parameters
     ///
     /// * `F` - A field name marker type that implements `FieldName`
+    /// * `V` - A value type that can be converted into `bson::Bson`
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to set for the field on insert
     ///
     /// # Returns
     ///
@@ -170,94 +220,116 @@ impl<T> UpdateBuilder<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct TempData {
-    ///     pub temp_data: String,
-    ///     pub obsolete_field: i32,
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub created_by: String,
     /// }
     ///
-    /// let update_doc = empty::<TempData>()
-    ///     .unset::<tempdata_fields::TempData>()
-    ///     .unset::<tempdata_fields::ObsoleteField>()
+    /// let update_doc = empty::<User>()
+    ///     .set::<user_fields::Name, _>("Jane Doe")
+    ///     .set_on_insert::<user_fields::CreatedBy, _>("signup")
     ///     .build();
-    /// // Results in: { "$unset": { "temp_data": "", "obsolete_field": "" } }
+    ///
+    /// assert_eq!(
+    ///     update_doc,
+    ///     bson::doc! {
+    ///         "$set": { "name": "Jane Doe" },
+    ///         "$setOnInsert": { "created_by": "signup" }
+    ///     }
+    /// );
+    /// // collection.update_one(filter, update_doc).upsert(true).await?;
     /// ```
-    pub fn unset<F: FieldName>(&mut self) -> &mut Self
+    pub fn set_on_insert<F: FieldName, V: Into<bson::Bson>>(&mut self, value: V) -> &mut Self
     where
         T: HasField<F>,
     {
         let path = self.field_path::<F>();
 
-        self.push_clause(UpdateOperation::Unset, path, bson::Bson::Null);
+        self.push_clause(UpdateOperation::SetOnInsert, path, value.into());
 
         self
     }
 
-    /// Increments the value of a numeric field by the specified amount.
+    /// Sets the value of a field, failing if this path was already set by the builder.
     ///
-    /// This method corresponds to MongoDB's `$inc` operator, which increments a field by a specified value.
-    /// The field must contain a numeric value (integer, long, double, or decimal). If the field does not exist,
-    /// it is created with the increment value. If the field exists but is not numeric, the operation will fail.
+    /// Unlike `set`, which is last-wins, this catches accidental double-sets (e.g. in
+    /// generated updates). Only the previous `$set` clauses are checked (including those
+    /// added by `set`), and the builder is left unchanged on error.
     ///
     /// # Type Parameters
     ///
     /// * `F` - A field name marker type that implements `FieldName`
-    /// * `N` - A numeric type that implements `Num` and can be converted into `bson::Bson`
+    /// * `V` - A value type that can be converted into `bson::Bson`
     ///
     /// # Parameters
     ///
-    /// * `value` - The amount to increment the field by (can be negative for decrementing)
+    /// * `value` - The value to set for the field
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns `&mut Self` to allow method chaining.
+    /// Returns a `DuplicatePathError` if the field path is already set.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Stats {
-    ///     pub count: i32,
-    ///     pub score: i32,
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub name: String,
     /// }
     ///
-    /// let update_doc = empty::<Stats>()
-    ///     .inc::<stats_fields::Count, _>(1)
-    ///     .inc::<stats_fields::Score, _>(-5)
-    ///     .build();
-    /// // Results in: { "$inc": { "count": 1, "score": -5 } }
+    /// let mut builder = empty::<User>();
+    ///
+    /// assert!(builder.set_once::<user_fields::Name, _>("Jane").is_ok());
+    ///
+    /// let Err(error) = builder.set_once::<user_fields::Name, _>("John") else {
+    ///     panic!("Expected a duplicate path error");
+    /// };
+    ///
+    /// assert_eq!(error.path(), "name");
+    /// assert_eq!(builder.build(), bson::doc! { "$set": { "name": "Jane" } });
     /// ```
-    pub fn inc<F: FieldName, N: Num + Into<bson::Bson>>(&mut self, value: N) -> &mut Self
+    pub fn set_once<F: FieldName, V: Into<bson::Bson>>(
+        &mut self,
+        value: V,
+    ) -> Result<&mut Self, DuplicatePathError>
     where
         T: HasField<F>,
     {
         let path = self.field_path::<F>();
+        let already_set = self
+            .clauses
+            .get(&UpdateOperation::Set)
+            .is_some_and(|clauses| clauses.iter().any(|(p, _)| *p == path));
 
-        self.push_clause(UpdateOperation::Inc, path, value.into());
+        if already_set {
+            return Err(DuplicatePathError { path });
+        }
 
-        self
+        self.push_clause(UpdateOperation::Set, path, value.into());
+
+        Ok(self)
     }
 
-    /// Updates a field only if the specified value is greater than the existing field value.
+    /// Sets the value of a field only if it differs from the current one.
     ///
-    /// This method corresponds to MongoDB's `$max` operator, which only updates the field if the specified value
-    /// is greater than the existing field value. If the field does not exist, it sets the field to the specified value.
-    /// The field must contain a value that can be compared to the specified value, typically numeric types or dates.
+    /// This avoids writing unchanged fields (reducing write amplification) when the
+    /// current value is known, e.g. from a previously loaded document. If `new` equals
+    /// `current`, no `$set` clause is added.
     ///
     /// # Type Parameters
     ///
     /// * `F` - A field name marker type that implements `FieldName`
-    /// * `N` - A numeric type that implements `Num` and can be converted into `bson::Bson`
+    /// * `V` - A comparable value type that can be converted into `bson::Bson`
     ///
     /// # Parameters
     ///
-    /// * `value` - The value to compare against the existing field value
+    /// * `new` - The value to set for the field
+    /// * `current` - The current value of the field
     ///
     /// # Returns
     ///
@@ -266,46 +338,64 @@ impl<T> UpdateBuilder<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct HighScore {
-    ///     pub best_score: i32,
-    ///     pub max_level: i32,
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub age: i32,
     /// }
     ///
-    /// let update_doc = empty::<HighScore>()
-    ///     .max::<highscore_fields::BestScore, _>(1500)     // Only update if 1500 > current score
-    ///     .max::<highscore_fields::MaxLevel, _>(10)        // Only update if 10 > current max level
+    /// let current = User { name: "Jane".to_string(), age: 25 };
+    ///
+    /// let update_doc = empty::<User>()
+    ///     .set_if_changed::<user_fields::Name, _>("Jane".to_string(), &current.name)
+    ///     .set_if_changed::<user_fields::Age, _>(26, &current.age)
     ///     .build();
-    /// // Results in: { "$max": { "best_score": 1500, "max_level": 10 } }
+    ///
+    /// assert_eq!(update_doc, bson::doc! { "$set": { "age": 26 } });
     /// ```
-    pub fn max<F: FieldName, N: Num + Into<bson::Bson>>(&mut self, value: N) -> &mut Self
+    pub fn set_if_changed<F: FieldName, V: PartialEq + Into<bson::Bson>>(
+        &mut self,
+        new: V,
+        current: &V,
+    ) -> &mut Self
     where
         T: HasField<F>,
     {
-        let path = self.field_path::<F>();
-
-        self.push_clause(UpdateOperation::Max, path, value.into());
+        if new != *current {
+            self.set::<F, V>(new);
+        }
 
         self
     }
 
-    /// Updates a field only if the specified value is less than the existing field value.
+    /// Sets the value of a field using a typed MongoDB expression.
     ///
-    /// This method corresponds to MongoDB's `$min` operator, which only updates the field if the specified value
-    /// is less than the existing field value. If the field does not exist, it sets the field to the specified value.
-    /// The field must contain a value that can be compared to the specified value, typically numeric types or dates.
+    /// While [`set`](Self::set) can also accept an expression (because `Expr<T, V>`
+    /// converts into `bson::Bson`), this method is often safer and clearer at call sites:
+    /// it explicitly requires `Expr<T, V>`, ensuring the expression root type matches
+    /// this `UpdateBuilder<T>`.
+    pub fn set_expr<F: FieldName, V>(&mut self, expr: Expr<T, V>) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        self.set::<F, _>(expr)
+    }
+
+    /// Sets the value of a date field, from any value convertible into a BSON date.
+    ///
+    /// This accepts `std::time::SystemTime` values, and `chrono::DateTime` ones with the
+    /// `bson-chrono` feature, which are converted to `bson::DateTime` (millisecond precision).
     ///
     /// # Type Parameters
     ///
-    /// * `F` - A field name marker type that implements `FieldName`
-    /// * `N` - A numeric type that implements `Num` and can be converted into `bson::Bson`
+    /// * `F` - A field name marker type that implements `FieldName`, for a date field
+    /// * `D` - A value type that can be converted into `bson::DateTime`
     ///
     /// # Parameters
     ///
-    /// * `value` - The value to compare against the existing field value
+    /// * `value` - The date to set for the field
     ///
     /// # Returns
     ///
@@ -314,95 +404,105 @@ impl<T> UpdateBuilder<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
+    /// use std::time::{Duration, SystemTime};
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Pricing {
-    ///     pub lowest_price: f64,
-    ///     pub min_quantity: i32,
+    /// #[derive(FieldWitnesses)]
+    /// struct Session {
+    ///     pub expires_at: bson::DateTime,
     /// }
     ///
-    /// let update_doc = empty::<Pricing>()
-    ///     .min::<pricing_fields::LowestPrice, _>(29.99)    // Only update if 29.99 < current price
-    ///     .min::<pricing_fields::MinQuantity, _>(5)        // Only update if 5 < current min quantity
+    /// let expiration = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    /// let update_doc = empty::<Session>()
+    ///     .set_date::<session_fields::ExpiresAt, _>(expiration)
     ///     .build();
-    /// // Results in: { "$min": { "lowest_price": 29.99, "min_quantity": 5 } }
+    ///
+    /// assert_eq!(
+    ///     update_doc,
+    ///     bson::doc! { "$set": { "expires_at": bson::DateTime::from_millis(1_700_000_000_000) } }
+    /// );
     /// ```
-    pub fn min<F: FieldName, N: Num + Into<bson::Bson>>(&mut self, value: N) -> &mut Self
+    pub fn set_date<F: FieldName, D: Into<bson::DateTime>>(&mut self, value: D) -> &mut Self
     where
         T: HasField<F>,
+        T::Value: MongoDate,
     {
         let path = self.field_path::<F>();
 
-        self.push_clause(UpdateOperation::Min, path, value.into());
+        self.push_clause(UpdateOperation::Set, path, value.into().into());
 
         self
     }
 
-    /// Multiplies the value of a numeric field by the specified amount.
+    /// Sets the value of a field from any serde-serializable value.
     ///
-    /// This method corresponds to MongoDB's `$mul` operator, which multiplies the value of a field by a number.
-    /// The field must contain a numeric value (integer, long, double, or decimal). If the field does not exist,
-    /// it is created with a value of zero (0 * multiplier = 0). If the field exists but is not numeric, the operation will fail.
+    /// This complements [`set`](Self::set) for values having no conversion into `bson::Bson`,
+    /// such as enums or nested structs: the value is converted with `bson::serialize_to_bson`,
+    /// so its serde attributes (e.g. `rename_all`) are honored.
     ///
     /// # Type Parameters
     ///
     /// * `F` - A field name marker type that implements `FieldName`
-    /// * `N` - A numeric type that implements `Num` and can be converted into `bson::Bson`
+    /// * `V` - A value type that implements `serde::Serialize`
     ///
     /// # Parameters
     ///
-    /// * `value` - The multiplier to apply to the field value
+    /// * `value` - The value to set for the field
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns `&mut Self` to allow method chaining.
+    /// Returns an error if the value cannot be serialized to BSON.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    /// use serde::Serialize;
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Product {
-    ///     pub price: f64,
-    ///     pub quantity: i32,
+    /// #[derive(Serialize)]
+    /// #[serde(rename_all = "snake_case")]
+    /// enum Status {
+    ///     Active,
+    ///     Suspended { reason: String },
     /// }
     ///
-    /// let update_doc = empty::<Product>()
-    ///     .mul::<product_fields::Price, _>(1.1)      // Increase price by 10%
-    ///     .mul::<product_fields::Quantity, _>(2)     // Double the quantity
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub status: Status,
+    /// }
+    ///
+    /// let update_doc = empty::<User>()
+    ///     .set_serialized::<user_fields::Status, _>(&Status::Active)
+    ///     .unwrap()
     ///     .build();
-    /// // Results in: { "$mul": { "price": 1.1, "quantity": 2 } }
+    ///
+    /// assert_eq!(update_doc, bson::doc! { "$set": { "status": "active" } });
     /// ```
-    pub fn mul<F: FieldName, N: Num + Into<bson::Bson>>(&mut self, value: N) -> &mut Self
+    pub fn set_serialized<F: FieldName, V: serde::Serialize>(
+        &mut self,
+        value: &V,
+    ) -> bson::error::Result<&mut Self>
     where
         T: HasField<F>,
     {
         let path = self.field_path::<F>();
+        let value = bson::serialize_to_bson(value)?;
 
-        self.push_clause(UpdateOperation::Mul, path, value.into());
+        self.push_clause(UpdateOperation::Set, path, value);
 
-        self
+        Ok(self)
     }
 
-    /// Renames a field in the document.
+    /// Removes a field from the document.
     ///
-    /// This method corresponds to MongoDB's `$rename` operator, which renames a field.
-    /// The new field name must differ from the existing field name. If the target field name
-    /// already exists, its value will be overwritten. If the source field does not exist,
-    /// the operation has no effect.
+    /// This method corresponds to MongoDB's `$unset` operator, which deletes a particular field.
+    /// The operation removes the field completely from the document. If the field does not exist,
+    /// the operation has no effect but is not considered an error.
     ///
     /// # Type Parameters
     ///
     /// * `F` - A field name marker type that implements `FieldName`
     ///
-    /// # Parameters
-    ///
-    /// * `new_name` - The new name for the field
-    ///
     /// # Returns
     ///
     /// Returns `&mut Self` to allow method chaining.
@@ -414,46 +514,37 @@ impl<T> UpdateBuilder<T> {
     /// use serde::{Serialize, Deserialize};
     ///
     /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Document {
-    ///     pub old_name: String,
-    ///     pub legacy_field: String,
+    /// struct TempData {
+    ///     pub temp_data: String,
+    ///     pub obsolete_field: i32,
     /// }
     ///
-    /// let update_doc = empty::<Document>()
-    ///     .rename::<document_fields::OldName>("new_name")
-    ///     .rename::<document_fields::LegacyField>("modern_field")
+    /// let update_doc = empty::<TempData>()
+    ///     .unset::<tempdata_fields::TempData>()
+    ///     .unset::<tempdata_fields::ObsoleteField>()
     ///     .build();
-    /// // Results in: { "$rename": { "old_name": "new_name", "legacy_field": "modern_field" } }
+    /// // Results in: { "$unset": { "temp_data": "", "obsolete_field": "" } }
     /// ```
-    pub fn rename<F: FieldName>(&mut self, new_name: &str) -> &mut Self
+    pub fn unset<F: FieldName>(&mut self) -> &mut Self
     where
         T: HasField<F>,
     {
         let path = self.field_path::<F>();
 
-        self.push_clause(
-            UpdateOperation::Rename,
-            path,
-            bson::Bson::String(new_name.to_string()),
-        );
+        self.push_clause(UpdateOperation::Unset, path, bson::Bson::Null);
 
         self
     }
 
-    /// Sets the value of a field to the current date.
+    /// Removes a field which can be missing from the document.
     ///
-    /// This method corresponds to MongoDB's `$currentDate` operator, which sets the value of a field
-    /// to the current date, either as a BSON Date or a BSON Timestamp. The default type is Date.
-    /// This is useful for tracking when documents were last modified.
+    /// Same as `unset`, but only for the fields implementing `OptionalField` (`Option` fields,
+    /// or fields with a serde default), so the updated documents can still be deserialized.
     ///
     /// # Type Parameters
     ///
     /// * `F` - A field name marker type that implements `FieldName`
     ///
-    /// # Parameters
-    ///
-    /// * `date_type` - The type of date value to set (Date or Timestamp)
-    ///
     /// # Returns
     ///
     /// Returns `&mut Self` to allow method chaining.
@@ -461,51 +552,44 @@ impl<T> UpdateBuilder<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use tnuctipun::{FieldName, FieldWitnesses, updates::{empty, CurrentDateType}};
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
     /// use serde::{Serialize, Deserialize};
     ///
     /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Timestamps {
-    ///     pub last_modified: String, // In practice, this would be a proper date type
-    ///     pub updated_at: String,    // In practice, this would be a proper date type
+    /// struct User {
+    ///     pub name: String,
+    ///     pub nickname: Option<String>,
     /// }
     ///
-    /// let update_doc = empty::<Timestamps>()
-    ///     .current_date::<timestamps_fields::LastModified>(CurrentDateType::Date)
-    ///     .current_date::<timestamps_fields::UpdatedAt>(CurrentDateType::Timestamp)
+    /// let update_doc = empty::<User>()
+    ///     .unset_optional::<user_fields::Nickname>()
     ///     .build();
-    /// // Results in: { "$currentDate": { "last_modified": "date", "updated_at": "timestamp" } }
+    ///
+    /// assert_eq!(update_doc, bson::doc! { "$unset": { "nickname": bson::Bson::Null } });
     /// ```
-    pub fn current_date<F: FieldName>(&mut self, date_type: CurrentDateType) -> &mut Self
+    pub fn unset_optional<F: FieldName>(&mut self) -> &mut Self
     where
-        T: HasField<F>,
+        T: OptionalField<F>,
     {
-        let path = self.field_path::<F>();
+        self.unset::<F>()
+    }
 
-        self.push_clause(
-            UpdateOperation::CurrentDate,
-            path,
-            bson::Bson::String(date_type.to_string()),
-        );
-
-        self
-    }
-
-    /// Adds a value to an array field only if it does not already exist.
+    /// Increments the value of a numeric field by the specified amount.
     ///
-    /// This method corresponds to MongoDB's `$addToSet` operator, which adds a value to an array
-    /// unless the value is already present, in which case it does nothing to that array.
-    /// This ensures array uniqueness without duplicates. If the field is not an array,
-    /// the operation will fail. If the field does not exist, it creates a new array with the value.
+    /// This method corresponds to MongoDB's `$inc` operator, which increments a field by a specified value.
+    /// The field must contain a numeric value (integer, long, double, or decimal). If the field does not exist,
+    /// it is created with the increment value. If the field exists but is not numeric, the operation will fail.
     ///
     /// # Type Parameters
     ///
     /// * `F` - A field name marker type that implements `FieldName`
-    /// * `V` - A value type that can be converted into `bson::Bson`
+    /// * `N` - A numeric type that implements `Num` and can be converted into `bson::Bson`
     ///
     /// # Parameters
     ///
-    /// * `value` - The value to add to the array if it's not already present
+    /// * `value` - The amount to increment the field by (can be negative for decrementing)
+    ///
+    /// For integers not convertible into `bson::Bson` (e.g. `i128`, `u128`), see `inc_decimal128`.
     ///
     /// # Returns
     ///
@@ -516,49 +600,44 @@ impl<T> UpdateBuilder<T> {
     /// ```rust
     /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
     /// use serde::{Serialize, Deserialize};
-    /// use bson::doc;
     ///
     /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Article {
-    ///     pub tags: Vec<String>,
-    ///     pub categories: Vec<String>,
+    /// struct Stats {
+    ///     pub count: i32,
+    ///     pub score: i32,
     /// }
     ///
-    /// let update_doc = empty::<Article>()
-    ///     .add_to_set::<article_fields::Tags, _>("rust".to_string())
-    ///     .add_to_set::<article_fields::Categories, _>("programming".to_string())
+    /// let update_doc = empty::<Stats>()
+    ///     .inc::<stats_fields::Count, _>(1)
+    ///     .inc::<stats_fields::Score, _>(-5)
     ///     .build();
-    /// // Results in: { "$addToSet": { "tags": "rust", "categories": "programming" } }
+    /// // Results in: { "$inc": { "count": 1, "score": -5 } }
     /// ```
-    pub fn add_to_set<F: FieldName, V: Into<bson::Bson>>(&mut self, value: V) -> &mut Self
+    pub fn inc<F: FieldName, N: Num + Into<bson::Bson>>(&mut self, value: N) -> &mut Self
     where
         T: HasField<F>,
-        T::Value: IntoIterator<Item = V>,
     {
         let path = self.field_path::<F>();
 
-        self.push_clause(UpdateOperation::AddToSet, path, value.into());
+        self.push_clause(UpdateOperation::Inc, path, value.into());
 
         self
     }
 
-    /// Adds multiple values to an array field only if they do not already exist.
+    /// Increments a numeric field of an embedded document, without `with_lookup`.
     ///
-    /// This method corresponds to MongoDB's `$addToSet` operator with the `$each` modifier,
-    /// which adds multiple values to an array unless the values are already present.
-    /// This ensures array uniqueness without duplicates for all provided values.
-    /// If the field is not an array, the operation will fail. If the field does not exist,
-    /// it creates a new array with the unique values.
+    /// It's a shorthand for the common nested counter case, equivalent to a `with_lookup`
+    /// on the field `F` with an `inc` of its field `G`.
     ///
     /// # Type Parameters
     ///
-    /// * `F` - A field name marker type that implements `FieldName`
-    /// * `I` - An iterable type that yields items of type `V`
-    /// * `V` - A value type that can be converted into `bson::Bson`
+    /// * `F` - A field name marker type for the embedded document field
+    /// * `G` - A field name marker type for the numeric field of the embedded document
+    /// * `N` - A numeric type that implements `Num` and can be converted into `bson::Bson`
     ///
     /// # Parameters
     ///
-    /// * `values` - An iterable collection of values to add to the array if they're not already present
+    /// * `value` - The amount to increment the nested field by (can be negative for decrementing)
     ///
     /// # Returns
     ///
@@ -567,90 +646,53 @@ impl<T> UpdateBuilder<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
-    /// use bson::doc;
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Article {
-    ///     pub tags: Vec<String>,
-    ///     pub categories: Vec<String>,
+    /// #[derive(FieldWitnesses)]
+    /// struct Stats {
+    ///     pub views: i32,
     /// }
     ///
-    /// let new_tags = vec!["rust".to_string(), "mongodb".to_string(), "database".to_string()];
-    /// let new_categories = vec!["programming".to_string(), "tutorial".to_string()];
-    ///
-    /// let update_doc = empty::<Article>()
-    ///     .add_to_set_each::<article_fields::Tags, _, _>(new_tags)
-    ///     .add_to_set_each::<article_fields::Categories, _, _>(new_categories)
-    ///     .build();
-    /// // Results in: {
-    /// //   "$addToSet": {
-    /// //     "tags": { "$each": ["rust", "mongodb", "database"] },
-    /// //     "categories": { "$each": ["programming", "tutorial"] }
-    /// //   }
-    /// // }
-    /// ```
-    ///
-    /// # Difference from `add_to_set`
-    ///
-    /// Unlike `add_to_set` which adds a single value, `add_to_set_each` can efficiently
-    /// add multiple values in a single operation:
-    ///
-    /// ```rust
-    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
-    ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Collection {
-    ///     pub items: Vec<String>,
+    /// #[derive(FieldWitnesses)]
+    /// struct Article {
+    ///     pub stats: Stats,
     /// }
     ///
-    /// // Single value addition
-    /// let single_update = empty::<Collection>()
-    ///     .add_to_set::<collection_fields::Items, _>("item1".to_string())
+    /// let update_doc = empty::<Article>()
+    ///     .inc_in::<article_fields::Stats, stats_fields::Views, _>(1)
     ///     .build();
-    /// // Results in: { "$addToSet": { "items": "item1" } }
     ///
-    /// // Multiple value addition
-    /// let multiple_update = empty::<Collection>()
-    ///     .add_to_set_each::<collection_fields::Items, _, _>(vec!["item1".to_string(), "item2".to_string()])
-    ///     .build();
-    /// // Results in: { "$addToSet": { "items": { "$each": ["item1", "item2"] } } }
+    /// assert_eq!(update_doc, bson::doc! { "$inc": { "stats.views": 1 } });
     /// ```
-    pub fn add_to_set_each<F: FieldName, I: IntoIterator<Item = V>, V: Into<bson::Bson>>(
+    pub fn inc_in<F: FieldName, G: FieldName, N: Num + Into<bson::Bson>>(
         &mut self,
-        values: I,
+        value: N,
     ) -> &mut Self
     where
         T: HasField<F>,
-        T::Value: IntoIterator<Item = V>,
+        T::Value: HasField<G>,
     {
-        let path = self.field_path::<F>();
-        let values_vec: Vec<bson::Bson> = values.into_iter().map(|v| v.into()).collect();
+        let path = format!("{}.{}", self.field_path::<F>(), G::field_name());
 
-        self.push_clause(
-            UpdateOperation::AddToSet,
-            path,
-            bson::doc! { "$each": values_vec }.into(),
-        );
+        self.push_clause(UpdateOperation::Inc, path, value.into());
 
         self
     }
 
-    /// Removes the first or last element from an array field.
+    /// Decrements the value of a numeric field by the specified amount.
     ///
-    /// This method corresponds to MongoDB's `$pop` operator, which removes either the first
-    /// or the last element from an array. The operation fails if the field is not an array.
-    /// If the array is empty, the operation has no effect.
+    /// This is a clarity helper over `inc`, negating the given amount so that `dec(3)` is
+    /// equivalent to `inc(-3)` (MongoDB has no `$dec` operator). The `Neg` bound excludes
+    /// unsigned amounts, which cannot be negated without silently changing their type.
     ///
     /// # Type Parameters
     ///
     /// * `F` - A field name marker type that implements `FieldName`
+    /// * `N` - A signed numeric type that implements `Num`, `Neg` and can be converted into `bson::Bson`
     ///
     /// # Parameters
     ///
-    /// * `strategy` - The strategy for which element to remove (`PopStrategy::First` or `PopStrategy::Last`)
+    /// * `amount` - The amount to decrement the field by (negating `N::MIN` overflows)
     ///
     /// # Returns
     ///
@@ -659,105 +701,117 @@ impl<T> UpdateBuilder<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use tnuctipun::{FieldWitnesses, updates::{empty, PopStrategy}};
+    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
     /// use serde::{Serialize, Deserialize};
     ///
     /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Collections {
-    ///     pub queue: Vec<String>,
-    ///     pub stack: Vec<String>,
+    /// struct Stock {
+    ///     pub quantity: i32,
     /// }
     ///
-    /// let update_doc = empty::<Collections>()
-    ///     .pop::<collections_fields::Queue>(PopStrategy::First)    // Remove first element (FIFO)
-    ///     .pop::<collections_fields::Stack>(PopStrategy::Last)     // Remove last element (LIFO)
+    /// let update_doc = empty::<Stock>()
+    ///     .dec::<stock_fields::Quantity, _>(3)
     ///     .build();
-    /// // Results in: { "$pop": { "queue": -1, "stack": 1 } }
+    /// // Results in: { "$inc": { "quantity": -3 } }
     /// ```
-    pub fn pop<F: FieldName>(&mut self, strategy: PopStrategy) -> &mut Self
+    ///
+    /// Unsigned amounts are rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Stock {
+    ///     pub quantity: u32,
+    /// }
+    ///
+    /// empty::<Stock>().dec::<stock_fields::Quantity, _>(3u32);
+    /// ```
+    pub fn dec<F: FieldName, N: Num + Neg<Output = N> + Into<bson::Bson>>(
+        &mut self,
+        amount: N,
+    ) -> &mut Self
     where
         T: HasField<F>,
-        T::Value: IntoIterator,
     {
-        let path = self.field_path::<F>();
-
-        self.push_clause(UpdateOperation::Pop, path, strategy.into());
-
-        self
+        self.inc::<F, N>(-amount)
     }
 
-    /// Removes array elements that match a conditional BSON expression.
+    /// Increments a numeric field by an integer amount converted to `bson::Decimal128`.
     ///
-    /// This method corresponds to MongoDB's `$pull` operator with a conditional expression,
-    /// which removes from an existing array all instances of values that match the specified condition.
-    /// This is useful for complex matching scenarios where you need to remove elements based on
-    /// embedded document fields or complex criteria.
+    /// This allows `$inc` with integers BSON has no type for, such as `i128`/`u128` values
+    /// beyond `i64::MAX`. The amount is converted exactly, so its absolute value must have
+    /// at most 34 significant digits (the Decimal128 precision), otherwise an error is returned.
+    ///
+    /// Note that MongoDB then stores the field as Decimal128 (e.g. an `int64` field incremented
+    /// by a Decimal128 becomes a Decimal128), so such counters should be read as Decimal128.
     ///
     /// # Type Parameters
     ///
     /// * `F` - A field name marker type that implements `FieldName`
+    /// * `N` - A primitive integer type (e.g. `i128`, `u128`)
     ///
     /// # Parameters
     ///
-    /// * `cond` - A BSON expression that defines the condition for elements to be removed
+    /// * `value` - The amount to increment the field by (can be negative for decrementing)
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns `&mut Self` to allow method chaining.
+    /// Returns an error if the value cannot be exactly represented as Decimal128.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
-    /// use bson::{doc, Bson};
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Inventory {
-    ///     pub items: Vec<bson::Document>,
+    /// #[derive(FieldWitnesses)]
+    /// struct Stats {
+    ///     pub total: bson::Decimal128,
     /// }
     ///
-    /// // Remove all items where quantity is less than 5
-    /// let condition = doc! { "quantity": { "$lt": 5 } };
-    ///
-    /// let update_doc = empty::<Inventory>()
-    ///     .pull_expr::<inventory_fields::Items>(Bson::Document(condition))
+    /// let update_doc = empty::<Stats>()
+    ///     .inc_decimal128::<stats_fields::Total, _>(i128::from(i64::MAX) + 1)
+    ///     .unwrap()
     ///     .build();
-    /// // Results in: { "$pull": { "items": { "quantity": { "$lt": 5 } } } }
+    ///
+    /// let expected: bson::Decimal128 = "9223372036854775808".parse().unwrap();
+    ///
+    /// assert_eq!(update_doc, bson::doc! { "$inc": { "total": expected } });
     /// ```
-    pub fn pull_expr<F: FieldName>(&mut self, cond: bson::Bson) -> &mut Self
+    pub fn inc_decimal128<F: FieldName, N: PrimInt + std::fmt::Display>(
+        &mut self,
+        value: N,
+    ) -> bson::error::Result<&mut Self>
     where
         T: HasField<F>,
-        T::Value: IntoIterator,
     {
         let path = self.field_path::<F>();
+        let decimal = integer_to_decimal128(value)?;
 
-        self.push_clause(UpdateOperation::Pull, path, cond);
+        self.push_clause(UpdateOperation::Inc, path, decimal.into());
 
-        self
+        Ok(self)
     }
 
-    /// Removes all array elements that match a specific value.
+    /// Updates a field only if the specified value is greater than the existing field value.
     ///
-    /// This method corresponds to MongoDB's `$pull` operator with a simple value match,
-    /// which removes from an existing array all instances of the specified value.
-    /// This is the simpler version of `pull_expr` for exact value matching.
+    /// This method corresponds to MongoDB's `$max` operator, which only updates the field if the specified value
+    /// is greater than the existing field value. If the field does not exist, it sets the field to the specified value.
+    /// The field must contain a value that can be compared to the specified value, typically numeric types or dates.
     ///
     /// # Type Parameters
     ///
     /// * `F` - A field name marker type that implements `FieldName`
-    /// * `V` - A value type that can be converted into `bson::Bson`
+    /// * `N` - A numeric type that implements `Num` and can be converted into `bson::Bson`
     ///
     /// # Parameters
     ///
-    /// * `value` - The exact value to remove from the array
+    /// * `value` - The value to compare against the existing field value
     ///
     /// # Returns
     ///
     /// Returns `&mut Self` to allow method chaining.
     ///
-    /// Returns `&mut Self` to allow method chaining.
-    ///
     /// # Examples
     ///
     /// ```rust
@@ -765,44 +819,62 @@ impl<T> UpdateBuilder<T> {
     /// use serde::{Serialize, Deserialize};
     ///
     /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Content {
-    ///     pub tags: Vec<String>,
-    ///     pub scores: Vec<i32>,
+    /// struct HighScore {
+    ///     pub best_score: i32,
+    ///     pub max_level: i32,
     /// }
     ///
-    /// let update_doc = empty::<Content>()
-    ///     .pull::<content_fields::Tags, _>("deprecated".to_string())
-    ///     .pull::<content_fields::Scores, _>(0)
+    /// let update_doc = empty::<HighScore>()
+    ///     .max::<highscore_fields::BestScore, _>(1500)     // Only update if 1500 > current score
+    ///     .max::<highscore_fields::MaxLevel, _>(10)        // Only update if 10 > current max level
     ///     .build();
-    /// // Results in: { "$pull": { "tags": "deprecated", "scores": 0 } }
+    /// // Results in: { "$max": { "best_score": 1500, "max_level": 10 } }
     /// ```
-    pub fn pull<F: FieldName, V: Into<bson::Bson>>(&mut self, value: V) -> &mut Self
+    ///
+    /// # Repeated Calls
+    ///
+    /// Calling `max` several times for the same field keeps only the last value (as for the
+    /// other operators), not the greatest one. Calls for distinct fields are accumulated.
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct HighScore {
+    ///     pub best_score: i32,
+    /// }
+    ///
+    /// let update_doc = empty::<HighScore>()
+    ///     .max::<highscore_fields::BestScore, _>(1500)
+    ///     .max::<highscore_fields::BestScore, _>(1000)
+    ///     .build();
+    ///
+    /// assert_eq!(update_doc, bson::doc! { "$max": { "best_score": 1000 } });
+    /// ```
+    pub fn max<F: FieldName, N: Num + Into<bson::Bson>>(&mut self, value: N) -> &mut Self
     where
         T: HasField<F>,
-        T::Value: IntoIterator<Item = V>,
     {
         let path = self.field_path::<F>();
 
-        self.push_clause(UpdateOperation::Pull, path, value.into());
+        self.push_clause(UpdateOperation::Max, path, value.into());
 
         self
     }
 
-    /// Removes all instances of specified values from an array.
+    /// Updates a date field only if the specified date is later than the existing one (`$max`).
     ///
-    /// This method corresponds to MongoDB's `$pullAll` operator, which removes all instances
-    /// of the specified values from an existing array. Unlike `$pull`, which removes elements
-    /// by specifying a condition, `$pullAll` removes elements that match any of the listed values.
-    /// The field must be an array, or the operation will fail.
+    /// Unlike `max`, which is bound to numeric values, this accepts any value convertible into
+    /// a BSON date (as `set_date`), for the "latest wins" updates.
     ///
     /// # Type Parameters
     ///
-    /// * `F` - A field name marker type that implements `FieldName`
-    /// * `I` - An iterable type that can be converted into `bson::Bson`
+    /// * `F` - A field name marker type that implements `FieldName`, for a date field
+    /// * `D` - A value type that can be converted into `bson::DateTime`
     ///
     /// # Parameters
     ///
-    /// * `values` - An iterable collection of values to remove from the array
+    /// * `value` - The date to compare with the existing field value
     ///
     /// # Returns
     ///
@@ -811,53 +883,48 @@ impl<T> UpdateBuilder<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
-    /// use bson::Bson;
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Lists {
-    ///     pub tags: Vec<String>,
-    ///     pub numbers: Vec<i32>,
+    /// #[derive(FieldWitnesses)]
+    /// struct Account {
+    ///     pub last_login: bson::DateTime,
     /// }
     ///
-    /// let tags_to_remove = vec!["old".to_string(), "deprecated".to_string(), "unused".to_string()];
-    /// let numbers_to_remove = vec![1i32, 3i32, 5i32, 7i32];
-    ///
-    /// empty::<Lists>()
-    ///     .pull_all::<lists_fields::Tags, _>(tags_to_remove)
-    ///     .pull_all::<lists_fields::Numbers, _>(numbers_to_remove)
+    /// let update_doc = empty::<Account>()
+    ///     .max_date::<account_fields::LastLogin, _>(bson::DateTime::from_millis(1_700_000_000_000))
     ///     .build();
-    /// // Results in: { "$pullAll": { "tags": ["old", "deprecated", "unused"], "numbers": [1, 3, 5, 7] } }
+    ///
+    /// assert_eq!(
+    ///     update_doc,
+    ///     bson::doc! { "$max": { "last_login": bson::DateTime::from_millis(1_700_000_000_000) } }
+    /// );
     /// ```
-    pub fn pull_all<F: FieldName, I>(&mut self, values: I) -> &mut Self
+    pub fn max_date<F: FieldName, D: Into<bson::DateTime>>(&mut self, value: D) -> &mut Self
     where
         T: HasField<F>,
-        I: Into<bson::Bson> + IntoIterator,
-        T::Value: IntoIterator<Item = I::Item>,
+        T::Value: MongoDate,
     {
         let path = self.field_path::<F>();
 
-        self.push_clause(UpdateOperation::PullAll, path, values.into());
+        self.push_clause(UpdateOperation::Max, path, value.into().into());
 
         self
     }
 
-    /// Appends a value to an array field.
+    /// Updates a field only if the specified value is less than the existing field value.
     ///
-    /// This method corresponds to MongoDB's `$push` operator, which appends a specified value
-    /// to an array. If the field is absent, it creates a new array field with the value as its element.
-    /// If the field exists but is not an array, the operation will fail. Unlike `$addToSet`,
-    /// `$push` allows duplicate values to be added to the array.
+    /// This method corresponds to MongoDB's `$min` operator, which only updates the field if the specified value
+    /// is less than the existing field value. If the field does not exist, it sets the field to the specified value.
+    /// The field must contain a value that can be compared to the specified value, typically numeric types or dates.
     ///
     /// # Type Parameters
     ///
     /// * `F` - A field name marker type that implements `FieldName`
-    /// * `V` - A value type that can be converted into `bson::Bson`
+    /// * `N` - A numeric type that implements `Num` and can be converted into `bson::Bson`
     ///
     /// # Parameters
     ///
-    /// * `value` - The value to append to the array
+    /// * `value` - The value to compare against the existing field value
     ///
     /// # Returns
     ///
@@ -870,51 +937,62 @@ impl<T> UpdateBuilder<T> {
     /// use serde::{Serialize, Deserialize};
     ///
     /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Logging {
-    ///     pub logs: Vec<String>,
-    ///     pub history: Vec<String>,
+    /// struct Pricing {
+    ///     pub lowest_price: f64,
+    ///     pub min_quantity: i32,
     /// }
     ///
-    /// let update_doc = empty::<Logging>()
-    ///     .push::<logging_fields::Logs, _>("User logged in".to_string())
-    ///     .push::<logging_fields::History, _>("Action performed".to_string())
+    /// let update_doc = empty::<Pricing>()
+    ///     .min::<pricing_fields::LowestPrice, _>(29.99)    // Only update if 29.99 < current price
+    ///     .min::<pricing_fields::MinQuantity, _>(5)        // Only update if 5 < current min quantity
     ///     .build();
-    /// // Results in: { "$push": { "logs": "User logged in", "history": "Action performed" } }
+    /// // Results in: { "$min": { "lowest_price": 29.99, "min_quantity": 5 } }
     /// ```
-    pub fn push<F: FieldName, V: Into<bson::Bson>>(&mut self, value: V) -> &mut Self
+    ///
+    /// # Repeated Calls
+    ///
+    /// Calling `min` several times for the same field keeps only the last value (as for the
+    /// other operators), not the lowest one. Calls for distinct fields are accumulated.
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Pricing {
+    ///     pub lowest_price: f64,
+    /// }
+    ///
+    /// let update_doc = empty::<Pricing>()
+    ///     .min::<pricing_fields::LowestPrice, _>(19.99)
+    ///     .min::<pricing_fields::LowestPrice, _>(29.99)
+    ///     .build();
+    ///
+    /// assert_eq!(update_doc, bson::doc! { "$min": { "lowest_price": 29.99 } });
+    /// ```
+    pub fn min<F: FieldName, N: Num + Into<bson::Bson>>(&mut self, value: N) -> &mut Self
     where
         T: HasField<F>,
-        T::Value: IntoIterator<Item = V>,
     {
         let path = self.field_path::<F>();
 
-        self.push_clause(UpdateOperation::Push, path, value.into());
+        self.push_clause(UpdateOperation::Min, path, value.into());
 
         self
     }
 
-    /// Appends multiple values to an array field with advanced options.
-    ///
-    /// This method corresponds to MongoDB's `$push` operator with the `$each` modifier,
-    /// along with optional modifiers like `$slice`, `$sort`, and `$position`. It provides
-    /// more control over how values are added to arrays compared to the basic `push` method.
+    /// Updates a date field only if the specified date is earlier than the existing one (`$min`).
     ///
-    /// The method accepts a `PushEach` clause that can be configured with:
-    /// - `$each`: The array of values to append (always included)
-    /// - `$slice`: Limits the array to a specified number of elements
-    /// - `$sort`: Sorts the array elements after adding new values
-    /// - `$position`: Specifies where in the array to insert the new elements
+    /// Unlike `min`, which is bound to numeric values, this accepts any value convertible into
+    /// a BSON date (as `set_date`), for the "earliest wins" updates.
     ///
     /// # Type Parameters
     ///
-    /// * `F` - A field name marker type that implements `FieldName`
-    /// * `I` - An iterable type that yields items of type `V`
-    /// * `V` - A value type that can be converted into `bson::Bson`
-    /// * `Clause` - A type that can be converted into `PushEach<I, V>`
+    /// * `F` - A field name marker type that implements `FieldName`, for a date field
+    /// * `D` - A value type that can be converted into `bson::DateTime`
     ///
     /// # Parameters
     ///
-    /// * `clause` - A `PushEach` clause or any type that converts to it, containing the values and options
+    /// * `value` - The date to compare with the existing field value
     ///
     /// # Returns
     ///
@@ -923,210 +1001,138 @@ impl<T> UpdateBuilder<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct GameData {
-    ///     pub scores: Vec<i32>,
-    ///     pub tags: Vec<String>,
+    /// #[derive(FieldWitnesses)]
+    /// struct Account {
+    ///     pub first_seen: bson::DateTime,
     /// }
     ///
-    /// // Basic usage - just append values
-    /// let values = vec![100, 200, 300];
-    /// let update_doc = empty::<GameData>()
-    ///     .push_each::<gamedata_fields::Scores, _, _, _>(values)
+    /// let update_doc = empty::<Account>()
+    ///     .min_date::<account_fields::FirstSeen, _>(bson::DateTime::from_millis(1_600_000_000_000))
     ///     .build();
-    /// // Results in: { "$push": { "scores": { "$each": [100, 200, 300] } } }
-    /// ```
-    ///
-    /// # Advanced Usage with PushEach Options
-    ///
-    /// For more advanced scenarios, you can create a `PushEach` instance with specific options:
-    ///
-    /// ```rust
-    /// use tnuctipun::updates::{PushEach, PushEachSlice, PushEachSort};
     ///
-    /// // This would be used with appropriate field setup
-    /// // let push_clause = PushEach::from(vec![1, 2, 3])
-    /// //     .with_slice(PushEachSlice::PushFirstSlice(5))
-    /// //     .with_sort(PushEachSort::PushSortDescending);
+    /// assert_eq!(
+    ///     update_doc,
+    ///     bson::doc! { "$min": { "first_seen": bson::DateTime::from_millis(1_600_000_000_000) } }
+    /// );
     /// ```
-    ///
-    /// # Difference from `push`
-    ///
-    /// Unlike `push` which adds a single value, `push_each` efficiently adds multiple
-    /// values in a single operation and supports advanced array manipulation options.
-    pub fn push_each<
-        F: FieldName,
-        I: IntoIterator<Item = V>,
-        V: Into<bson::Bson>,
-        Clause: Into<PushEach<I, V>>,
-    >(
-        &mut self,
-        clause: Clause,
-    ) -> &mut Self
+    pub fn min_date<F: FieldName, D: Into<bson::DateTime>>(&mut self, value: D) -> &mut Self
     where
         T: HasField<F>,
-        T::Value: IntoIterator<Item = V>,
+        T::Value: MongoDate,
     {
         let path = self.field_path::<F>();
-        let c: PushEach<I, V> = clause.into();
 
-        self.push_clause(UpdateOperation::Push, path, c.into());
+        self.push_clause(UpdateOperation::Min, path, value.into().into());
 
         self
     }
 
-    /// Conditionally applies update operations based on an optional value.
-    ///
-    /// This method provides a convenient way to conditionally add update operations
-    /// to the builder when dealing with optional values. If the provided `Option<A>`
-    /// contains a value (`Some(A)`), the closure is executed with the unwrapped value
-    /// and the current builder. If the option is `None`, the operation is skipped
-    /// and the builder remains unchanged.
+    /// Multiplies the value of a numeric field by the specified amount.
     ///
-    /// This is particularly useful when building update documents dynamically where
-    /// some fields should only be updated if certain values are provided, avoiding
-    /// the need for explicit `if let Some(value) = option { ... }` patterns.
+    /// This method corresponds to MongoDB's `$mul` operator, which multiplies the value of a field by a number.
+    /// The field must contain a numeric value (integer, long, double, or decimal). If the field does not exist,
+    /// it is created with a value of zero (0 * multiplier = 0). If the field exists but is not numeric, the operation will fail.
     ///
     /// # Type Parameters
     ///
-    /// * `A` - The type of the value contained in the `Option`
-    /// * `F` - The closure type that takes a mutable reference to the builder and the unwrapped value
+    /// * `F` - A field name marker type that implements `FieldName`
+    /// * `N` - A numeric type that implements `Num` and can be converted into `bson::Bson`
     ///
     /// # Parameters
     ///
-    /// * `value` - An `Option<A>` that may contain a value to use in the update operation
-    /// * `f` - A closure that takes `&mut Self` and `A` and returns `&mut Self`, defining the update operations to apply
+    /// * `value` - The multiplier to apply to the field value
     ///
     /// # Returns
     ///
-    /// Returns `&mut Self` to allow method chaining, regardless of whether the option contained a value.
+    /// Returns `&mut Self` to allow method chaining.
     ///
     /// # Examples
     ///
-    /// ## Basic Usage
-    ///
     /// ```rust
-    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
     /// use serde::{Serialize, Deserialize};
     ///
     /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct User {
-    ///     pub name: String,
-    ///     pub age: i32,
-    ///     pub email: Option<String>,
+    /// struct Product {
+    ///     pub price: f64,
+    ///     pub quantity: i32,
     /// }
     ///
-    /// let maybe_email = Some("user@example.com".to_string());
-    /// let no_email: Option<String> = None;
-    ///
-    /// // When the option contains a value, the update is applied
-    /// let update_with_email = empty::<User>()
-    ///     .set::<user_fields::Name, _>("John Doe".to_string())
-    ///     .if_some(maybe_email, |builder, email| {
-    ///         builder.set::<user_fields::Email, _>(email)
-    ///     })
-    ///     .build();
-    /// // Results in: { "$set": { "name": "John Doe", "email": "user@example.com" } }
-    ///
-    /// // When the option is None, the update is skipped
-    /// let update_without_email = empty::<User>()
-    ///     .set::<user_fields::Name, _>("Jane Doe".to_string())
-    ///     .if_some(no_email, |builder, email| {
-    ///         builder.set::<user_fields::Email, _>(email)
-    ///     })
+    /// let update_doc = empty::<Product>()
+    ///     .mul::<product_fields::Price, _>(1.1)      // Increase price by 10%
+    ///     .mul::<product_fields::Quantity, _>(2)     // Double the quantity
     ///     .build();
-    /// // Results in: { "$set": { "name": "Jane Doe" } }
+    /// // Results in: { "$mul": { "price": 1.1, "quantity": 2 } }
     /// ```
+    pub fn mul<F: FieldName, N: Num + Into<bson::Bson>>(&mut self, value: N) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        let path = self.field_path::<F>();
+
+        self.push_clause(UpdateOperation::Mul, path, value.into());
+
+        self
+    }
+
+    /// Multiplies a numeric field by an integer factor converted to `bson::Decimal128`.
     ///
-    /// ## Multiple Conditional Operations
+    /// This is the `$mul` counterpart of `inc_decimal128`, with the same conversion rules:
+    /// the factor must have at most 34 significant digits, and MongoDB stores the result
+    /// as Decimal128.
     ///
-    /// ```rust
-    /// use tnuctipun::{FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
+    /// # Type Parameters
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Profile {
-    ///     pub name: String,
-    ///     pub bio: Option<String>,
-    ///     pub website: Option<String>,
-    ///     pub score: i32,
-    /// }
+    /// * `F` - A field name marker type that implements `FieldName`
+    /// * `N` - A primitive integer type (e.g. `i128`, `u128`)
     ///
-    /// let maybe_bio = Some("Software developer".to_string());
-    /// let maybe_website: Option<String> = None;
-    /// let maybe_score_increase = Some(10);
+    /// # Parameters
     ///
-    /// let update_doc = empty::<Profile>()
-    ///     .set::<profile_fields::Name, _>("Alice".to_string())
-    ///     .if_some(maybe_bio, |builder, bio| {
-    ///         builder.set::<profile_fields::Bio, _>(bio)
-    ///     })
-    ///     .if_some(maybe_website, |builder, website| {
-    ///         builder.set::<profile_fields::Website, _>(website)
-    ///     })
-    ///     .if_some(maybe_score_increase, |builder, increase| {
-    ///         builder.inc::<profile_fields::Score, _>(increase)
-    ///     })
-    ///     .build();
-    /// // Results in: {
-    /// //   "$set": { "name": "Alice", "bio": "Software developer" },
-    /// //   "$inc": { "score": 10 }
-    /// // }
-    /// ```
+    /// * `value` - The factor to multiply the field by
     ///
-    /// ## Complex Update Operations
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be exactly represented as Decimal128.
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// use tnuctipun::{FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Product {
-    ///     pub name: String,
-    ///     pub tags: Vec<String>,
-    ///     pub discount: Option<f64>,
+    /// #[derive(FieldWitnesses)]
+    /// struct Stats {
+    ///     pub total: bson::Decimal128,
     /// }
     ///
-    /// let maybe_discount = Some(0.15); // 15% discount
-    ///
-    /// let update_doc = empty::<Product>()
-    ///     .set::<product_fields::Name, _>("Special Item".to_string())
-    ///     .push::<product_fields::Tags, _>("on-sale".to_string())
-    ///     .if_some(maybe_discount, |builder, discount| {
-    ///         builder
-    ///             .set::<product_fields::Discount, _>(discount)
-    ///             .push::<product_fields::Tags, _>("discounted".to_string())
-    ///     })
+    /// let update_doc = empty::<Stats>()
+    ///     .mul_decimal128::<stats_fields::Total, _>(1_000_u128)
+    ///     .unwrap()
     ///     .build();
-    /// // Results in: {
-    /// //   "$set": { "name": "Special Item", "discount": 0.15 },
-    /// //   "$push": { "tags": { "$each": ["on-sale", "discounted"] } }
-    /// // }
+    /// // Results in: { "$mul": { "total": NumberDecimal("1000") } }
     /// ```
-    pub fn if_some<A, F>(&mut self, value: Option<A>, f: F) -> &mut Self
+    pub fn mul_decimal128<F: FieldName, N: PrimInt + std::fmt::Display>(
+        &mut self,
+        value: N,
+    ) -> bson::error::Result<&mut Self>
     where
-        F: FnOnce(&mut Self, A) -> &mut Self,
+        T: HasField<F>,
     {
-        if let Some(v) = value {
-            f(self, v);
-        }
+        let path = self.field_path::<F>();
+        let decimal = integer_to_decimal128(value)?;
 
-        self
+        self.push_clause(UpdateOperation::Mul, path, decimal.into());
+
+        Ok(self)
     }
 
-    /// Performs a raw update operation with a custom BSON expression.
-    ///
-    /// This method provides an escape hatch for advanced MongoDB update operations
-    /// that are not directly supported by the type-safe methods. It allows you to
-    /// specify any MongoDB update operator along with a custom BSON expression.
+    /// Renames a field in the document.
     ///
-    /// **Warning**: This method bypasses type safety and should be used with caution.
-    /// Prefer using the type-safe methods when possible. This is intended for cases
-    /// where you need to use MongoDB features that aren't yet supported by the
-    /// typed API, or for complex expressions that require manual BSON construction.
+    /// This method corresponds to MongoDB's `$rename` operator, which renames a field.
+    /// The new field name must differ from the existing field name. If the target field name
+    /// already exists, its value will be overwritten. If the source field does not exist,
+    /// the operation has no effect.
     ///
     /// # Type Parameters
     ///
@@ -1134,8 +1140,7 @@ impl<T> UpdateBuilder<T> {
     ///
     /// # Parameters
     ///
-    /// * `op` - The MongoDB update operation to perform
-    /// * `expr` - A raw BSON expression for the operation
+    /// * `new_name` - The new name for the field
     ///
     /// # Returns
     ///
@@ -1144,82 +1149,101 @@ impl<T> UpdateBuilder<T> {
     /// # Examples
     ///
     /// ```rust
-    /// use tnuctipun::{FieldName, FieldWitnesses, updates::{empty, UpdateOperation}};
+    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
     /// use serde::{Serialize, Deserialize};
-    /// use bson::{doc, Bson};
     ///
     /// #[derive(FieldWitnesses, Serialize, Deserialize)]
     /// struct Document {
-    ///     pub custom_field: String,
-    ///     pub array_field: Vec<i32>,
+    ///     pub old_name: String,
+    ///     pub legacy_field: String,
     /// }
     ///
-    /// // Example: Using a complex $push operation with multiple modifiers
-    /// let complex_push = doc! {
-    ///     "$each": [1, 2, 3],
-    ///     "$slice": -10,
-    ///     "$sort": -1
-    /// };
-    ///
     /// let update_doc = empty::<Document>()
-    ///     .untyped::<document_fields::ArrayField>(
-    ///         UpdateOperation::Push,
-    ///         Bson::Document(complex_push)
-    ///     )
+    ///     .rename::<document_fields::OldName>("new_name")
+    ///     .rename::<document_fields::LegacyField>("modern_field")
     ///     .build();
-    /// // Results in: {
-    /// //   "$push": {
-    /// //     "array_field": {
-    /// //       "$each": [1, 2, 3],
-    /// //       "$slice": -10,
-    /// //       "$sort": -1
-    /// //     }
-    /// //   }
-    /// // }
+    /// // Results in: { "$rename": { "old_name": "new_name", "legacy_field": "modern_field" } }
     /// ```
     ///
-    /// # Use Cases
-    ///
-    /// - Complex array operations with multiple modifiers
-    /// - New MongoDB operators not yet supported by the typed API
-    /// - Custom aggregation expressions in update operations
-    /// - Conditional updates using MongoDB expressions
-    ///
-    /// # Evolution Note
+    /// # Nested Contexts
     ///
-    /// This method is marked for potential removal if a comprehensive safe expression
-    /// builder is implemented in the future. The goal is to eventually provide type-safe
-    /// alternatives for all MongoDB update operations.
-    pub fn untyped<F: FieldName>(&mut self, op: UpdateOperation, expr: bson::Bson) -> &mut Self
+    /// Within a nested context (e.g. `with_lookup`), only the source field is prefixed:
+    /// the new name is used verbatim as a full path, so renaming `home_address.city` to
+    /// `"town"` moves the field to the top level of the document. Use `rename_sibling`
+    /// to keep the renamed field within the same embedded document.
+    pub fn rename<F: FieldName>(&mut self, new_name: &str) -> &mut Self
     where
         T: HasField<F>,
     {
-        // EVOLUTION: Remove if a safe expression builder is provided
         let path = self.field_path::<F>();
 
-        self.push_clause(op, path, expr);
+        self.push_clause(
+            UpdateOperation::Rename,
+            path,
+            bson::Bson::String(new_name.to_string()),
+        );
 
         self
     }
 
-    /// Performs nested field updates using a path lookup function.
+    /// Renames a field to the name of a field of another struct (e.g. the migrated schema).
     ///
-    /// This method enables type-safe updates on nested document structures by providing
-    /// a lookup function that navigates to the target nested field, and a configuration
-    /// function that defines the update operations to apply to that nested context.
+    /// This is the typed alternative to `rename`, for which both sides are verified at
+    /// compile time: the source field `F` must exist in `T`, and the target field `G` in `U`.
+    /// As for `rename`, the target name is used verbatim (not prefixed in nested contexts).
+    /// See [`rename_pairs!`](crate::rename_pairs) to rename many fields at once.
     ///
     /// # Type Parameters
     ///
-    /// * `F` - The field name marker type for the starting field that implements `FieldName`
-    /// * `L` - The lookup function type that navigates from the starting field to the target field
-    /// * `G` - The field name marker type for the target nested field that implements `FieldName`
-    /// * `U` - The target struct type that contains the nested field and implements `HasField<G>`
-    /// * `N` - The configuration function type that defines update operations on the nested field
+    /// * `F` - A field name marker type for the source field of `T`
+    /// * `U` - The struct type having the target field
+    /// * `G` - A field name marker type for the target field of `U`
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct UserV1 {
+    ///     pub mail: String,
+    /// }
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct UserV2 {
+    ///     pub email: String,
+    /// }
+    ///
+    /// let update_doc = empty::<UserV1>()
+    ///     .rename_to::<userv1_fields::Mail, UserV2, userv2_fields::Email>()
+    ///     .build();
+    ///
+    /// assert_eq!(update_doc, bson::doc! { "$rename": { "mail": "email" } });
+    /// ```
+    pub fn rename_to<F: FieldName, U: HasField<G>, G: FieldName>(&mut self) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        self.rename::<F>(G::field_name())
+    }
+
+    /// Renames a field, keeping it within the current embedded document.
+    ///
+    /// Unlike `rename`, the new name is prefixed with the current nested context as the
+    /// source field is, so that in a `home_address` context renaming `city` to `"town"`
+    /// results in `home_address.town`. Without prefix, it's equivalent to `rename`.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
     ///
     /// # Parameters
     ///
-    /// * `lookup` - A function that takes a path to field `F` and returns a path to the target field `G`
-    /// * `f` - A function that takes an `UpdateBuilder<U>` for the nested context and returns it configured with update operations
+    /// * `new_name` - The new name for the field, relative to the current context
     ///
     /// # Returns
     ///
@@ -1229,287 +1253,2106 @@ impl<T> UpdateBuilder<T> {
     ///
     /// ```rust
     /// use tnuctipun::{FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// #[derive(FieldWitnesses)]
     /// struct Address {
-    ///     pub street: String,
     ///     pub city: String,
-    ///     pub zip_code: String,
     /// }
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// #[derive(FieldWitnesses)]
     /// struct User {
-    ///     pub name: String,
     ///     pub home_address: Address,
-    ///     pub work_address: Address,
     /// }
     ///
-    /// // Update nested field in home address
     /// let update_doc = empty::<User>()
     ///     .with_lookup::<user_fields::HomeAddress, _, address_fields::City, Address, _>(
     ///         |path| path.field::<address_fields::City>(),
     ///         |nested| {
-    ///             nested.set::<address_fields::City, _>("San Francisco".to_string());
-    ///         }
+    ///             nested.rename_sibling::<address_fields::City>("town");
+    ///         },
     ///     )
     ///     .build();
-    /// // Results in: { "$set": { "home_address.city": "San Francisco" } }
+    ///
+    /// assert_eq!(
+    ///     update_doc,
+    ///     bson::doc! { "$rename": { "home_address.city": "home_address.town" } }
+    /// );
     /// ```
+    pub fn rename_sibling<F: FieldName>(&mut self, new_name: &str) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        let new_path = if self.prefix.is_empty() {
+            new_name.to_string()
+        } else {
+            format!("{}.{}", self.prefix.join("."), new_name)
+        };
+
+        self.rename::<F>(&new_path)
+    }
+
+    /// Sets the value of a field to the current date.
     ///
-    /// # Usage with Multiple Nested Operations
+    /// This method corresponds to MongoDB's `$currentDate` operator, which sets the value of a field
+    /// to the current date, either as a BSON Date or a BSON Timestamp. The default type is Date.
+    /// This is useful for tracking when documents were last modified.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    ///
+    /// # Parameters
+    ///
+    /// * `date_type` - The type of date value to set (Date or Timestamp)
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
     ///
     /// ```rust
-    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    /// use tnuctipun::{FieldName, FieldWitnesses, updates::{empty, CurrentDateType}};
     /// use serde::{Serialize, Deserialize};
     ///
     /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Address {
-    ///     pub street: String,
-    ///     pub city: String,
-    ///     pub zip_code: String,
+    /// struct Timestamps {
+    ///     pub last_modified: String, // In practice, this would be a proper date type
+    ///     pub updated_at: String,    // In practice, this would be a proper date type
     /// }
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct User {
+    /// let update_doc = empty::<Timestamps>()
+    ///     .current_date::<timestamps_fields::LastModified>(CurrentDateType::Date)
+    ///     .current_date::<timestamps_fields::UpdatedAt>(CurrentDateType::Timestamp)
+    ///     .build();
+    /// // Results in: { "$currentDate": { "last_modified": "date", "updated_at": "timestamp" } }
+    /// ```
+    pub fn current_date<F: FieldName>(&mut self, date_type: CurrentDateType) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        let path = self.field_path::<F>();
+
+        self.push_clause(
+            UpdateOperation::CurrentDate,
+            path,
+            bson::Bson::String(date_type.to_string()),
+        );
+
+        self
+    }
+
+    /// Sets the value of a field to the current date, using the `true` shorthand.
+    ///
+    /// MongoDB accepts `{ "$currentDate": { field: true } }` to set the field to the current
+    /// date as a BSON Date, which is the usual way to track the last modification.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Document {
     ///     pub name: String,
-    ///     pub home_address: Address,
-    ///     pub work_address: Address,
+    ///     pub last_modified: bson::DateTime,
     /// }
     ///
-    /// let update_doc = empty::<User>()
-    ///     .with_lookup::<user_fields::HomeAddress, _, address_fields::City, Address, _>(
-    ///         |path| path.field::<address_fields::City>(),
-    ///         |nested| {
-    ///             nested
-    ///                 .set::<address_fields::City, _>("Boston".to_string())
-    ///                 .set::<address_fields::ZipCode, _>("02101".to_string());
-    ///         }
-    ///     )
-    ///     .with_lookup::<user_fields::WorkAddress, _, address_fields::Street, Address, _>(
-    ///         |path| path.field::<address_fields::Street>(),
-    ///         |nested| {
-    ///             nested.set::<address_fields::Street, _>("123 Business Ave".to_string());
-    ///         }
-    ///     )
+    /// let update_doc = empty::<Document>()
+    ///     .set::<document_fields::Name, _>("Updated")
+    ///     .touch::<document_fields::LastModified>()
     ///     .build();
-    /// // Results in: {
-    /// //   "$set": {
-    /// //     "home_address.city": "Boston",
-    /// //     "home_address.zip_code": "02101",
-    /// //     "work_address.street": "123 Business Ave"
-    /// //   }
-    /// // }
+    /// // Results in: { "$set": { "name": "Updated" }, "$currentDate": { "last_modified": true } }
     /// ```
-    pub fn with_lookup<F: FieldName, L, G: FieldName, U: HasField<G>, N>(
-        &mut self,
-        lookup: L,
-        f: N,
-    ) -> &mut Self
+    pub fn touch<F: FieldName>(&mut self) -> &mut Self
     where
         T: HasField<F>,
-        L: FnOnce(&Path<F, T, T>) -> Path<G, U, T>,
-        N: FnOnce(&mut UpdateBuilder<U>),
     {
-        // Create a base field path for the lookup
-        let base_field: Path<F, T, T> = Path {
-            prefix: self.prefix.clone(),
-            _marker: std::marker::PhantomData,
-        };
-
-        // Resolve the field path using the provided lookup function
-        let resolved_field = lookup(&base_field);
+        let path = self.field_path::<F>();
 
-        // Create a new UpdateBuilder for the nested field
-        let mut nested_builder = UpdateBuilder::<U> {
-            prefix: resolved_field.prefix.clone(),
-            clauses: HashMap::new(),
-            _marker: std::marker::PhantomData,
-        };
+        self.push_clause(
+            UpdateOperation::CurrentDate,
+            path,
+            bson::Bson::Boolean(true),
+        );
 
-        f(&mut nested_builder);
+        self
+    }
 
-        // Merge the nested clauses properly into the main builder
-        for (operation, clauses_vec) in nested_builder.clauses {
-            self.clauses
-                .entry(operation)
-                .or_default()
-                .extend(clauses_vec);
+    /// Adds a value to an array field only if it does not already exist.
+    ///
+    /// This method corresponds to MongoDB's `$addToSet` operator, which adds a value to an array
+    /// unless the value is already present, in which case it does nothing to that array.
+    /// This ensures array uniqueness without duplicates. If the field is not an array,
+    /// the operation will fail. If the field does not exist, it creates a new array with the value.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    /// * `V` - A value type that can be converted into `bson::Bson`
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to add to the array if it's not already present
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    /// use bson::doc;
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Article {
+    ///     pub tags: Vec<String>,
+    ///     pub categories: Vec<String>,
+    /// }
+    ///
+    /// let update_doc = empty::<Article>()
+    ///     .add_to_set::<article_fields::Tags, _>("rust".to_string())
+    ///     .add_to_set::<article_fields::Categories, _>("programming".to_string())
+    ///     .build();
+    /// // Results in: { "$addToSet": { "tags": "rust", "categories": "programming" } }
+    /// ```
+    pub fn add_to_set<F: FieldName, V: Into<bson::Bson>>(&mut self, value: V) -> &mut Self
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator<Item = V>,
+    {
+        let path = self.field_path::<F>();
+
+        self.push_clause(UpdateOperation::AddToSet, path, value.into());
+
+        self
+    }
+
+    /// Adds multiple values to an array field only if they do not already exist.
+    ///
+    /// This method corresponds to MongoDB's `$addToSet` operator with the `$each` modifier,
+    /// which adds multiple values to an array unless the values are already present.
+    /// This ensures array uniqueness without duplicates for all provided values.
+    /// If the field is not an array, the operation will fail. If the field does not exist,
+    /// it creates a new array with the unique values.
+    ///
+    /// The values can be given by any iterable (e.g. an iterator adaptor such as
+    /// `ids.iter().map(...)`, not only a collection), whose items must have the type of
+    /// the array elements.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    /// * `I` - An iterable type that yields items of type `V`
+    /// * `V` - A value type that can be converted into `bson::Bson`
+    ///
+    /// # Parameters
+    ///
+    /// * `values` - An iterable collection of values to add to the array if they're not already present
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    /// use bson::doc;
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Article {
+    ///     pub tags: Vec<String>,
+    ///     pub categories: Vec<String>,
+    /// }
+    ///
+    /// let new_tags = vec!["rust".to_string(), "mongodb".to_string(), "database".to_string()];
+    /// let new_categories = vec!["programming".to_string(), "tutorial".to_string()];
+    ///
+    /// let update_doc = empty::<Article>()
+    ///     .add_to_set_each::<article_fields::Tags, _, _>(new_tags)
+    ///     .add_to_set_each::<article_fields::Categories, _, _>(new_categories)
+    ///     .build();
+    /// // Results in: {
+    /// //   "$addToSet": {
+    /// //     "tags": { "$each": ["rust", "mongodb", "database"] },
+    /// //     "categories": { "$each": ["programming", "tutorial"] }
+    /// //   }
+    /// // }
+    /// ```
+    ///
+    /// # Difference from `add_to_set`
+    ///
+    /// Unlike `add_to_set` which adds a single value, `add_to_set_each` can efficiently
+    /// add multiple values in a single operation:
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Collection {
+    ///     pub items: Vec<String>,
+    /// }
+    ///
+    /// // Single value addition
+    /// let single_update = empty::<Collection>()
+    ///     .add_to_set::<collection_fields::Items, _>("item1".to_string())
+    ///     .build();
+    /// // Results in: { "$addToSet": { "items": "item1" } }
+    ///
+    /// // Multiple value addition
+    /// let multiple_update = empty::<Collection>()
+    ///     .add_to_set_each::<collection_fields::Items, _, _>(vec!["item1".to_string(), "item2".to_string()])
+    ///     .build();
+    /// // Results in: { "$addToSet": { "items": { "$each": ["item1", "item2"] } } }
+    /// ```
+    pub fn add_to_set_each<F: FieldName, I: IntoIterator<Item = V>, V: Into<bson::Bson>>(
+        &mut self,
+        values: I,
+    ) -> &mut Self
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator<Item = V>,
+    {
+        let path = self.field_path::<F>();
+        let values_vec: Vec<bson::Bson> = values.into_iter().map(|v| v.into()).collect();
+
+        self.push_clause(
+            UpdateOperation::AddToSet,
+            path,
+            bson::doc! { "$each": values_vec }.into(),
+        );
+
+        self
+    }
+
+    /// Removes the first or last element from an array field.
+    ///
+    /// This method corresponds to MongoDB's `$pop` operator, which removes either the first
+    /// or the last element from an array. The operation fails if the field is not an array.
+    /// If the array is empty, the operation has no effect.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    ///
+    /// # Parameters
+    ///
+    /// * `strategy` - The strategy for which element to remove (`PopStrategy::First` or `PopStrategy::Last`)
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::{empty, PopStrategy}};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Collections {
+    ///     pub queue: Vec<String>,
+    ///     pub stack: Vec<String>,
+    /// }
+    ///
+    /// let update_doc = empty::<Collections>()
+    ///     .pop::<collections_fields::Queue>(PopStrategy::First)    // Remove first element (FIFO)
+    ///     .pop::<collections_fields::Stack>(PopStrategy::Last)     // Remove last element (LIFO)
+    ///     .build();
+    /// // Results in: { "$pop": { "queue": -1, "stack": 1 } }
+    /// ```
+    pub fn pop<F: FieldName>(&mut self, strategy: PopStrategy) -> &mut Self
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator,
+    {
+        let path = self.field_path::<F>();
+
+        self.push_clause(UpdateOperation::Pop, path, strategy.into());
+
+        self
+    }
+
+    /// Keeps the last `n` elements of an array field, removing the extra ones from the front.
+    ///
+    /// Unlike `$pop`, which removes a single element, this trims any number of elements at
+    /// once, using `$push` with an empty `$each` and a negative `$slice` (the capped array
+    /// idiom). As MongoDB `$slice` is relative to the resulting length, arrays with at most
+    /// `n` elements are left unchanged.
+    ///
+    /// When combined with `push`/`push_each` on the same field, the values are pushed
+    /// before the array is trimmed (see `build`).
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The maximum number of elements to keep, from the back of the array
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Feed {
+    ///     pub events: Vec<String>,
+    /// }
+    ///
+    /// let update_doc = empty::<Feed>()
+    ///     .keep_last::<feed_fields::Events>(50)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     update_doc,
+    ///     bson::doc! { "$push": { "events": { "$each": [], "$slice": -50 } } }
+    /// );
+    /// ```
+    pub fn keep_last<F: FieldName>(&mut self, n: usize) -> &mut Self
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator,
+    {
+        self.trim::<F>(PushEachSlice::PushLastSlice(n))
+    }
+
+    /// Keeps the first `n` elements of an array field, removing the extra ones from the back.
+    ///
+    /// This is the counterpart of [`keep_last`](Self::keep_last), using a positive `$slice`.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The maximum number of elements to keep, from the front of the array
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Leaderboard {
+    ///     pub top_scores: Vec<i32>,
+    /// }
+    ///
+    /// let update_doc = empty::<Leaderboard>()
+    ///     .keep_first::<leaderboard_fields::TopScores>(10)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     update_doc,
+    ///     bson::doc! { "$push": { "top_scores": { "$each": [], "$slice": 10 } } }
+    /// );
+    /// ```
+    pub fn keep_first<F: FieldName>(&mut self, n: usize) -> &mut Self
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator,
+    {
+        self.trim::<F>(PushEachSlice::PushFirstSlice(n))
+    }
+
+    fn trim<F: FieldName>(&mut self, slice: PushEachSlice) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        let path = self.field_path::<F>();
+        let clause = PushEach::<Vec<bson::Bson>, bson::Bson>::new(Vec::new()).with_slice(slice);
+
+        self.push_clause(UpdateOperation::Push, path, clause.into());
+
+        self
+    }
+
+    /// Removes array elements that match a conditional BSON expression.
+    ///
+    /// This method corresponds to MongoDB's `$pull` operator with a conditional expression,
+    /// which removes from an existing array all instances of values that match the specified condition.
+    /// This is useful for complex matching scenarios where you need to remove elements based on
+    /// embedded document fields or complex criteria.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    ///
+    /// # Parameters
+    ///
+    /// * `cond` - A BSON expression that defines the condition for elements to be removed
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    /// use bson::{doc, Bson};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Inventory {
+    ///     pub items: Vec<bson::Document>,
+    /// }
+    ///
+    /// // Remove all items where quantity is less than 5
+    /// let condition = doc! { "quantity": { "$lt": 5 } };
+    ///
+    /// let update_doc = empty::<Inventory>()
+    ///     .pull_expr::<inventory_fields::Items>(Bson::Document(condition))
+    ///     .build();
+    /// // Results in: { "$pull": { "items": { "quantity": { "$lt": 5 } } } }
+    /// ```
+    pub fn pull_expr<F: FieldName>(&mut self, cond: bson::Bson) -> &mut Self
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator,
+    {
+        let path = self.field_path::<F>();
+
+        self.push_clause(UpdateOperation::Pull, path, cond);
+
+        self
+    }
+
+    /// Removes all array elements that match a specific value.
+    ///
+    /// This method corresponds to MongoDB's `$pull` operator with a simple value match,
+    /// which removes from an existing array all instances of the specified value.
+    /// This is the simpler version of `pull_expr` for exact value matching.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    /// * `V` - A value type that can be converted into `bson::Bson`
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The exact value to remove from the array
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Content {
+    ///     pub tags: Vec<String>,
+    ///     pub scores: Vec<i32>,
+    /// }
+    ///
+    /// let update_doc = empty::<Content>()
+    ///     .pull::<content_fields::Tags, _>("deprecated".to_string())
+    ///     .pull::<content_fields::Scores, _>(0)
+    ///     .build();
+    /// // Results in: { "$pull": { "tags": "deprecated", "scores": 0 } }
+    /// ```
+    pub fn pull<F: FieldName, V: Into<bson::Bson>>(&mut self, value: V) -> &mut Self
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator<Item = V>,
+    {
+        let path = self.field_path::<F>();
+
+        self.push_clause(UpdateOperation::Pull, path, value.into());
+
+        self
+    }
+
+    /// Removes all instances of specified values from an array.
+    ///
+    /// This method corresponds to MongoDB's `$pullAll` operator, which removes all instances
+    /// of the specified values from an existing array. Unlike `$pull`, which removes elements
+    /// by specifying a condition, `$pullAll` removes elements that match any of the listed values.
+    /// The field must be an array, or the operation will fail.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    /// * `I` - An iterable type whose items are of the array element type
+    ///
+    /// # Parameters
+    ///
+    /// * `values` - An iterable collection of values to remove from the array (any iterator,
+    ///   e.g. `(1..4).map(...)`, collected internally)
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    /// use bson::Bson;
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Lists {
+    ///     pub tags: Vec<String>,
+    ///     pub numbers: Vec<i32>,
+    /// }
+    ///
+    /// let tags_to_remove = vec!["old".to_string(), "deprecated".to_string(), "unused".to_string()];
+    /// let numbers_to_remove = vec![1i32, 3i32, 5i32, 7i32];
+    ///
+    /// empty::<Lists>()
+    ///     .pull_all::<lists_fields::Tags, _>(tags_to_remove)
+    ///     .pull_all::<lists_fields::Numbers, _>(numbers_to_remove)
+    ///     .build();
+    /// // Results in: { "$pullAll": { "tags": ["old", "deprecated", "unused"], "numbers": [1, 3, 5, 7] } }
+    /// ```
+    pub fn pull_all<F: FieldName, I>(&mut self, values: I) -> &mut Self
+    where
+        T: HasField<F>,
+        I: IntoIterator,
+        I::Item: Into<bson::Bson>,
+        T::Value: IntoIterator<Item = I::Item>,
+    {
+        let path = self.field_path::<F>();
+        let values: Vec<bson::Bson> = values.into_iter().map(Into::into).collect();
+
+        self.push_clause(UpdateOperation::PullAll, path, values.into());
+
+        self
+    }
+
+    /// Appends a value to an array field.
+    ///
+    /// This method corresponds to MongoDB's `$push` operator, which appends a specified value
+    /// to an array. If the field is absent, it creates a new array field with the value as its element.
+    /// If the field exists but is not an array, the operation will fail. Unlike `$addToSet`,
+    /// `$push` allows duplicate values to be added to the array.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    /// * `V` - A value type that can be converted into `bson::Bson`
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to append to the array
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Logging {
+    ///     pub logs: Vec<String>,
+    ///     pub history: Vec<String>,
+    /// }
+    ///
+    /// let update_doc = empty::<Logging>()
+    ///     .push::<logging_fields::Logs, _>("User logged in".to_string())
+    ///     .push::<logging_fields::History, _>("Action performed".to_string())
+    ///     .build();
+    /// // Results in: { "$push": { "logs": "User logged in", "history": "Action performed" } }
+    /// ```
+    pub fn push<F: FieldName, V: Into<bson::Bson>>(&mut self, value: V) -> &mut Self
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator<Item = V>,
+    {
+        let path = self.field_path::<F>();
+
+        self.push_clause(UpdateOperation::Push, path, value.into());
+
+        self
+    }
+
+    /// Appends multiple values to an array field with advanced options.
+    ///
+    /// This method corresponds to MongoDB's `$push` operator with the `$each` modifier,
+    /// along with optional modifiers like `$slice`, `$sort`, and `$position`. It provides
+    /// more control over how values are added to arrays compared to the basic `push` method.
+    ///
+    /// The method accepts a `PushEach` clause that can be configured with:
+    /// - `$each`: The array of values to append (always included)
+    /// - `$slice`: Limits the array to a specified number of elements
+    /// - `$sort`: Sorts the array elements after adding new values
+    /// - `$position`: Specifies where in the array to insert the new elements
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    /// * `I` - An iterable type that yields items of type `V`
+    /// * `V` - A value type that can be converted into `bson::Bson`
+    /// * `Clause` - A type that can be converted into `PushEach<I, V>`
+    ///
+    /// # Parameters
+    ///
+    /// * `clause` - A `PushEach` clause or any type that converts to it, containing the values and options
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldName, FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct GameData {
+    ///     pub scores: Vec<i32>,
+    ///     pub tags: Vec<String>,
+    /// }
+    ///
+    /// // Basic usage - just append values
+    /// let values = vec![100, 200, 300];
+    /// let update_doc = empty::<GameData>()
+    ///     .push_each::<gamedata_fields::Scores, _, _, _>(values)
+    ///     .build();
+    /// // Results in: { "$push": { "scores": { "$each": [100, 200, 300] } } }
+    /// ```
+    ///
+    /// # Advanced Usage with PushEach Options
+    ///
+    /// For more advanced scenarios, you can create a `PushEach` instance with specific options:
+    ///
+    /// ```rust
+    /// use tnuctipun::updates::{PushEach, PushEachSlice, PushEachSort};
+    ///
+    /// // This would be used with appropriate field setup
+    /// // let push_clause = PushEach::from(vec![1, 2, 3])
+    /// //     .with_slice(PushEachSlice::PushFirstSlice(5))
+    /// //     .with_sort(PushEachSort::PushSortDescending);
+    /// ```
+    ///
+    /// # Difference from `push`
+    ///
+    /// Unlike `push` which adds a single value, `push_each` efficiently adds multiple
+    /// values in a single operation and supports advanced array manipulation options.
+    pub fn push_each<
+        F: FieldName,
+        I: IntoIterator<Item = V>,
+        V: Into<bson::Bson>,
+        Clause: Into<PushEach<I, V>>,
+    >(
+        &mut self,
+        clause: Clause,
+    ) -> &mut Self
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator<Item = V>,
+    {
+        let path = self.field_path::<F>();
+        let c: PushEach<I, V> = clause.into();
+
+        self.push_clause(UpdateOperation::Push, path, c.into());
+
+        self
+    }
+
+    /// Appends multiple values to an array field, configuring the `$push` modifiers fluently.
+    ///
+    /// This is a fluent alternative to [`push_each`](Self::push_each) with a configured
+    /// [`PushEach`]: the returned [`PushEachConfigurator`] offers the `slice`, `sort` and
+    /// `position` modifiers, and adds the `$push` clause to this builder either when
+    /// [`done`](PushEachConfigurator::done) is called (to continue chaining) or when dropped.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    /// * `I` - An iterable type that yields items of type `V`
+    /// * `V` - A value type that can be converted into `bson::Bson`
+    ///
+    /// # Parameters
+    ///
+    /// * `values` - The values to append to the array
+    ///
+    /// # Returns
+    ///
+    /// Returns a `PushEachConfigurator` borrowing this builder.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    /// use tnuctipun::updates::{PushEachSlice, PushEachSort};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct GameData {
+    ///     pub scores: Vec<i32>,
+    ///     pub level: i32,
+    /// }
+    ///
+    /// let update_doc = empty::<GameData>()
+    ///     .push_each_with::<gamedata_fields::Scores, _, _>(vec![100, 200])
+    ///     .sort(PushEachSort::PushSortDescending)
+    ///     .slice(PushEachSlice::PushFirstSlice(3))
+    ///     .done()
+    ///     .set::<gamedata_fields::Level, _>(2)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     update_doc,
+    ///     bson::doc! {
+    ///         "$push": { "scores": { "$each": [100, 200], "$sort": -1, "$slice": 3 } },
+    ///         "$set": { "level": 2 }
+    ///     }
+    /// );
+    /// ```
+    pub fn push_each_with<F: FieldName, I: IntoIterator<Item = V>, V: Into<bson::Bson>>(
+        &mut self,
+        values: I,
+    ) -> PushEachConfigurator<'_, T, I, V>
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator<Item = V>,
+    {
+        let path = self.field_path::<F>();
+
+        PushEachConfigurator {
+            builder: Some(self),
+            path,
+            clause: Some(PushEach::new(values)),
+        }
+    }
+
+    /// Inserts multiple values into an array field, before its last `n` elements.
+    ///
+    /// This is a shorthand for [`push_each`](Self::push_each) with the
+    /// [`PushEachPosition::PushTakeLast`] position (i.e. a negative `$position`),
+    /// e.g. to insert the values just before a trailing element.
+    /// With `n = 0`, the values are appended (without `$position`, as `0` would be
+    /// the beginning of the array).
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    /// * `I` - An iterable type that yields items of type `V`
+    /// * `V` - A value type that can be converted into `bson::Bson`
+    ///
+    /// # Parameters
+    ///
+    /// * `values` - The values to insert into the array
+    /// * `n` - The number of the last elements to insert the values before
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Playlist {
+    ///     pub tracks: Vec<String>,
+    /// }
+    ///
+    /// let update_doc = empty::<Playlist>()
+    ///     .insert_before_last::<playlist_fields::Tracks, _, _>(vec!["Bonus".to_string()], 1)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     update_doc,
+    ///     bson::doc! { "$push": { "tracks": { "$each": ["Bonus"], "$position": -1 } } }
+    /// );
+    /// ```
+    pub fn insert_before_last<F: FieldName, I: IntoIterator<Item = V>, V: Into<bson::Bson>>(
+        &mut self,
+        values: I,
+        n: usize,
+    ) -> &mut Self
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator<Item = V>,
+    {
+        let clause = PushEach::new(values);
+
+        if n == 0 {
+            self.push_each::<F, I, V, _>(clause)
+        } else {
+            self.push_each::<F, I, V, _>(clause.with_position(PushEachPosition::PushTakeLast(n)))
+        }
+    }
+
+    /// Appends multiple values to an array of scalars, then sorts the whole array.
+    ///
+    /// This is a shorthand for [`push_each`](Self::push_each) with the
+    /// [`PushEachSort::PushSortAscending`] or [`PushEachSort::PushSortDescending`] sort
+    /// (i.e. `$sort: 1` or `$sort: -1`), which sorts the elements by their own value.
+    /// For the arrays of documents, sorting by a field requires a
+    /// [`PushEachSort::PushSortExpression`] (e.g. with [`push_each_with`](Self::push_each_with)).
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    /// * `I` - An iterable type that yields items of type `V`
+    /// * `V` - A value type that can be converted into `bson::Bson`
+    ///
+    /// # Parameters
+    ///
+    /// * `values` - The values to append to the array
+    /// * `descending` - Whether the array is sorted descending, rather than ascending
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Game {
+    ///     pub scores: Vec<i32>,
+    /// }
+    ///
+    /// let update_doc = empty::<Game>()
+    ///     .push_sorted::<game_fields::Scores, _, _>(vec![72, 95], true)
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     update_doc,
+    ///     bson::doc! { "$push": { "scores": { "$each": [72, 95], "$sort": -1 } } }
+    /// );
+    /// ```
+    pub fn push_sorted<F: FieldName, I: IntoIterator<Item = V>, V: Into<bson::Bson>>(
+        &mut self,
+        values: I,
+        descending: bool,
+    ) -> &mut Self
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator<Item = V>,
+    {
+        let sort = if descending {
+            PushEachSort::PushSortDescending
+        } else {
+            PushEachSort::PushSortAscending
+        };
+
+        self.push_each::<F, I, V, _>(PushEach::new(values).with_sort(sort))
+    }
+
+    /// Conditionally applies update operations based on an optional value.
+    ///
+    /// This method provides a convenient way to conditionally add update operations
+    /// to the builder when dealing with optional values. If the provided `Option<A>`
+    /// contains a value (`Some(A)`), the closure is executed with the unwrapped value
+    /// and the current builder. If the option is `None`, the operation is skipped
+    /// and the builder remains unchanged.
+    ///
+    /// This is particularly useful when building update documents dynamically where
+    /// some fields should only be updated if certain values are provided, avoiding
+    /// the need for explicit `if let Some(value) = option { ... }` patterns.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `A` - The type of the value contained in the `Option`
+    /// * `F` - The closure type that takes a mutable reference to the builder and the unwrapped value
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - An `Option<A>` that may contain a value to use in the update operation
+    /// * `f` - A closure that takes `&mut Self` and `A` and returns `&mut Self`, defining the update operations to apply
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining, regardless of whether the option contained a value.
+    ///
+    /// # Examples
+    ///
+    /// ## Basic Usage
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub age: i32,
+    ///     pub email: Option<String>,
+    /// }
+    ///
+    /// let maybe_email = Some("user@example.com".to_string());
+    /// let no_email: Option<String> = None;
+    ///
+    /// // When the option contains a value, the update is applied
+    /// let update_with_email = empty::<User>()
+    ///     .set::<user_fields::Name, _>("John Doe".to_string())
+    ///     .if_some(maybe_email, |builder, email| {
+    ///         builder.set::<user_fields::Email, _>(email)
+    ///     })
+    ///     .build();
+    /// // Results in: { "$set": { "name": "John Doe", "email": "user@example.com" } }
+    ///
+    /// // When the option is None, the update is skipped
+    /// let update_without_email = empty::<User>()
+    ///     .set::<user_fields::Name, _>("Jane Doe".to_string())
+    ///     .if_some(no_email, |builder, email| {
+    ///         builder.set::<user_fields::Email, _>(email)
+    ///     })
+    ///     .build();
+    /// // Results in: { "$set": { "name": "Jane Doe" } }
+    /// ```
+    ///
+    /// ## Multiple Conditional Operations
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Profile {
+    ///     pub name: String,
+    ///     pub bio: Option<String>,
+    ///     pub website: Option<String>,
+    ///     pub score: i32,
+    /// }
+    ///
+    /// let maybe_bio = Some("Software developer".to_string());
+    /// let maybe_website: Option<String> = None;
+    /// let maybe_score_increase = Some(10);
+    ///
+    /// let update_doc = empty::<Profile>()
+    ///     .set::<profile_fields::Name, _>("Alice".to_string())
+    ///     .if_some(maybe_bio, |builder, bio| {
+    ///         builder.set::<profile_fields::Bio, _>(bio)
+    ///     })
+    ///     .if_some(maybe_website, |builder, website| {
+    ///         builder.set::<profile_fields::Website, _>(website)
+    ///     })
+    ///     .if_some(maybe_score_increase, |builder, increase| {
+    ///         builder.inc::<profile_fields::Score, _>(increase)
+    ///     })
+    ///     .build();
+    /// // Results in: {
+    /// //   "$set": { "name": "Alice", "bio": "Software developer" },
+    /// //   "$inc": { "score": 10 }
+    /// // }
+    /// ```
+    ///
+    /// ## Complex Update Operations
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Product {
+    ///     pub name: String,
+    ///     pub tags: Vec<String>,
+    ///     pub discount: Option<f64>,
+    /// }
+    ///
+    /// let maybe_discount = Some(0.15); // 15% discount
+    ///
+    /// let update_doc = empty::<Product>()
+    ///     .set::<product_fields::Name, _>("Special Item".to_string())
+    ///     .push::<product_fields::Tags, _>("on-sale".to_string())
+    ///     .if_some(maybe_discount, |builder, discount| {
+    ///         builder
+    ///             .set::<product_fields::Discount, _>(discount)
+    ///             .push::<product_fields::Tags, _>("discounted".to_string())
+    ///     })
+    ///     .build();
+    /// // Results in: {
+    /// //   "$set": { "name": "Special Item", "discount": 0.15 },
+    /// //   "$push": { "tags": { "$each": ["on-sale", "discounted"] } }
+    /// // }
+    /// ```
+    pub fn if_some<A, F>(&mut self, value: Option<A>, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self, A) -> &mut Self,
+    {
+        if let Some(v) = value {
+            f(self, v);
+        }
+
+        self
+    }
+
+    /// Performs a raw update operation with a custom BSON expression.
+    ///
+    /// This method provides an escape hatch for advanced MongoDB update operations
+    /// that are not directly supported by the type-safe methods. It allows you to
+    /// specify any MongoDB update operator along with a custom BSON expression.
+    ///
+    /// **Warning**: This method bypasses type safety and should be used with caution.
+    /// Prefer using the type-safe methods when possible. This is intended for cases
+    /// where you need to use MongoDB features that aren't yet supported by the
+    /// typed API, or for complex expressions that require manual BSON construction.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - A field name marker type that implements `FieldName`
+    ///
+    /// # Parameters
+    ///
+    /// * `op` - The MongoDB update operation to perform
+    /// * `expr` - A raw BSON expression for the operation
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldName, FieldWitnesses, updates::{empty, UpdateOperation}};
+    /// use serde::{Serialize, Deserialize};
+    /// use bson::{doc, Bson};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Document {
+    ///     pub custom_field: String,
+    ///     pub array_field: Vec<i32>,
+    /// }
+    ///
+    /// // Example: Using a complex $push operation with multiple modifiers
+    /// let complex_push = doc! {
+    ///     "$each": [1, 2, 3],
+    ///     "$slice": -10,
+    ///     "$sort": -1
+    /// };
+    ///
+    /// let update_doc = empty::<Document>()
+    ///     .untyped::<document_fields::ArrayField>(
+    ///         UpdateOperation::Push,
+    ///         Bson::Document(complex_push)
+    ///     )
+    ///     .build();
+    /// // Results in: {
+    /// //   "$push": {
+    /// //     "array_field": {
+    /// //       "$each": [1, 2, 3],
+    /// //       "$slice": -10,
+    /// //       "$sort": -1
+    /// //     }
+    /// //   }
+    /// // }
+    /// ```
+    ///
+    /// # Use Cases
+    ///
+    /// - Complex array operations with multiple modifiers
+    /// - New MongoDB operators not yet supported by the typed API
+    /// - Custom aggregation expressions in update operations
+    /// - Conditional updates using MongoDB expressions
+    ///
+    /// # Evolution Note
+    ///
+    /// This method is marked for potential removal if a comprehensive safe expression
+    /// builder is implemented in the future. The goal is to eventually provide type-safe
+    /// alternatives for all MongoDB update operations.
+    pub fn untyped<F: FieldName>(&mut self, op: UpdateOperation, expr: bson::Bson) -> &mut Self
+    where
+        T: HasField<F>,
+    {
+        // EVOLUTION: Remove if a safe expression builder is provided
+        let path = self.field_path::<F>();
+
+        self.push_clause(op, path, expr);
+
+        self
+    }
+
+    /// Performs nested field updates using a path lookup function.
+    ///
+    /// This method enables type-safe updates on nested document structures by providing
+    /// a lookup function that navigates to the target nested field, and a configuration
+    /// function that defines the update operations to apply to that nested context.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - The field name marker type for the starting field that implements `FieldName`
+    /// * `L` - The lookup function type that navigates from the starting field to the target field
+    /// * `G` - The field name marker type for the target nested field that implements `FieldName`
+    /// * `U` - The target struct type that contains the nested field and implements `HasField<G>`
+    /// * `N` - The configuration function type that defines update operations on the nested field
+    ///
+    /// # Parameters
+    ///
+    /// * `lookup` - A function that takes a path to field `F` and returns a path to the target field `G`
+    /// * `f` - A function that configures the update operations on the `UpdateBuilder<U>`
+    ///   for the nested context (see `with_lookup_chained` for a closure returning the builder)
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Address {
+    ///     pub street: String,
+    ///     pub city: String,
+    ///     pub zip_code: String,
+    /// }
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub home_address: Address,
+    ///     pub work_address: Address,
+    /// }
+    ///
+    /// // Update nested field in home address
+    /// let update_doc = empty::<User>()
+    ///     .with_lookup::<user_fields::HomeAddress, _, address_fields::City, Address, _>(
+    ///         |path| path.field::<address_fields::City>(),
+    ///         |nested| {
+    ///             nested.set::<address_fields::City, _>("San Francisco".to_string());
+    ///         }
+    ///     )
+    ///     .build();
+    /// // Results in: { "$set": { "home_address.city": "San Francisco" } }
+    /// ```
+    ///
+    /// # Usage with Multiple Nested Operations
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Address {
+    ///     pub street: String,
+    ///     pub city: String,
+    ///     pub zip_code: String,
+    /// }
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub home_address: Address,
+    ///     pub work_address: Address,
+    /// }
+    ///
+    /// let update_doc = empty::<User>()
+    ///     .with_lookup::<user_fields::HomeAddress, _, address_fields::City, Address, _>(
+    ///         |path| path.field::<address_fields::City>(),
+    ///         |nested| {
+    ///             nested
+    ///                 .set::<address_fields::City, _>("Boston".to_string())
+    ///                 .set::<address_fields::ZipCode, _>("02101".to_string());
+    ///         }
+    ///     )
+    ///     .with_lookup::<user_fields::WorkAddress, _, address_fields::Street, Address, _>(
+    ///         |path| path.field::<address_fields::Street>(),
+    ///         |nested| {
+    ///             nested.set::<address_fields::Street, _>("123 Business Ave".to_string());
+    ///         }
+    ///     )
+    ///     .build();
+    /// // Results in: {
+    /// //   "$set": {
+    /// //     "home_address.city": "Boston",
+    /// //     "home_address.zip_code": "02101",
+    /// //     "work_address.street": "123 Business Ave"
+    /// //   }
+    /// // }
+    /// ```
+    pub fn with_lookup<F: FieldName, L, G: FieldName, U: HasField<G>, N>(
+        &mut self,
+        lookup: L,
+        f: N,
+    ) -> &mut Self
+    where
+        T: HasField<F>,
+        L: FnOnce(&Path<F, T, T>) -> Path<G, U, T>,
+        N: FnOnce(&mut UpdateBuilder<U>),
+    {
+        // Create a base field path for the lookup
+        let base_field: Path<F, T, T> = Path {
+            prefix: self.prefix.clone(),
+            _marker: std::marker::PhantomData,
+        };
+
+        // Resolve the field path using the provided lookup function
+        let resolved_field = lookup(&base_field);
+
+        // Create a new UpdateBuilder for the nested field
+        let mut nested_builder = UpdateBuilder::<U> {
+            prefix: resolved_field.prefix.clone(),
+            clauses: HashMap::new(),
+            array_filters: Vec::new(),
+            _marker: std::marker::PhantomData,
+        };
+
+        f(&mut nested_builder);
+
+        self.merge_nested(nested_builder)
+    }
+
+    /// Same as `with_lookup`, but with a closure returning the nested builder, as the one
+    /// of `FilterBuilder::with_lookup`.
+    ///
+    /// It allows copying the nested closures between filter and update code. As a closure
+    /// returning a reference to its argument cannot match the same bound as a closure
+    /// returning `()`, it's a distinct method rather than a relaxed `with_lookup`.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - The field name marker type for the root field
+    /// * `L` - The lookup function type
+    /// * `G` - The field name marker type for the target nested field
+    /// * `U` - The type of the nested struct
+    /// * `N` - The function type configuring the nested updates
+    ///
+    /// # Parameters
+    ///
+    /// * `lookup` - A function that resolves the path to the nested field
+    /// * `f` - A function that configures the updates on the nested builder, and returns it
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Address {
+    ///     pub city: String,
+    ///     pub zip_code: String,
+    /// }
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub home_address: Address,
+    /// }
+    ///
+    /// let update_doc = empty::<User>()
+    ///     .with_lookup_chained::<user_fields::HomeAddress, _, address_fields::City, Address, _>(
+    ///         |path| path.field::<address_fields::City>(),
+    ///         |nested| {
+    ///             nested
+    ///                 .set::<address_fields::City, _>("Boston".to_string())
+    ///                 .set::<address_fields::ZipCode, _>("02101".to_string())
+    ///         },
+    ///     )
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     update_doc,
+    ///     bson::doc! { "$set": { "home_address.city": "Boston", "home_address.zip_code": "02101" } }
+    /// );
+    /// ```
+    pub fn with_lookup_chained<F: FieldName, L, G: FieldName, U: HasField<G>, N>(
+        &mut self,
+        lookup: L,
+        f: N,
+    ) -> &mut Self
+    where
+        T: HasField<F>,
+        L: FnOnce(&Path<F, T, T>) -> Path<G, U, T>,
+        N: FnOnce(&mut UpdateBuilder<U>) -> &mut UpdateBuilder<U>,
+    {
+        self.with_lookup::<F, L, G, U, _>(lookup, |nested| {
+            f(nested);
+        })
+    }
+
+    /// Updates the elements of an array field matching a condition, using the filtered
+    /// positional operator `$[identifier]`.
+    ///
+    /// The updates defined by `f` on the element type `U` are prefixed by
+    /// `field.$[identifier]`, while the `condition` on `U` is collected as an array filter
+    /// (with the `identifier.` prefix). As array filters are not part of the update
+    /// document itself, use `build_spec` to get both.
+    ///
+    /// Nothing is added to the builder if the identifier or the condition is invalid.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - The field name marker type for an array field
+    /// * `U` - The type of the array elements
+    /// * `C` - The function type configuring the filter on the elements
+    /// * `N` - The function type configuring the element updates
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier` - The identifier, which must start with a lowercase letter and only
+    ///   contain alphanumeric characters (MongoDB requirement)
+    /// * `condition` - A function that configures the filter selecting the elements to update
+    /// * `f` - A function that configures the updates on the selected elements
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ArrayFilterError`, either `InvalidIdentifier` if the identifier doesn't
+    /// meet the MongoDB requirement, or `EmptyCondition` if the condition adds no clause
+    /// (MongoDB rejects a `$[identifier]` without a matching array filter).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, MongoComparable, updates::empty};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Item {
+    ///     pub sku: String,
+    ///     pub quantity: i32,
+    /// }
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Order {
+    ///     pub items: Vec<Item>,
+    /// }
+    ///
+    /// let spec = empty::<Order>()
+    ///     .with_array_filter::<order_fields::Items, Item, _, _>(
+    ///         "item",
+    ///         |filter| {
+    ///             filter.eq::<item_fields::Sku, _>("A1".to_string());
+    ///         },
+    ///         |nested| {
+    ///             nested.inc::<item_fields::Quantity, _>(1);
+    ///         },
+    ///     )
+    ///     .expect("valid array filter")
+    ///     .build_spec();
+    ///
+    /// assert_eq!(spec.update, bson::doc! { "$inc": { "items.$[item].quantity": 1 } });
+    /// assert_eq!(spec.array_filters, vec![bson::doc! { "item.sku": "A1" }]);
+    /// ```
+    pub fn with_array_filter<F: FieldName, U, C, N>(
+        &mut self,
+        identifier: &str,
+        condition: C,
+        f: N,
+    ) -> Result<&mut Self, ArrayFilterError>
+    where
+        T: HasField<F>,
+        T::Value: IntoIterator<Item = U>,
+        C: FnOnce(&mut FilterBuilder<U>),
+        N: FnOnce(&mut UpdateBuilder<U>),
+    {
+        // MongoDB requires the identifier to start with a lowercase letter,
+        // and to only contain alphanumeric characters
+        let valid_identifier = identifier
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_lowercase())
+            && identifier.chars().all(|c| c.is_ascii_alphanumeric());
+
+        if !valid_identifier {
+            return Err(ArrayFilterError::InvalidIdentifier(identifier.to_string()));
+        }
+
+        let mut filter_builder = FilterBuilder::<U>::with_prefix(vec![identifier.to_string()]);
+
+        condition(&mut filter_builder);
+
+        let array_filter = filter_builder.and();
+
+        if array_filter.is_empty() {
+            return Err(ArrayFilterError::EmptyCondition(identifier.to_string()));
+        }
+
+        self.array_filters.push(array_filter);
+
+        let mut prefix = self.prefix.clone();
+
+        prefix.push(F::field_name().to_string());
+        prefix.push(format!("$[{identifier}]"));
+
+        let mut nested_builder = UpdateBuilder::<U> {
+            prefix,
+            clauses: HashMap::new(),
+            array_filters: Vec::new(),
+            _marker: std::marker::PhantomData,
+        };
+
+        f(&mut nested_builder);
+
+        Ok(self.merge_nested(nested_builder))
+    }
+
+    /// Merges the clauses and array filters of a nested builder into this builder.
+    fn merge_nested<U>(&mut self, nested_builder: UpdateBuilder<U>) -> &mut Self {
+        for (operation, clauses_vec) in nested_builder.clauses {
+            self.clauses
+                .entry(operation)
+                .or_default()
+                .extend(clauses_vec);
+        }
+
+        self.array_filters.extend(nested_builder.array_filters);
+
+        self
+    }
+
+    /// Convenience method for updating fields using identity lookup.
+    ///
+    /// This method provides a simpler interface for field updates when you don't need
+    /// to navigate to nested fields. It's equivalent to calling `with_lookup` with an
+    /// identity function that returns the same field path unchanged.
+    ///
+    /// This is particularly useful for applying update operations within the current
+    /// document context without having to deal with path navigation.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - The field name marker type that implements `FieldName`
+    /// * `N` - The configuration function type that defines update operations
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes an `UpdateBuilder<T>` and returns it configured with update operations
+    ///
+    /// # Returns
+    ///
+    /// Returns `&mut Self` to allow method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub age: i32,
+    ///     pub active: bool,
+    /// }
+    ///
+    /// // Apply multiple operations in the same context
+    /// let update_doc = empty::<User>()
+    ///     .with_field::<user_fields::Name, _>(|nested| {
+    ///         nested
+    ///             .set::<user_fields::Name, _>("John Doe".to_string())
+    ///             .inc::<user_fields::Age, _>(1)
+    ///             .set::<user_fields::Active, _>(true);
+    ///     })
+    ///     .build();
+    /// // Results in: {
+    /// //   "$set": {
+    /// //     "name": "John Doe",
+    /// //     "active": true
+    /// //   },
+    /// //   "$inc": { "age": 1 }
+    /// // }
+    /// ```
+    ///
+    /// # Comparison with Direct Method Calls
+    ///
+    /// The following two approaches are equivalent:
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Product {
+    ///     pub name: String,
+    ///     pub price: f64,
+    /// }
+    ///
+    /// // Using with_field
+    /// let update_doc1 = empty::<Product>()
+    ///     .with_field::<product_fields::Name, _>(|nested| {
+    ///         nested.set::<product_fields::Name, _>("Widget".to_string());
+    ///     })
+    ///     .build();
+    ///
+    /// // Using direct method calls
+    /// let update_doc2 = empty::<Product>()
+    ///     .set::<product_fields::Name, _>("Widget".to_string())
+    ///     .build();
+    ///
+    /// // Both produce the same result: { "$set": { "name": "Widget" } }
+    /// assert_eq!(update_doc1, update_doc2);
+    /// ```
+    pub fn with_field<F: FieldName, N>(&mut self, f: N) -> &mut Self
+    where
+        T: HasField<F>,
+        N: FnOnce(&mut UpdateBuilder<T>),
+    {
+        self.with_lookup::<F, _, F, T, _>(|path| path.clone(), f)
+    }
+
+    /// Builds the final MongoDB update document.
+    ///
+    /// This method consumes the accumulated update operations and produces a
+    /// `bson::Document` that can be used directly with MongoDB update queries.
+    /// All update clauses are organized by operation type (e.g., `$set`, `$inc`)
+    /// and combined into their respective operation documents, emitted in the canonical
+    /// order (see `build_ordered` for a custom one).
+    ///
+    /// # Returns
+    ///
+    /// A `bson::Document` containing all the update operations that were added
+    /// to this builder, organized by MongoDB update operator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct User {
+    ///     pub name: String,
+    ///     pub age: i32,
+    /// }
+    ///
+    /// let update_doc = empty::<User>()
+    ///     .set::<user_fields::Name, _>("John".to_string())
+    ///     .inc::<user_fields::Age, _>(1)
+    ///     .build();
+    /// // Results in: {
+    /// //   "$set": { "name": "John" },
+    /// //   "$inc": { "age": 1 }
+    /// // }
+    /// ```
+    ///
+    /// # Behavior with Multiple Operations
+    ///
+    /// Operations of the same type are combined into a single operation document:
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    /// use serde::{Serialize, Deserialize};
+    ///
+    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// struct Stats {
+    ///     pub views: i32,
+    ///     pub likes: i32,
+    /// }
+    ///
+    /// let update_doc = empty::<Stats>()
+    ///     .inc::<stats_fields::Views, _>(1)
+    ///     .inc::<stats_fields::Likes, _>(1)
+    ///     .build();
+    /// // Results in: {
+    /// //   "$inc": {
+    /// //     "views": 1,
+    /// //     "likes": 1
+    /// //   }
+    /// // }
+    /// ```
+    ///
+    /// # Behavior with Duplicate Fields
+    ///
+    /// For most operators, the last operation on a field wins (`try_build` instead rejects
+    /// it, e.g. when merging nested builders updating a same path). The array operators `$push`
+    /// and `$addToSet` instead accumulate: the values of all operations on the same field
+    /// are concatenated in a single `$each` (if several carry `$slice`, `$sort` or
+    /// `$position` modifiers, the last one wins).
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::empty};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Post {
+    ///     pub tags: Vec<String>,
+    /// }
+    ///
+    /// let update_doc = empty::<Post>()
+    ///     .push::<post_fields::Tags, _>("rust".to_string())
+    ///     .push_each::<post_fields::Tags, _, _, _>(vec!["mongodb".to_string()])
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     update_doc,
+    ///     bson::doc! { "$push": { "tags": { "$each": ["rust", "mongodb"] } } }
+    /// );
+    /// ```
+    #[must_use]
+    pub fn build(&mut self) -> bson::Document {
+        self.update_document()
+    }
+
+    /// Builds the update document as `build` does, but fails on the updates MongoDB would reject.
+    ///
+    /// MongoDB rejects at runtime an empty update document, or conflicting paths: the same path
+    /// updated by several operators (e.g. `$set` and `$setOnInsert`, see `set_on_insert`),
+    /// or a path updated along with its parent or child (e.g. `a` and `a.b`), whatever the
+    /// operators (the `$rename` targets included). This allows detecting them before calling
+    /// the driver (e.g. when all the operations are conditionally added).
+    /// It also detects a path updated several times by the same operator, which `build`
+    /// silently resolves by keeping the last value (except for `$push` and `$addToSet`,
+    /// whose values are accumulated).
+    ///
+    /// The positional paths (e.g. `items.$[item].qty`) are checked as they are, so the conflicts
+    /// depending on the matched array elements are only detected by MongoDB.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `InvalidUpdateError`, either `Empty` (with an `EmptyUpdateError`) if the update
+    /// document would be empty, `DuplicatePath` (with a `DuplicatePathError`) for a path updated
+    /// several times by the same operator, or `ConflictingPath` for a path conflicting with
+    /// another one. The paths are checked with the operators in the canonical order (as emitted
+    /// by `build`), then in call order, and the first invalid one is reported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::{EmptyUpdateError, InvalidUpdateError, empty}};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct User {
+    ///     pub name: String,
+    /// }
+    ///
+    /// let new_name: Option<String> = None;
+    /// let result = empty::<User>()
+    ///     .if_some(new_name, |b, name| b.set::<user_fields::Name, _>(name))
+    ///     .try_build();
+    ///
+    /// assert_eq!(result, Err(InvalidUpdateError::Empty(EmptyUpdateError)));
+    /// ```
+    pub fn try_build(&mut self) -> Result<bson::Document, InvalidUpdateError> {
+        // The paths already checked, with their operator
+        let mut checked: Vec<(&UpdateOperation, &str)> = Vec::new();
+
+        for op in self.canonical_operations() {
+            let accumulates = matches!(op, UpdateOperation::Push | UpdateOperation::AddToSet);
+
+            for (path, clause) in &self.clauses[op] {
+                // The target of a `$rename` is updated too
+                let target = match (op, clause) {
+                    (UpdateOperation::Rename, bson::Bson::String(target)) => Some(target.as_str()),
+                    _ => None,
+                };
+
+                for path in std::iter::once(path.as_str()).chain(target) {
+                    if checked.contains(&(op, path)) {
+                        if accumulates {
+                            continue;
+                        }
+
+                        return Err(InvalidUpdateError::DuplicatePath(DuplicatePathError {
+                            path: path.to_string(),
+                        }));
+                    }
+
+                    if checked
+                        .iter()
+                        .any(|(_, checked_path)| paths_conflict(checked_path, path))
+                    {
+                        return Err(InvalidUpdateError::ConflictingPath {
+                            path: path.to_string(),
+                        });
+                    }
+
+                    checked.push((op, path));
+                }
+            }
+        }
+
+        let doc = self.update_document();
+
+        if doc.is_empty() {
+            Err(EmptyUpdateError.into())
+        } else {
+            Ok(doc)
+        }
+    }
+
+    /// Builds the update document along with the array filters it requires.
+    ///
+    /// The update document is the same as the one returned by `build`, while the array
+    /// filters are the ones collected by `with_array_filter`, in call order.
+    ///
+    /// # Returns
+    ///
+    /// An `UpdateSpec` combining the update document and the array filters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, MongoComparable, updates::empty};
+    ///
+    /// #[derive(FieldWitnesses, MongoComparable)]
+    /// struct Item {
+    ///     pub quantity: i32,
+    /// }
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Order {
+    ///     pub items: Vec<Item>,
+    /// }
+    ///
+    /// let spec = empty::<Order>()
+    ///     .with_array_filter::<order_fields::Items, Item, _, _>(
+    ///         "empty",
+    ///         |filter| {
+    ///             filter.lte::<item_fields::Quantity, _>(0);
+    ///         },
+    ///         |nested| {
+    ///             nested.set::<item_fields::Quantity, _>(10);
+    ///         },
+    ///     )
+    ///     .expect("valid array filter")
+    ///     .build_spec();
+    ///
+    /// assert_eq!(spec.update, bson::doc! { "$set": { "items.$[empty].quantity": 10 } });
+    /// assert_eq!(spec.array_filters, vec![bson::doc! { "empty.quantity": { "$lte": 0 } }]);
+    ///
+    /// // let options = UpdateOptions::builder().array_filters(spec.array_filters).build();
+    /// // collection.update_one(filter, spec.update).with_options(options).await?;
+    /// ```
+    #[must_use]
+    pub fn build_spec(&mut self) -> UpdateSpec {
+        UpdateSpec {
+            update: self.update_document(),
+            array_filters: self.array_filters.clone(),
+        }
+    }
+
+    /// Builds the update document as `build` does, with the operators emitted in a custom order.
+    ///
+    /// The order of the operators doesn't change how MongoDB applies the update, but it can
+    /// matter for observability (e.g. logged or snapshot updates). The listed operators come
+    /// first, in the given order, then the other ones in the canonical order (the declaration
+    /// order of `UpdateOperation`, as used by `build`).
+    ///
+    /// # Parameters
+    ///
+    /// * `order` - The operators to be emitted first
+    ///
+    /// # Returns
+    ///
+    /// The update document, with the operators in the requested order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tnuctipun::{FieldWitnesses, updates::{UpdateOperation, empty}};
+    ///
+    /// #[derive(FieldWitnesses)]
+    /// struct Document {
+    ///     pub name: String,
+    ///     pub last_modified: bson::DateTime,
+    /// }
+    ///
+    /// let update_doc = empty::<Document>()
+    ///     .set::<document_fields::Name, _>("Updated")
+    ///     .touch::<document_fields::LastModified>()
+    ///     .build_ordered(&[UpdateOperation::CurrentDate]);
+    ///
+    /// assert_eq!(update_doc.keys().collect::<Vec<_>>(), ["$currentDate", "$set"]);
+    /// ```
+    #[must_use]
+    pub fn build_ordered(&mut self, order: &[UpdateOperation]) -> bson::Document {
+        self.ordered_update_document(order)
+    }
+
+    fn update_document(&self) -> bson::Document {
+        self.ordered_update_document(&[])
+    }
+
+    // Returns the operators having clauses, in the canonical order
+    fn canonical_operations(&self) -> Vec<&UpdateOperation> {
+        let mut operations: Vec<_> = self.clauses.keys().collect();
+
+        operations.sort_by_key(|operation| operation.canonical_rank());
+
+        operations
+    }
+
+    fn ordered_update_document(&self, order: &[UpdateOperation]) -> bson::Document {
+        let mut doc = bson::Document::new();
+        let operations = order.iter().chain(
+            self.canonical_operations()
+                .into_iter()
+                .filter(|operation| !order.contains(operation)),
+        );
+
+        for op in operations {
+            let operation = op.as_str();
+
+            let Some(op_clauses) = self.clauses.get(op) else {
+                continue;
+            };
+
+            if doc.contains_key(operation) {
+                continue; // Listed several times in the custom order
+            }
+
+            let mut operation_doc = bson::Document::new();
+            let accumulates = matches!(op, UpdateOperation::Push | UpdateOperation::AddToSet);
+
+            for (field, clause) in op_clauses {
+                let value = match operation_doc.get(field) {
+                    Some(previous) if accumulates => merge_each_clauses(previous, clause),
+                    _ => clause.clone(),
+                };
+
+                operation_doc.insert(field.clone(), value);
+            }
+
+            // Insert the operation document into the main document
+            doc.insert(operation, operation_doc);
         }
 
-        self
+        #[cfg(feature = "tracing")]
+        crate::instrumentation::trace_built("update", &doc);
+
+        doc
     }
 
-    /// Convenience method for updating fields using identity lookup.
-    ///
-    /// This method provides a simpler interface for field updates when you don't need
-    /// to navigate to nested fields. It's equivalent to calling `with_lookup` with an
-    /// identity function that returns the same field path unchanged.
-    ///
-    /// This is particularly useful for applying update operations within the current
-    /// document context without having to deal with path navigation.
-    ///
-    /// # Type Parameters
-    ///
-    /// * `F` - The field name marker type that implements `FieldName`
-    /// * `N` - The configuration function type that defines update operations
-    ///
-    /// # Parameters
+    /// Builds the update as `build()` does, and encodes the result as a raw BSON buffer.
     ///
-    /// * `f` - A function that takes an `UpdateBuilder<T>` and returns it configured with update operations
+    /// As for `FilterBuilder::and_raw_buf`, the update document is built and then encoded once,
+    /// for the APIs accepting a `RawDocumentBuf`.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns `&mut Self` to allow method chaining.
+    /// Returns an error if the document cannot be encoded (e.g. a key containing a null byte).
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use tnuctipun::{FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
+    /// use tnuctipun::updates::empty;
+    /// use tnuctipun::{FieldWitnesses, MongoComparable};
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// #[derive(FieldWitnesses, MongoComparable)]
     /// struct User {
     ///     pub name: String,
-    ///     pub age: i32,
-    ///     pub active: bool,
-    /// }
-    ///
-    /// // Apply multiple operations in the same context
-    /// let update_doc = empty::<User>()
-    ///     .with_field::<user_fields::Name, _>(|nested| {
-    ///         nested
-    ///             .set::<user_fields::Name, _>("John Doe".to_string())
-    ///             .inc::<user_fields::Age, _>(1)
-    ///             .set::<user_fields::Active, _>(true);
-    ///     })
-    ///     .build();
-    /// // Results in: {
-    /// //   "$set": {
-    /// //     "name": "John Doe",
-    /// //     "active": true
-    /// //   },
-    /// //   "$inc": { "age": 1 }
-    /// // }
-    /// ```
-    ///
-    /// # Comparison with Direct Method Calls
-    ///
-    /// The following two approaches are equivalent:
-    ///
-    /// ```rust
-    /// use tnuctipun::{FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
-    ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Product {
-    ///     pub name: String,
-    ///     pub price: f64,
     /// }
     ///
-    /// // Using with_field
-    /// let update_doc1 = empty::<Product>()
-    ///     .with_field::<product_fields::Name, _>(|nested| {
-    ///         nested.set::<product_fields::Name, _>("Widget".to_string());
-    ///     })
-    ///     .build();
-    ///
-    /// // Using direct method calls
-    /// let update_doc2 = empty::<Product>()
-    ///     .set::<product_fields::Name, _>("Widget".to_string())
-    ///     .build();
+    /// let raw = empty::<User>()
+    ///     .set::<user_fields::Name, _>("John Doe")
+    ///     .build_raw_buf()
+    ///     .expect("encodable update");
     ///
-    /// // Both produce the same result: { "$set": { "name": "Widget" } }
-    /// assert_eq!(update_doc1, update_doc2);
+    /// assert!(raw.get_document("$set").is_ok());
     /// ```
-    pub fn with_field<F: FieldName, N>(&mut self, f: N) -> &mut Self
-    where
-        T: HasField<F>,
-        N: FnOnce(&mut UpdateBuilder<T>),
-    {
-        self.with_lookup::<F, _, F, T, _>(|path| path.clone(), f)
+    pub fn build_raw_buf(&mut self) -> bson::error::Result<bson::RawDocumentBuf> {
+        bson::RawDocumentBuf::try_from(&self.build())
     }
 
-    /// Builds the final MongoDB update document.
+    /// Renders the update document built as `build()` does as canonical JSON.
     ///
-    /// This method consumes the accumulated update operations and produces a
-    /// `bson::Document` that can be used directly with MongoDB update queries.
-    /// All update clauses are organized by operation type (e.g., `$set`, `$inc`)
-    /// and combined into their respective operation documents.
+    /// As for `FilterBuilder::to_canonical_json`, the keys of all the nested documents are
+    /// sorted (including the operators) and the JSON is compact, so the output is stable
+    /// for snapshot tests, but for comparison only. Requires the `serde_json` feature.
     ///
     /// # Returns
     ///
-    /// A `bson::Document` containing all the update operations that were added
-    /// to this builder, organized by MongoDB update operator.
+    /// The canonical JSON of the update document.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use tnuctipun::{FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
     ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
+    /// #[derive(FieldWitnesses)]
     /// struct User {
     ///     pub name: String,
     ///     pub age: i32,
     /// }
     ///
-    /// let update_doc = empty::<User>()
-    ///     .set::<user_fields::Name, _>("John".to_string())
+    /// let json = empty::<User>()
+    ///     .set::<user_fields::Name, _>("John")
     ///     .inc::<user_fields::Age, _>(1)
-    ///     .build();
-    /// // Results in: {
-    /// //   "$set": { "name": "John" },
-    /// //   "$inc": { "age": 1 }
-    /// // }
-    /// ```
-    ///
-    /// # Behavior with Multiple Operations
-    ///
-    /// Operations of the same type are combined into a single operation document:
-    ///
-    /// ```rust
-    /// use tnuctipun::{FieldWitnesses, updates::empty};
-    /// use serde::{Serialize, Deserialize};
-    ///
-    /// #[derive(FieldWitnesses, Serialize, Deserialize)]
-    /// struct Stats {
-    ///     pub views: i32,
-    ///     pub likes: i32,
-    /// }
+    ///     .to_canonical_json();
     ///
-    /// let update_doc = empty::<Stats>()
-    ///     .inc::<stats_fields::Views, _>(1)
-    ///     .inc::<stats_fields::Likes, _>(1)
-    ///     .build();
-    /// // Results in: {
-    /// //   "$inc": {
-    /// //     "views": 1,
-    /// //     "likes": 1
-    /// //   }
-    /// // }
+    /// assert_eq!(json, r#"{"$inc":{"age":1},"$set":{"name":"John"}}"#);
     /// ```
-    pub fn build(&mut self) -> bson::Document {
-        let mut doc = bson::Document::new();
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    pub fn to_canonical_json(&self) -> String {
+        crate::canonical_json::to_canonical_json(&self.update_document())
+    }
+}
 
-        for (op, op_clauses) in &self.clauses {
-            let operation = op.as_str();
-            let mut operation_doc = bson::Document::new();
+/// Converts an integer to `bson::Decimal128`, failing if it would be rounded.
+fn integer_to_decimal128<N: PrimInt + std::fmt::Display>(
+    value: N,
+) -> bson::error::Result<bson::Decimal128> {
+    value.to_string().parse()
+}
 
-            for (field, clause) in op_clauses {
-                operation_doc.insert(field.clone(), clause.clone());
+/// Merges two `$push`/`$addToSet` clauses for the same field into a single `$each` clause.
+///
+/// A plain value is considered as a single-element `$each`; the modifiers of the second
+/// clause (e.g. `$slice`) take precedence over the ones of the first clause.
+fn merge_each_clauses(previous: &bson::Bson, clause: &bson::Bson) -> bson::Bson {
+    fn split(clause: &bson::Bson) -> (Vec<bson::Bson>, bson::Document) {
+        match clause {
+            bson::Bson::Document(doc) if doc.contains_key("$each") => {
+                let mut modifiers = doc.clone();
+                let values = match modifiers.remove("$each") {
+                    Some(bson::Bson::Array(values)) => values,
+                    Some(value) => vec![value],
+                    None => vec![],
+                };
+
+                (values, modifiers)
             }
-
-            // Insert the operation document into the main document
-            doc.insert(operation, operation_doc);
+            value => (vec![value.clone()], bson::Document::new()),
         }
+    }
 
-        doc
+    let (mut values, mut modifiers) = split(previous);
+    let (next_values, next_modifiers) = split(clause);
+
+    values.extend(next_values);
+    modifiers.extend(next_modifiers);
+
+    let mut merged = bson::doc! { "$each": values };
+
+    merged.extend(modifiers);
+
+    bson::Bson::Document(merged)
+}
+
+/// Abstraction over values that can be turned into a MongoDB update document.
+///
+/// It allows generic code (e.g. repositories over `T`) to accept `impl IntoMongoUpdate`
+/// without depending on the concrete builder kind. It's implemented by `UpdateBuilder<T>`,
+/// and by `&mut UpdateBuilder<T>` so a fluent chain can be passed directly.
+///
+/// # Examples
+///
+/// ```rust
+/// use tnuctipun::updates::{IntoMongoUpdate, empty};
+/// use tnuctipun::FieldWitnesses;
+///
+/// #[derive(FieldWitnesses)]
+/// struct User {
+///     pub name: String,
+/// }
+///
+/// fn update_one(update: impl IntoMongoUpdate) -> bson::Document {
+///     update.into_document()
+/// }
+///
+/// let update = update_one(empty::<User>().set::<user_fields::Name, _>("John"));
+///
+/// assert_eq!(update, bson::doc! { "$set": { "name": "John" } });
+/// ```
+pub trait IntoMongoUpdate {
+    /// Returns the update document, with the same semantics as `UpdateBuilder::build`.
+    fn into_document(self) -> bson::Document;
+}
+
+impl<T> IntoMongoUpdate for UpdateBuilder<T> {
+    fn into_document(mut self) -> bson::Document {
+        self.build()
+    }
+}
+
+impl<T> IntoMongoUpdate for &mut UpdateBuilder<T> {
+    fn into_document(self) -> bson::Document {
+        self.build()
     }
 }
 
 // ---
 
+/// Renames many fields at once, from pairs of source and target field witnesses.
+///
+/// As the witness types are distinct, they cannot be given as a runtime collection: this
+/// macro expands to a `rename_to` call per pair on the builder, so both sides of each pair
+/// are verified at compile time (the source fields in the builder struct, and the target
+/// fields in the given target struct). It evaluates to the `&mut UpdateBuilder`.
+///
+/// # Examples
+///
+/// ```rust
+/// use tnuctipun::{FieldWitnesses, rename_pairs, updates::empty};
+///
+/// #[derive(FieldWitnesses)]
+/// struct UserV1 {
+///     pub mail: String,
+///     pub tel: String,
+/// }
+///
+/// #[derive(FieldWitnesses)]
+/// struct UserV2 {
+///     pub email: String,
+///     pub phone: String,
+/// }
+///
+/// let mut builder = empty::<UserV1>();
+///
+/// rename_pairs!(builder, UserV2;
+///     (userv1_fields::Mail, userv2_fields::Email),
+///     (userv1_fields::Tel, userv2_fields::Phone),
+/// );
+///
+/// assert_eq!(
+///     builder.build(),
+///     bson::doc! { "$rename": { "mail": "email", "tel": "phone" } }
+/// );
+/// ```
+///
+/// The compilation fails if a target witness is not a field of the target struct:
+///
+/// ```compile_fail,E0277
+/// use tnuctipun::{FieldWitnesses, rename_pairs, updates::empty};
+///
+/// #[derive(FieldWitnesses)]
+/// struct UserV1 {
+///     pub mail: String,
+/// }
+///
+/// #[derive(FieldWitnesses)]
+/// struct UserV2 {
+///     pub email: String,
+/// }
+///
+/// let mut builder = empty::<UserV1>();
+///
+/// rename_pairs!(builder, UserV2; (userv1_fields::Mail, userv1_fields::Mail));
+/// ```
+#[macro_export]
+macro_rules! rename_pairs {
+    ($builder:expr, $target:ty; $(($from:ty, $to:ty)),+ $(,)?) => {
+        $builder $(.rename_to::<$from, $target, $to>())+
+    };
+}
+
 /// MongoDB update operations that can be performed on documents.
 ///
 /// This enum represents the various update operators available in MongoDB,
@@ -1537,6 +3380,12 @@ pub enum UpdateOperation {
     /// If the field does not exist, `$set` will add a new field with the specified value.
     Set,
 
+    /// Sets the value of a field only when an upsert inserts a new document.
+    ///
+    /// Corresponds to MongoDB's `$setOnInsert` operator, which has no effect when the update
+    /// modifies an existing document. A path cannot be both in `$set` and `$setOnInsert`.
+    SetOnInsert,
+
     /// Removes the specified field from a document.
     ///
     /// Corresponds to MongoDB's `$unset` operator, which deletes a particular field.
@@ -1616,6 +3465,7 @@ impl UpdateOperation {
     pub const fn as_str(&self) -> &'static str {
         match self {
             UpdateOperation::Set => "$set",
+            UpdateOperation::SetOnInsert => "$setOnInsert",
             UpdateOperation::Unset => "$unset",
             UpdateOperation::Inc => "$inc",
             UpdateOperation::Max => "$max",
@@ -1630,6 +3480,27 @@ impl UpdateOperation {
             UpdateOperation::Push => "$push",
         }
     }
+
+    // Returns the rank of the operator in the canonical emission order (the declaration order),
+    // matched exhaustively so that any new operator has to be ranked
+    const fn canonical_rank(&self) -> u8 {
+        match self {
+            UpdateOperation::Set => 0,
+            UpdateOperation::SetOnInsert => 1,
+            UpdateOperation::Unset => 2,
+            UpdateOperation::Inc => 3,
+            UpdateOperation::Max => 4,
+            UpdateOperation::Min => 5,
+            UpdateOperation::Mul => 6,
+            UpdateOperation::Rename => 7,
+            UpdateOperation::CurrentDate => 8,
+            UpdateOperation::AddToSet => 9,
+            UpdateOperation::Pop => 10,
+            UpdateOperation::Pull => 11,
+            UpdateOperation::PullAll => 12,
+            UpdateOperation::Push => 13,
+        }
+    }
 }
 
 /// Controls how many elements to keep in an array after a `$push` operation with `$each`.
@@ -1973,6 +3844,168 @@ impl<Values: IntoIterator<Item = V>, V: Into<bson::Bson>> From<PushEach<Values,
     }
 }
 
+/// Error raised by [`UpdateBuilder::try_build`] when the update would be rejected by MongoDB.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidUpdateError {
+    /// No update operation was added.
+    Empty(EmptyUpdateError),
+
+    /// The path is updated several times by the same operator, only the last value being kept.
+    DuplicatePath(DuplicatePathError),
+
+    /// The path is updated by another operator, or its parent or child is updated.
+    ConflictingPath {
+        /// The conflicting path (the last one checked)
+        path: String,
+    },
+}
+
+impl std::fmt::Display for InvalidUpdateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidUpdateError::Empty(error) => write!(f, "{error}"),
+            InvalidUpdateError::DuplicatePath(error) => write!(f, "{error}"),
+            InvalidUpdateError::ConflictingPath { path } => write!(
+                f,
+                "Conflicting path: '{path}' conflicts with another updated path"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvalidUpdateError {}
+
+impl From<EmptyUpdateError> for InvalidUpdateError {
+    fn from(error: EmptyUpdateError) -> Self {
+        InvalidUpdateError::Empty(error)
+    }
+}
+
+/// Error raised by [`UpdateBuilder::try_build`] (as `InvalidUpdateError::Empty`) when no update
+/// operation was added.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmptyUpdateError;
+
+impl std::fmt::Display for EmptyUpdateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Empty update: no operation was added")
+    }
+}
+
+impl std::error::Error for EmptyUpdateError {}
+
+// Checks whether the paths are the same, or one is the parent of the other
+fn paths_conflict(a: &str, b: &str) -> bool {
+    let (shorter, longer) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    longer
+        .strip_prefix(shorter)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// Error raised by [`UpdateBuilder::with_array_filter`] when the array filter would be
+/// rejected by MongoDB.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArrayFilterError {
+    /// The identifier doesn't start with a lowercase letter, or has non-alphanumeric characters.
+    InvalidIdentifier(String),
+
+    /// The condition adds no clause, so no array filter would match the identifier.
+    EmptyCondition(String),
+}
+
+impl std::fmt::Display for ArrayFilterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArrayFilterError::InvalidIdentifier(identifier) => write!(
+                f,
+                "Invalid array filter identifier: '{identifier}' must start with a lowercase letter and only contain alphanumeric characters"
+            ),
+            ArrayFilterError::EmptyCondition(identifier) => write!(
+                f,
+                "Empty array filter: the condition for '{identifier}' adds no clause"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ArrayFilterError {}
+
+/// Error raised by [`UpdateBuilder::set_once`] when the field path is already set,
+/// or by [`UpdateBuilder::try_build`] (as `InvalidUpdateError::DuplicatePath`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicatePathError {
+    path: String,
+}
+
+impl DuplicatePathError {
+    /// Returns the duplicate field path
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl std::fmt::Display for DuplicatePathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Duplicate path: '{}' is already set", self.path)
+    }
+}
+
+impl std::error::Error for DuplicatePathError {}
+
+/// Fluent configuration of a `$push` with `$each`, returned by
+/// [`UpdateBuilder::push_each_with`].
+///
+/// The configured clause is added to the borrowed builder by [`done`](Self::done),
+/// or when the configurator is dropped.
+pub struct PushEachConfigurator<'a, T, I: IntoIterator<Item = V>, V: Into<bson::Bson>> {
+    builder: Option<&'a mut UpdateBuilder<T>>,
+    path: String,
+    clause: Option<PushEach<I, V>>,
+}
+
+impl<'a, T, I: IntoIterator<Item = V>, V: Into<bson::Bson>> PushEachConfigurator<'a, T, I, V> {
+    /// Sets the `$slice` modifier (see [`PushEach::with_slice`]).
+    pub fn slice(mut self, slice: PushEachSlice) -> Self {
+        self.clause = self.clause.take().map(|c| c.with_slice(slice));
+        self
+    }
+
+    /// Sets the `$sort` modifier (see [`PushEach::with_sort`]).
+    pub fn sort(mut self, sort: PushEachSort) -> Self {
+        self.clause = self.clause.take().map(|c| c.with_sort(sort));
+        self
+    }
+
+    /// Sets the `$position` modifier (see [`PushEach::with_position`]).
+    pub fn position(mut self, position: PushEachPosition) -> Self {
+        self.clause = self.clause.take().map(|c| c.with_position(position));
+        self
+    }
+
+    /// Adds the configured `$push` clause to the builder, and returns it to continue chaining.
+    pub fn done(mut self) -> &'a mut UpdateBuilder<T> {
+        self.commit();
+
+        // The builder is only taken by `done`, which consumes the configurator
+        self.builder.take().unwrap()
+    }
+
+    fn commit(&mut self) {
+        if let (Some(builder), Some(clause)) = (self.builder.as_mut(), self.clause.take()) {
+            let path = std::mem::take(&mut self.path);
+
+            builder.push_clause(UpdateOperation::Push, path, clause.into());
+        }
+    }
+}
+
+impl<T, I: IntoIterator<Item = V>, V: Into<bson::Bson>> Drop for PushEachConfigurator<'_, T, I, V> {
+    fn drop(&mut self) {
+        self.commit();
+    }
+}
+
 /// Converts `UpdateOperation` variants to their corresponding MongoDB operator strings.
 ///
 /// This implementation allows `UpdateOperation` enum variants to be converted to the
@@ -2175,6 +4208,7 @@ impl From<PopStrategy> for bson::Bson {
 /// //   "$unset": { "email": null }
 /// // }
 /// ```
+#[must_use]
 pub fn empty<T>() -> UpdateBuilder<T> {
     UpdateBuilder::new()
 }
@@ -0,0 +1,88 @@
+#![cfg(feature = "serde_json")]
+
+use bson::oid::ObjectId;
+use tnuctipun::{FieldWitnesses, MongoComparable};
+use tnuctipun::{filters, updates};
+
+#[derive(Debug, Clone, FieldWitnesses, MongoComparable)]
+pub struct Product {
+    #[tnuctipun(rename = "_id")]
+    pub id: ObjectId,
+    pub name: String,
+    pub price: f64,
+    pub stock: i32,
+    pub tags: Vec<String>,
+}
+
+fn complex_filter(id: ObjectId) -> filters::FilterBuilder<Product> {
+    let mut builder = filters::empty::<Product>();
+
+    builder
+        .field::<product_fields::Price>()
+        .lt(1000.0)
+        .gte(100.0)
+        .apply()
+        .ne::<product_fields::Id, _>(id)
+        .or_values::<product_fields::Name, _>(vec!["Laptop", "Phone"])
+        .r#in::<product_fields::Tags, _>(vec!["sale".to_string(), "new".to_string()]);
+
+    builder
+}
+
+#[test]
+fn test_filter_canonical_json_is_stable() {
+    let id = ObjectId::parse_str("64b7f0c2a1b2c3d4e5f60718").unwrap();
+
+    let first = complex_filter(id).to_canonical_json();
+    let second = complex_filter(id).to_canonical_json();
+
+    let expected = concat!(
+        r#"{"$and":["#,
+        r#"{"price":{"$gte":100.0,"$lt":1000.0}},"#,
+        r#"{"_id":{"$ne":{"$oid":"64b7f0c2a1b2c3d4e5f60718"}}},"#,
+        r#"{"$or":[{"name":"Laptop"},{"name":"Phone"}]},"#,
+        r#"{"tags":{"$in":["sale","new"]}}"#,
+        r#"]}"#
+    );
+
+    assert_eq!(first, expected);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_filter_canonical_json_sorts_keys() {
+    let lt_first = filters::empty::<Product>()
+        .field::<product_fields::Stock>()
+        .lt(10)
+        .gt(0)
+        .apply()
+        .to_canonical_json();
+
+    let gt_first = filters::empty::<Product>()
+        .field::<product_fields::Stock>()
+        .gt(0)
+        .lt(10)
+        .apply()
+        .to_canonical_json();
+
+    assert_eq!(lt_first, gt_first);
+    assert_eq!(lt_first, r#"{"stock":{"$gt":0,"$lt":10}}"#);
+}
+
+#[test]
+fn test_update_canonical_json_sorts_operators() {
+    let json = updates::empty::<Product>()
+        .set::<product_fields::Stock, _>(5)
+        .set::<product_fields::Name, _>("Laptop")
+        .push::<product_fields::Tags, _>("sale".to_string())
+        .inc::<product_fields::Price, _>(10.5)
+        .to_canonical_json();
+
+    let expected = concat!(
+        r#"{"$inc":{"price":10.5},"#,
+        r#""$push":{"tags":"sale"},"#,
+        r#""$set":{"name":"Laptop","stock":5}}"#
+    );
+
+    assert_eq!(json, expected);
+}
@@ -6,14 +6,14 @@ use tnuctipun::{FieldWitnesses, MongoComparable};
 
 #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
 struct Product {
-    pub Name: String,
+    pub Active: bool,
 }
 
 fn main() {
-    // This must fail because String is comparable but not ordered for $gt.
+    // This must fail because bool is comparable but not ordered for $gt.
     let b = expr::empty::<Product>();
-    let left = b.select::<product_fields::Name>();
-    let right = b.from("abc".to_string());
+    let left = b.select::<product_fields::Active>();
+    let right = b.from(true);
 
     let _ = b.gt(left, right);
 }
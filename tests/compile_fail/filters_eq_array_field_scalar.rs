@@ -0,0 +1,17 @@
+// This test verifies that comparing an array field with a scalar of another type than its items
+// will cause a compile error
+
+use tnuctipun::{FieldWitnesses, MongoComparable};
+use tnuctipun::filters::empty;
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+struct Product {
+    pub Tags: Vec<String>,
+}
+
+fn main() {
+    // This should fail to compile because the items of the Vec<String> field are not integers
+    let mut builder = empty::<Product>();
+    builder.eq::<product_fields::Tags, _>(42);
+}
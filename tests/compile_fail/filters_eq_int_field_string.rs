@@ -0,0 +1,16 @@
+// This test verifies that comparing an integer field with a string will cause a compile error
+
+use tnuctipun::{FieldWitnesses, MongoComparable};
+use tnuctipun::filters::empty;
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+struct Product {
+    pub Stock: i32,
+}
+
+fn main() {
+    // This should fail to compile because we're trying to use a string for an i32 field
+    let mut builder = empty::<Product>();
+    builder.eq::<product_fields::Stock, _>("42");
+}
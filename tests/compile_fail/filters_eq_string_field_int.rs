@@ -0,0 +1,16 @@
+// This test verifies that comparing a String field with an integer will cause a compile error
+
+use tnuctipun::{FieldWitnesses, MongoComparable};
+use tnuctipun::filters::empty;
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+struct Product {
+    pub Name: String,
+}
+
+fn main() {
+    // This should fail to compile because we're trying to use an integer for a String field
+    let mut builder = empty::<Product>();
+    builder.eq::<product_fields::Name, _>(42);
+}
@@ -0,0 +1,19 @@
+// This test verifies that dropping a field condition without applying it triggers
+// the unused_must_use lint
+
+#![deny(unused_must_use)]
+
+use tnuctipun::filters::empty;
+use tnuctipun::{FieldWitnesses, MongoComparable};
+
+#[derive(FieldWitnesses, MongoComparable)]
+struct Product {
+    pub price: f64,
+}
+
+fn main() {
+    let mut builder = empty::<Product>();
+
+    // This should fail to compile because the condition is dropped without `apply()`
+    builder.field::<product_fields::Price>().gte(1.0).lt(10.0);
+}
@@ -6,11 +6,11 @@ use tnuctipun::{FieldWitnesses, MongoComparable};
 
 #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
 struct Product {
-    pub Name: String,
+    pub Active: bool,
 }
 
 fn main() {
-    // This must fail because String is comparable but not ordered for $gt.
+    // This must fail because bool is comparable but not ordered for $gt.
     let mut builder = empty::<Product>();
-    builder.gt::<product_fields::Name, _>("abc".to_string());
+    builder.gt::<product_fields::Active, _>(true);
 }
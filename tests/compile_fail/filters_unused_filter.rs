@@ -0,0 +1,20 @@
+// This test verifies that dropping a built filter triggers the unused_must_use lint
+
+#![deny(unused_must_use)]
+
+use tnuctipun::filters::empty;
+use tnuctipun::{FieldWitnesses, MongoComparable};
+
+#[derive(FieldWitnesses, MongoComparable)]
+struct User {
+    pub name: String,
+}
+
+fn main() {
+    let mut builder = empty::<User>();
+
+    builder.eq::<user_fields::Name, _>("John".to_string());
+
+    // This should fail to compile because the built filter is dropped
+    builder.and();
+}
@@ -0,0 +1,15 @@
+// Test that the MongoComparable derive macro rejects a bson_type declared on a field
+// sharing its type with another field, as the comparability is defined per field type
+
+use tnuctipun::{FieldWitnesses, MongoComparable};
+
+#[derive(Debug, Clone, FieldWitnesses, MongoComparable)]
+pub struct Event {
+    #[tnuctipun(bson_type = "date")]
+    pub created_at: String,
+    pub label: String,
+}
+
+fn main() {
+    // This should not compile
+}
@@ -0,0 +1,17 @@
+// Test that a bson_type declared on a field doesn't widen the comparability of a sibling field
+
+use tnuctipun::{FieldWitnesses, MongoComparable};
+use tnuctipun::filters::empty;
+
+#[derive(Debug, Clone, FieldWitnesses, MongoComparable)]
+pub struct Event {
+    #[tnuctipun(bson_type = "long")]
+    pub counter: String,
+    pub label: Option<String>,
+}
+
+fn main() {
+    // This should fail to compile, as only `counter` is declared as a BSON long
+    let mut builder = empty::<Event>();
+    builder.eq::<event_fields::Label, _>(42i64);
+}
@@ -0,0 +1,13 @@
+// Test that the MongoComparable derive macro fails with an unsupported bson_type
+
+use tnuctipun::{FieldWitnesses, MongoComparable};
+
+#[derive(Debug, Clone, FieldWitnesses, MongoComparable)]
+pub struct Event {
+    #[tnuctipun(bson_type = "timestamp")]
+    pub created_at: String,
+}
+
+fn main() {
+    // This should not compile
+}
@@ -0,0 +1,14 @@
+// This test verifies that UpdateBuilder::add_to_set_each rejects values of another type than the array elements
+
+use tnuctipun::FieldWitnesses;
+use tnuctipun::updates::empty;
+
+#[derive(FieldWitnesses)]
+struct Game {
+    pub scores: Vec<i32>,
+}
+
+fn main() {
+    // This should fail to compile because the items are i64, while the elements are i32
+    empty::<Game>().add_to_set_each::<game_fields::Scores, _, _>(1i64..=3);
+}
@@ -0,0 +1,14 @@
+// This test verifies that UpdateBuilder::max_date rejects non-date fields
+
+use tnuctipun::FieldWitnesses;
+use tnuctipun::updates::empty;
+
+#[derive(FieldWitnesses)]
+struct Account {
+    pub last_login: String,
+}
+
+fn main() {
+    // This should fail to compile because `last_login` is a String, not a date
+    empty::<Account>().max_date::<account_fields::LastLogin, _>(bson::DateTime::now());
+}
@@ -0,0 +1,14 @@
+// This test verifies that UpdateBuilder::pop rejects scalar (non-array) fields
+
+use tnuctipun::FieldWitnesses;
+use tnuctipun::updates::{PopStrategy, empty};
+
+#[derive(FieldWitnesses)]
+struct User {
+    pub name: String,
+}
+
+fn main() {
+    // This should fail to compile because `name` is a String, not an array
+    empty::<User>().pop::<user_fields::Name>(PopStrategy::First);
+}
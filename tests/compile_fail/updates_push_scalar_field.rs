@@ -0,0 +1,14 @@
+// This test verifies that UpdateBuilder::push rejects scalar (non-array) fields
+
+use tnuctipun::FieldWitnesses;
+use tnuctipun::updates::empty;
+
+#[derive(FieldWitnesses)]
+struct Counter {
+    pub value: i32,
+}
+
+fn main() {
+    // This should fail to compile because `value` is an i32, not an array
+    empty::<Counter>().push::<counter_fields::Value, _>(1);
+}
@@ -0,0 +1,16 @@
+// This test verifies that UpdateBuilder::set_date rejects chrono::DateTime fields,
+// which serde serializes as RFC 3339 strings rather than BSON dates
+
+use std::time::SystemTime;
+use tnuctipun::FieldWitnesses;
+use tnuctipun::updates::empty;
+
+#[derive(FieldWitnesses)]
+struct Session {
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+fn main() {
+    // This should fail to compile because `expires_at` isn't stored as a BSON date
+    empty::<Session>().set_date::<session_fields::ExpiresAt, _>(SystemTime::now());
+}
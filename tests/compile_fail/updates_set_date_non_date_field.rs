@@ -0,0 +1,15 @@
+// This test verifies that UpdateBuilder::set_date rejects non-date fields
+
+use std::time::SystemTime;
+use tnuctipun::FieldWitnesses;
+use tnuctipun::updates::empty;
+
+#[derive(FieldWitnesses)]
+struct Session {
+    pub expires_at: i64,
+}
+
+fn main() {
+    // This should fail to compile because `expires_at` is an i64, not a date
+    empty::<Session>().set_date::<session_fields::ExpiresAt, _>(SystemTime::now());
+}
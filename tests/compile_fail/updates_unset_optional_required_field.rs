@@ -0,0 +1,15 @@
+// This test verifies that UpdateBuilder::unset_optional rejects the fields required to deserialize
+
+use tnuctipun::FieldWitnesses;
+use tnuctipun::updates::empty;
+
+#[derive(FieldWitnesses)]
+struct Profile {
+    pub name: String,
+    pub nickname: Option<String>,
+}
+
+fn main() {
+    // This should fail to compile because `name` is neither an Option nor has a serde default
+    empty::<Profile>().unset_optional::<profile_fields::Name>();
+}
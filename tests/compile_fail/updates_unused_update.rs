@@ -0,0 +1,16 @@
+// This test verifies that dropping an update builder triggers the unused_must_use lint
+
+#![deny(unused_must_use)]
+
+use tnuctipun::FieldWitnesses;
+use tnuctipun::updates::empty;
+
+#[derive(FieldWitnesses)]
+struct User {
+    pub name: String,
+}
+
+fn main() {
+    // This should fail to compile because the builder is dropped
+    empty::<User>();
+}
@@ -0,0 +1,13 @@
+// Test that the FieldWitnesses derive macro alone fails with an unsupported bson_type
+
+use tnuctipun::FieldWitnesses;
+
+#[derive(Debug, Clone, FieldWitnesses)]
+pub struct Event {
+    #[tnuctipun(bson_type = "timestamp")]
+    pub created_at: String,
+}
+
+fn main() {
+    // This should not compile
+}
@@ -47,3 +47,163 @@ fn test_derive_field_witnesses_macro() {
     assert_eq!(deriveuser_fields::IsActive::field_name(), "is_active");
     assert_eq!(deriveuser_fields::Score::field_name(), "score");
 }
+
+#[test]
+fn test_derive_field_names() {
+    use tnuctipun::FieldNames;
+
+    #[derive(FieldWitnesses)]
+    #[tnuctipun(field_naming = "camelCase")]
+    #[allow(dead_code)]
+    struct Account {
+        pub user_name: String,
+        #[tnuctipun(rename = "mail")]
+        pub email_address: String,
+        #[tnuctipun(skip)]
+        pub cache: String,
+        secret: String,
+    }
+
+    assert_eq!(Account::field_names(), &["userName", "mail"]);
+}
+
+#[test]
+fn test_derive_skips_serde_skipped_fields() {
+    use tnuctipun::FieldNames;
+
+    #[derive(Serialize, Deserialize, FieldWitnesses)]
+    #[allow(dead_code)]
+    struct Session {
+        pub token: String,
+        #[serde(skip)]
+        pub cache: String,
+        #[serde(skip_serializing)]
+        pub password: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub device: Option<String>,
+        #[serde(skip)]
+        #[tnuctipun(skip = false)]
+        pub local_state: String,
+    }
+
+    assert_eq!(Session::field_names(), &["token", "device", "local_state"]);
+}
+
+#[test]
+fn test_derive_fields_const() {
+    use std::any::TypeId;
+
+    #[derive(FieldWitnesses)]
+    #[tnuctipun(field_naming = "camelCase")]
+    #[allow(dead_code)]
+    struct Account {
+        pub user_name: String,
+        #[tnuctipun(rename = "mail")]
+        pub email_address: String,
+        #[tnuctipun(skip)]
+        pub cache: String,
+    }
+
+    assert_eq!(
+        Account::FIELDS,
+        &[
+            ("userName", TypeId::of::<account_fields::UserName>()),
+            ("mail", TypeId::of::<account_fields::EmailAddress>()),
+        ]
+    );
+}
+
+#[test]
+fn test_derive_field_marker_const() {
+    #[derive(FieldWitnesses)]
+    #[tnuctipun(field_naming = "camelCase")]
+    #[allow(dead_code)]
+    struct Account {
+        pub user_name: String,
+        #[tnuctipun(rename = "mail")]
+        pub email_address: String,
+    }
+
+    const PROJECTED: [&str; 2] = [
+        account_fields::UserName::FIELD,
+        account_fields::EmailAddress::FIELD,
+    ];
+
+    assert_eq!(PROJECTED, ["userName", "mail"]);
+    assert_eq!(
+        account_fields::UserName::FIELD,
+        account_fields::UserName::field_name()
+    );
+}
+
+#[test]
+fn test_derive_field_bson_type() {
+    #[derive(FieldWitnesses)]
+    #[allow(dead_code)]
+    struct Profile {
+        pub name: String,
+        pub tags: Vec<String>,
+        pub age: Option<i32>,
+        #[tnuctipun(bson_type = "date")]
+        pub birth_date: String,
+        pub settings: Settings,
+        pub created_at: bson::DateTime,
+        pub updated_at: chrono::DateTime<chrono::Utc>,
+        #[tnuctipun(bson_type = "date")]
+        pub synced_at: chrono::DateTime<chrono::Utc>,
+    }
+
+    #[allow(dead_code)]
+    struct Settings {
+        pub theme: String,
+    }
+
+    assert_eq!(
+        <Profile as HasField<profile_fields::Name>>::BSON_TYPE,
+        Some("string")
+    );
+    assert_eq!(
+        <Profile as HasField<profile_fields::Tags>>::BSON_TYPE,
+        Some("array")
+    );
+    assert_eq!(
+        <Profile as HasField<profile_fields::Age>>::BSON_TYPE,
+        Some("int")
+    );
+    assert_eq!(
+        <Profile as HasField<profile_fields::BirthDate>>::BSON_TYPE,
+        Some("date")
+    );
+    assert_eq!(
+        <Profile as HasField<profile_fields::Settings>>::BSON_TYPE,
+        None
+    );
+
+    // Only the bson::DateTime fields are inferred as dates, as chrono's are stored as strings
+    assert_eq!(
+        <Profile as HasField<profile_fields::CreatedAt>>::BSON_TYPE,
+        Some("date")
+    );
+    assert_eq!(
+        <Profile as HasField<profile_fields::UpdatedAt>>::BSON_TYPE,
+        None
+    );
+    assert_eq!(
+        <Profile as HasField<profile_fields::SyncedAt>>::BSON_TYPE,
+        Some("date")
+    );
+}
+
+#[test]
+fn test_derive_field_markers_are_hashable() {
+    use std::collections::HashSet;
+
+    let mut markers = HashSet::new();
+
+    markers.insert(deriveuser_fields::Name);
+    markers.insert(deriveuser_fields::Name);
+
+    assert_eq!(markers.len(), 1);
+    assert!(markers.contains(&deriveuser_fields::Name));
+    assert_eq!(deriveuser_fields::Age, deriveuser_fields::Age.clone());
+}
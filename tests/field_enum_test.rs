@@ -0,0 +1,29 @@
+use tnuctipun::{FieldName, FieldWitnesses};
+
+#[derive(Debug, Clone, FieldWitnesses)]
+#[tnuctipun(field_enum, field_naming = "camelCase")]
+#[allow(dead_code)]
+pub struct User {
+    pub name: String,
+    pub age: i32,
+    pub email_address: String,
+    #[tnuctipun(skip)]
+    pub cache: String,
+}
+
+#[test]
+fn test_field_enum_iterates_witnessed_fields() {
+    let names: Vec<&str> = UserField::ALL.iter().map(|f| f.as_str()).collect();
+
+    assert_eq!(names, vec!["name", "age", "emailAddress"]);
+}
+
+#[test]
+fn test_field_enum_matches_field_witnesses() {
+    assert_eq!(UserField::Name.as_str(), user_fields::Name::field_name());
+    assert_eq!(UserField::Age.as_str(), user_fields::Age::field_name());
+    assert_eq!(
+        UserField::EmailAddress.as_str(),
+        user_fields::EmailAddress::field_name()
+    );
+}
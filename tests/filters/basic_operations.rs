@@ -16,6 +16,135 @@ fn test_eq_filter() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_eq_filter_str() {
+    let result = empty::<Product>()
+        .eq::<product_fields::Name, _>("Laptop")
+        .and();
+
+    let expected = bson::doc! {
+        "name": "Laptop"
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_eq_filter_borrowed_str() {
+    let name = String::from("Laptop");
+
+    let result = empty::<Product>()
+        .eq::<product_fields::Name, _>(name.as_str())
+        .and();
+
+    let expected = bson::doc! {
+        "name": "Laptop"
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_eq_filter_string_reference() {
+    let name = String::from("Laptop");
+
+    let result = empty::<Product>()
+        .eq::<product_fields::Name, _>(&name)
+        .ne::<product_fields::Brand, _>(&name)
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "name": "Laptop" },
+            { "brand": { "$ne": "Laptop" } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+// `Cow<str>` values aren't supported as such (bson has no conversion for them),
+// so they're passed as `&str`
+#[test]
+fn test_eq_filter_cow_str_as_str() {
+    use std::borrow::Cow;
+
+    let borrowed: Cow<str> = Cow::Borrowed("Laptop");
+    let owned: Cow<str> = Cow::Owned("Laptop".to_string());
+
+    let borrowed_result = empty::<Product>()
+        .eq::<product_fields::Name, _>(&*borrowed)
+        .and();
+
+    let owned_result = empty::<Product>()
+        .eq::<product_fields::Name, _>(owned.as_ref())
+        .and();
+
+    let expected = bson::doc! {
+        "name": "Laptop"
+    };
+
+    assert_eq!(borrowed_result, expected);
+    assert_eq!(owned_result, expected);
+}
+
+#[test]
+fn test_array_contains_filter() {
+    let result = empty::<Product>()
+        .array_contains::<product_fields::Categories, _>("Electronics".to_string())
+        .and();
+
+    let expected = bson::doc! {
+        "categories": "Electronics"
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_array_eq_filter() {
+    let result = empty::<Product>()
+        .array_eq::<product_fields::Categories, _>(vec![
+            "Electronics".to_string(),
+            "Computers".to_string(),
+        ])
+        .and();
+
+    let expected = bson::doc! {
+        "categories": ["Electronics", "Computers"]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_elem_match_scalar_range_filter() {
+    let result = empty::<Student>()
+        .elem_match::<student_fields::Scores, _>(|elem| elem.gte(80).lt(90))
+        .and();
+
+    let expected = bson::doc! {
+        "scores": { "$elemMatch": { "$gte": 80, "$lt": 90 } }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_eq_on_array_field_keeps_value_shape() {
+    // `eq` with an element value means "contains", with an array value "equals exactly"
+    let contains = empty::<Product>()
+        .eq::<product_fields::Categories, _>("Electronics".to_string())
+        .and();
+
+    let exact = empty::<Product>()
+        .eq::<product_fields::Categories, _>(vec!["Electronics".to_string()])
+        .and();
+
+    assert_eq!(contains, bson::doc! { "categories": "Electronics" });
+    assert_eq!(exact, bson::doc! { "categories": ["Electronics"] });
+}
+
 #[test]
 fn test_gt_filter() {
     let result = empty::<Product>()
@@ -56,6 +185,47 @@ fn test_in_filter() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_in_filter_empty_values() {
+    let result = empty::<Product>()
+        .r#in::<product_fields::Categories, String>(vec![])
+        .and();
+
+    let expected = bson::doc! {
+        "categories": { "$in": [] }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_in_or_skip_filter() {
+    let categories = vec!["Electronics".to_string()];
+    let result = empty::<Product>()
+        .in_or_skip::<product_fields::Categories, _>(categories.clone())
+        .and();
+
+    let expected = bson::doc! {
+        "categories": { "$in": categories }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_in_or_skip_filter_empty_values() {
+    let result = empty::<Product>()
+        .eq::<product_fields::Name, _>("Laptop".to_string())
+        .in_or_skip::<product_fields::Categories, String>(vec![])
+        .and();
+
+    let expected = bson::doc! {
+        "name": "Laptop"
+    };
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_ne_filter() {
     let result = empty::<Product>()
@@ -80,6 +250,60 @@ fn test_gte_filter() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_string_range_filter() {
+    let result = empty::<Product>()
+        .gte::<product_fields::Name, _>("M".to_string())
+        .lt::<product_fields::Name, _>("N")
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "name": { "$gte": "M" } },
+            { "name": { "$lt": "N" } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_gt_filter_narrower_integers_on_i64_field() {
+    let result = empty::<Product>()
+        .gt::<product_fields::Sold, _>(5)
+        .gt::<product_fields::Sold, _>(5i32)
+        .gt::<product_fields::Sold, _>(5u32)
+        .gt::<product_fields::Sold, _>(5i64)
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "sold": { "$gt": 5 } },
+            { "sold": { "$gt": 5 } },
+            { "sold": { "$gt": 5 } },
+            { "sold": { "$gt": 5_i64 } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_gt_filter_u8_on_i64_field() {
+    // The bson crate has no conversion from u8, so the value is widened first
+    let count: u8 = 5;
+
+    let result = empty::<Product>()
+        .gt::<product_fields::Sold, _>(u32::from(count))
+        .and();
+
+    let expected = bson::doc! {
+        "sold": { "$gt": 5 }
+    };
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_lte_filter() {
     let result = empty::<Product>()
@@ -106,6 +330,79 @@ fn test_exists_filter() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_is_null_filter() {
+    let result = empty::<Customer>()
+        .is_null::<customer_fields::Nickname>()
+        .and();
+
+    let expected = bson::doc! {
+        "nickname": bson::Bson::Null
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_is_exactly_null_filter() {
+    let result = empty::<Customer>()
+        .is_exactly_null::<customer_fields::Nickname>()
+        .eq::<customer_fields::Active, _>(true)
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "nickname": { "$type": "null" } },
+            { "active": true }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_is_array_filter() {
+    let result = empty::<Product>()
+        .is_array::<product_fields::Categories>()
+        .and();
+
+    let expected = bson::doc! {
+        "categories": { "$exists": true, "$type": "array" }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_is_string_filter() {
+    let result = empty::<Customer>()
+        .is_string::<customer_fields::Nickname>()
+        .and();
+
+    let expected = bson::doc! {
+        "nickname": { "$exists": true, "$type": "string" }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_is_number_filter() {
+    let result = empty::<Product>()
+        .is_number::<product_fields::Price>()
+        .is_number::<product_fields::Stock>()
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "price": { "$exists": true, "$type": "number" } },
+            { "stock": { "$exists": true, "$type": "number" } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_nin_filter() {
     let excluded_categories = vec!["Discontinued".to_string(), "Out of Stock".to_string()];
@@ -120,6 +417,42 @@ fn test_nin_filter() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_nin_filter_empty_values() {
+    let result = empty::<Product>()
+        .nin::<product_fields::Categories, String>(vec![])
+        .and();
+
+    let expected = bson::doc! {
+        "categories": { "$nin": [] }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_nin_or_skip_filter() {
+    let excluded_categories = vec!["Discontinued".to_string()];
+    let result = empty::<Product>()
+        .nin_or_skip::<product_fields::Categories, _>(excluded_categories.clone())
+        .and();
+
+    let expected = bson::doc! {
+        "categories": { "$nin": excluded_categories }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_nin_or_skip_filter_empty_values() {
+    let result = empty::<Product>()
+        .nin_or_skip::<product_fields::Categories, String>(vec![])
+        .and();
+
+    assert_eq!(result, bson::doc! {});
+}
+
 #[test]
 fn test_untyped_filter_regex() {
     let regex_condition = bson::doc! {
@@ -289,3 +622,466 @@ fn test_untyped_filter_nested_conditions() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_op_filter_custom_operator() {
+    let result = empty::<Product>()
+        .op::<product_fields::Stock>("$bitsAllClear", bson::Bson::Int32(35))
+        .and();
+
+    let expected = bson::doc! {
+        "stock": { "$bitsAllClear": 35 }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_eq_bson_filter() {
+    let result = empty::<Product>()
+        .eq_bson::<product_fields::Stock>(bson::Bson::Int32(10))
+        .and();
+
+    let expected = bson::doc! {
+        "stock": 10
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_comparison_bson_filters() {
+    let result = empty::<Product>()
+        .gte_bson::<product_fields::Stock>(bson::Bson::Int32(1))
+        .lt_bson::<product_fields::Stock>(bson::Bson::Int32(100))
+        .ne_bson::<product_fields::Stock>(bson::Bson::Int32(50))
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "stock": { "$gte": 1 } },
+            { "stock": { "$lt": 100 } },
+            { "stock": { "$ne": 50 } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_eq_dynamic_filter() {
+    let fields = ["brand", "metadata.origin"];
+
+    let mut builder = empty::<Product>();
+
+    for field in fields {
+        builder.eq_dynamic(field, bson::Bson::String("ACME".to_string()));
+    }
+
+    let expected = bson::doc! {
+        "$and": [
+            { "brand": "ACME" },
+            { "metadata.origin": "ACME" }
+        ]
+    };
+
+    assert_eq!(builder.and(), expected);
+}
+
+#[test]
+fn test_expr_eq_var_filter() {
+    let result = empty::<Product>()
+        .expr_eq_var::<product_fields::Brand>("brand_name")
+        .gt::<product_fields::Stock, _>(0)
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "$expr": { "$eq": ["$brand", "$$brand_name"] } },
+            { "stock": { "$gt": 0 } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_between_dates_filter() {
+    use chrono::{TimeZone, Utc};
+
+    let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 1, 31, 23, 59, 59).unwrap();
+
+    let result = empty::<Event>()
+        .between_dates::<event_fields::CreatedAt>(from, to)
+        .and();
+
+    let expected = bson::doc! {
+        "created_at": {
+            "$gte": bson::DateTime::from_millis(1_704_067_200_000),
+            "$lte": bson::DateTime::from_millis(1_706_745_599_000)
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_between_dates_filter_on_bson_date_field() {
+    use chrono::{TimeZone, Utc};
+
+    let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 1, 31, 23, 59, 59).unwrap();
+
+    let result = empty::<Event>()
+        .between_dates::<event_fields::RecordedAt>(from, to)
+        .and();
+
+    let expected = bson::doc! {
+        "recorded_at": {
+            "$gte": bson::DateTime::from_millis(1_704_067_200_000),
+            "$lte": bson::DateTime::from_millis(1_706_745_599_000)
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_starts_with_filter() {
+    let result = empty::<Product>()
+        .starts_with::<product_fields::Name>("Laptop")
+        .and();
+
+    let expected = bson::doc! {
+        "name": { "$regex": "^Laptop" }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_starts_with_filter_escapes_metacharacters() {
+    let result = empty::<Product>()
+        .starts_with::<product_fields::Name>("C++ (v1.2)")
+        .and();
+
+    let expected = bson::doc! {
+        "name": { "$regex": r"^C\+\+ \(v1\.2\)" }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_starts_with_filter_optional_field() {
+    let result = empty::<Customer>()
+        .starts_with::<customer_fields::Nickname>("Jo")
+        .and();
+
+    let expected = bson::doc! {
+        "nickname": { "$regex": "^Jo" }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_eq_serialized_enum() {
+    let result = empty::<Ticket>()
+        .eq_serialized::<ticket_fields::Status, _>(&TicketStatus::Closed)
+        .unwrap()
+        .and();
+
+    let expected = bson::doc! {
+        "status": "closed"
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_eq_embedded_value_serializes_struct_field() {
+    let address = Address {
+        street: "1 Main St".to_string(),
+        city: "Portland".to_string(),
+        zip_code: "97201".to_string(),
+        country: "US".to_string(),
+    };
+
+    let result = empty::<User>()
+        .eq_embedded_value::<user_fields::HomeAddress>(&address)
+        .unwrap()
+        .and();
+
+    let expected = bson::doc! {
+        "home_address": {
+            "$eq": {
+                "street": "1 Main St",
+                "city": "Portland",
+                "zip_code": "97201",
+                "country": "US"
+            }
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_eq_embedded_document() {
+    let result = empty::<User>()
+        .eq_embedded::<user_fields::Contact>(bson::doc! {
+            "email": "john@example.com",
+            "phone": "555-0100"
+        })
+        .gte::<user_fields::Age, _>(18)
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            {
+                "contact": {
+                    "$eq": {
+                        "email": "john@example.com",
+                        "phone": "555-0100"
+                    }
+                }
+            },
+            { "age": { "$gte": 18 } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_eq_filter_optional_numeric_field() {
+    let result = empty::<Customer>().eq::<customer_fields::Age, _>(30).and();
+
+    let expected = bson::doc! {
+        "age": 30
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_eq_filter_optional_numeric_field_none() {
+    let result = empty::<Customer>()
+        .eq::<customer_fields::Age, _>(None::<i32>)
+        .and();
+
+    let expected = bson::doc! {
+        "age": null
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_on_field_query() {
+    let result = empty::<Product>()
+        .on::<product_fields::Name>()
+        .eq("x")
+        .on::<product_fields::Price>()
+        .lt(100.0)
+        .on::<product_fields::Brand>()
+        .r#in(vec!["Apple", "Sony"])
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "name": "x" },
+            { "price": { "$lt": 100.0 } },
+            { "brand": { "$in": ["Apple", "Sony"] } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_eq_filter_bool_field() {
+    let result = empty::<Customer>()
+        .eq::<customer_fields::Active, _>(true)
+        .and();
+
+    let expected = bson::doc! {
+        "active": true
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_ne_filter_bool_field() {
+    let result = empty::<Customer>()
+        .ne::<customer_fields::Active, _>(false)
+        .and();
+
+    let expected = bson::doc! {
+        "active": { "$ne": false }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_eq_binary_field() {
+    let checksum = bson::Binary {
+        subtype: bson::spec::BinarySubtype::Generic,
+        bytes: vec![0xde, 0xad, 0xbe, 0xef],
+    };
+
+    let filter = empty::<Attachment>()
+        .eq::<attachment_fields::Checksum, _>(checksum.clone())
+        .and();
+
+    assert_eq!(filter, bson::doc! { "checksum": checksum });
+}
+
+#[test]
+fn test_eq_binary_declared_bytes_field() {
+    let thumbnail = bson::Binary {
+        subtype: bson::spec::BinarySubtype::Generic,
+        bytes: vec![0x89, 0x50, 0x4e, 0x47],
+    };
+
+    let filter = empty::<Attachment>()
+        .eq::<attachment_fields::Thumbnail, _>(thumbnail.clone())
+        .and();
+
+    assert_eq!(filter, bson::doc! { "thumbnail": thumbnail });
+}
+
+#[test]
+fn test_missing_or_eq_filter() {
+    let filter = empty::<Customer>()
+        .eq::<customer_fields::Active, _>(true)
+        .missing_or_eq::<customer_fields::Nickname, _>("anonymous")
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "active": true },
+            {
+                "$or": [
+                    { "nickname": { "$exists": false } },
+                    { "nickname": "anonymous" }
+                ]
+            }
+        ]
+    };
+
+    assert_eq!(filter, expected);
+}
+
+#[test]
+fn test_in_serialized_embedded_documents() {
+    let addresses = [
+        Address {
+            street: "1 Main St".to_string(),
+            city: "Portland".to_string(),
+            zip_code: "97201".to_string(),
+            country: "USA".to_string(),
+        },
+        Address {
+            street: "2 Rue de Rivoli".to_string(),
+            city: "Paris".to_string(),
+            zip_code: "75001".to_string(),
+            country: "France".to_string(),
+        },
+    ];
+
+    let mut builder = empty::<User>();
+
+    let Ok(builder) = builder.in_serialized::<user_fields::HomeAddress, _>(&addresses) else {
+        panic!("serializable addresses");
+    };
+
+    let expected = bson::doc! {
+        "home_address": {
+            "$in": [
+                {
+                    "street": "1 Main St",
+                    "city": "Portland",
+                    "zip_code": "97201",
+                    "country": "USA"
+                },
+                {
+                    "street": "2 Rue de Rivoli",
+                    "city": "Paris",
+                    "zip_code": "75001",
+                    "country": "France"
+                }
+            ]
+        }
+    };
+
+    assert_eq!(builder.and(), expected);
+}
+
+type LineCondition = Box<dyn FnOnce(&mut tnuctipun::filters::FilterBuilder<OrderLine>)>;
+
+#[test]
+fn test_all_elem_match_filter() {
+    let conditions: Vec<LineCondition> = vec![
+        Box::new(|line| {
+            line.eq::<orderline_fields::Sku, _>("pen".to_string())
+                .gte::<orderline_fields::Quantity, _>(10);
+        }),
+        Box::new(|_| {}),
+        Box::new(|line| {
+            line.eq::<orderline_fields::Sku, _>("ink".to_string());
+        }),
+    ];
+
+    let filter = empty::<Order>()
+        .all_elem_match::<order_fields::Lines, OrderLine, _>(conditions)
+        .and();
+
+    let expected = bson::doc! {
+        "lines": {
+            "$all": [
+                { "$elemMatch": { "sku": "pen", "quantity": { "$gte": 10 } } },
+                { "$elemMatch": { "sku": "ink" } }
+            ]
+        }
+    };
+
+    assert_eq!(filter, expected);
+}
+
+#[test]
+fn test_eq_any_delegates_to_in() {
+    let brands = vec!["Apple".to_string(), "Samsung".to_string()];
+
+    let result = empty::<Product>()
+        .eq_any::<product_fields::Brand, _>(brands.clone())
+        .and();
+
+    let expected = empty::<Product>()
+        .r#in::<product_fields::Brand, _>(brands)
+        .and();
+
+    assert_eq!(result, expected);
+    assert_eq!(
+        result,
+        bson::doc! { "brand": { "$in": ["Apple", "Samsung"] } }
+    );
+}
+
+#[test]
+fn test_ne_all_delegates_to_nin() {
+    let result = empty::<Product>()
+        .ne_all::<product_fields::Stock, _>(vec![0, 1])
+        .and();
+
+    let expected = empty::<Product>()
+        .nin::<product_fields::Stock, _>(vec![0, 1])
+        .and();
+
+    assert_eq!(result, expected);
+    assert_eq!(result, bson::doc! { "stock": { "$nin": [0, 1] } });
+}
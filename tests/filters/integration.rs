@@ -54,3 +54,113 @@ fn test_product_availability_filter() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_builder_is_send_and_sync_whatever_the_type() {
+    // Raw pointers are neither `Send` nor `Sync`
+    struct NotSendStruct {
+        _pointer: *const u8,
+    }
+
+    fn assert_send_sync<B: Send + Sync>() {}
+
+    assert_send_sync::<tnuctipun::filters::FilterBuilder<NotSendStruct>>();
+}
+
+#[test]
+fn test_frozen_filter_equals_and() {
+    let mut builder = empty::<Product>();
+
+    builder
+        .eq::<product_fields::Brand, _>("Acme".to_string())
+        .gt::<product_fields::Price, _>(100.0);
+
+    let frozen = builder.freeze();
+    let shared = frozen.clone();
+
+    assert_eq!(frozen.document(), &builder.and());
+    assert_eq!(shared, frozen);
+
+    let document: bson::Document = shared.into();
+
+    assert_eq!(document, builder.and());
+}
+
+#[test]
+fn test_frozen_filter_into_mongo_filter() {
+    use tnuctipun::filters::IntoMongoFilter;
+
+    let frozen = empty::<Product>()
+        .eq::<product_fields::Name, _>("Laptop".to_string())
+        .freeze();
+
+    let expected = bson::doc! { "name": "Laptop" };
+
+    assert_eq!((&frozen).into_document(), expected);
+    assert_eq!(frozen.into_document(), expected);
+}
+
+#[test]
+fn test_shape_ignores_literal_values() {
+    let first = empty::<Product>()
+        .eq::<product_fields::Brand, _>("ACME".to_string())
+        .gt::<product_fields::Price, _>(100.0)
+        .r#in::<product_fields::Stock, _>(vec![1, 2])
+        .shape();
+
+    let second = empty::<Product>()
+        .eq::<product_fields::Brand, _>("Globex".to_string())
+        .gt::<product_fields::Price, _>(2500.5)
+        .r#in::<product_fields::Stock, _>(vec![10, 20, 30])
+        .shape();
+
+    assert_eq!(first, second);
+
+    assert_eq!(
+        first,
+        "{ $and: [{ brand: ? }, { price: { $gt: ? } }, { stock: { $in: ? } }] }"
+    );
+}
+
+#[test]
+fn test_shape_differs_by_operator() {
+    let gt = empty::<Product>()
+        .gt::<product_fields::Price, _>(100.0)
+        .shape();
+
+    let lt = empty::<Product>()
+        .lt::<product_fields::Price, _>(100.0)
+        .shape();
+
+    assert_ne!(gt, lt);
+
+    assert_eq!(empty::<Product>().shape(), "{}");
+}
+
+#[test]
+fn test_and_opt_empty_builder() {
+    let builder = empty::<Product>();
+
+    assert_eq!(builder.and_opt(), None);
+}
+
+#[test]
+fn test_and_opt_non_empty_builder() {
+    let mut builder = empty::<Product>();
+
+    builder
+        .eq::<product_fields::Brand, _>("ACME".to_string())
+        .gt::<product_fields::Stock, _>(0);
+
+    assert_eq!(builder.and_opt(), Some(builder.and()));
+
+    assert_eq!(
+        builder.and_opt(),
+        Some(bson::doc! {
+            "$and": [
+                { "brand": "ACME" },
+                { "stock": { "$gt": 0 } }
+            ]
+        })
+    );
+}
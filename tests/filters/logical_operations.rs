@@ -1,7 +1,7 @@
 //! Tests for logical operations (and, or, not)
 
 use super::test_fixtures::*;
-use tnuctipun::filters::empty;
+use tnuctipun::filters::{EmptyBranchError, FilterBuilder, and_all, empty, or_all};
 
 #[test]
 fn test_and_function_empty_builder() {
@@ -84,6 +84,95 @@ fn test_not_filter_basic() {
     assert_eq!(filter, expected);
 }
 
+#[test]
+fn test_not_filter_chained_operators_single_range() {
+    let filter = empty::<Product>()
+        .not::<product_fields::Stock, _>(|op| op.gte(1).lte(10))
+        .and();
+
+    let expected = bson::doc! {
+        "stock": {
+            "$not": {
+                "$gte": 1,
+                "$lte": 10
+            }
+        }
+    };
+
+    assert_eq!(filter, expected);
+}
+
+#[test]
+fn test_not_filter_regex() {
+    let filter = empty::<Product>()
+        .not::<product_fields::Name, _>(|op| op.regex("^temp", Some("i")))
+        .and();
+
+    let expected = bson::doc! {
+        "name": {
+            "$not": {
+                "$regex": "^temp",
+                "$options": "i"
+            }
+        }
+    };
+
+    assert_eq!(filter, expected);
+}
+
+#[test]
+fn test_not_filter_regex_without_options() {
+    let filter = empty::<Product>()
+        .not::<product_fields::Name, _>(|op| op.regex("^temp", Some("")))
+        .and();
+
+    let expected = bson::doc! {
+        "name": {
+            "$not": {
+                "$regex": "^temp"
+            }
+        }
+    };
+
+    assert_eq!(filter, expected);
+}
+
+#[test]
+fn test_not_filter_elem_match_no_element_matches() {
+    // No score above 90
+    let filter = empty::<Student>()
+        .not::<student_fields::Scores, _>(|op| op.elem_match(|elem| elem.gt(90)))
+        .and();
+
+    let expected = bson::doc! {
+        "scores": {
+            "$not": {
+                "$elemMatch": { "$gt": 90 }
+            }
+        }
+    };
+
+    assert_eq!(filter, expected);
+}
+
+#[test]
+fn test_not_op_filter_mod() {
+    // Stock not being a multiple of 5
+    let filter = empty::<Product>()
+        .not_op::<product_fields::Stock>("$mod", bson::bson!([5, 0]))
+        .and();
+
+    let expected = bson::doc! {
+        "stock": {
+            "$not": {
+                "$mod": [5, 0]
+            }
+        }
+    };
+
+    assert_eq!(filter, expected);
+}
+
 #[test]
 fn test_not_filter_with_and_combination() {
     let filter = empty::<Product>()
@@ -133,6 +222,39 @@ fn test_or_standalone_operation() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_or_values_three_values() {
+    let result = empty::<Product>()
+        .or_values::<product_fields::Brand, _>(vec!["Apple", "Samsung", "Sony"])
+        .gt::<product_fields::Price, _>(100.0)
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            {
+                "$or": [
+                    { "brand": "Apple" },
+                    { "brand": "Samsung" },
+                    { "brand": "Sony" }
+                ]
+            },
+            { "price": { "$gt": 100.0 } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_or_values_without_value() {
+    let result = empty::<Product>()
+        .or_values::<product_fields::Brand, &str>(vec![])
+        .gt::<product_fields::Price, _>(100.0)
+        .and();
+
+    assert_eq!(result, bson::doc! { "price": { "$gt": 100.0 } });
+}
+
 #[test]
 fn test_multiple_or_operations() {
     // Test multiple OR operations combined
@@ -163,3 +285,423 @@ fn test_multiple_or_operations() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_and_explicit_without_clause() {
+    let filter = empty::<Product>().and_explicit();
+
+    let expected = bson::doc! {
+        "$and": []
+    };
+
+    assert_eq!(filter, expected);
+}
+
+#[test]
+fn test_and_explicit_single_clause() {
+    let filter = empty::<Product>()
+        .eq::<product_fields::Name, _>("Laptop".to_string())
+        .and_explicit();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "name": "Laptop" }
+        ]
+    };
+
+    assert_eq!(filter, expected);
+}
+
+#[test]
+fn test_and_explicit_two_clauses() {
+    let filter = empty::<Product>()
+        .eq::<product_fields::Name, _>("Laptop".to_string())
+        .gt::<product_fields::Price, _>(500.0)
+        .and_explicit();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "name": "Laptop" },
+            { "price": { "$gt": 500.0 } }
+        ]
+    };
+
+    assert_eq!(filter, expected);
+}
+
+#[test]
+fn test_and_flat_distinct_fields() {
+    let filter = empty::<Product>()
+        .eq::<product_fields::Name, _>("Laptop".to_string())
+        .gt::<product_fields::Price, _>(500.0)
+        .and_flat();
+
+    let expected = bson::doc! {
+        "name": "Laptop",
+        "price": { "$gt": 500.0 }
+    };
+
+    assert_eq!(filter, expected);
+}
+
+#[test]
+fn test_and_flat_colliding_fields() {
+    let filter = empty::<Product>()
+        .eq::<product_fields::Name, _>("Laptop".to_string())
+        .gte::<product_fields::Price, _>(500.0)
+        .lt::<product_fields::Price, _>(1000.0)
+        .and_flat();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "name": "Laptop" },
+            { "price": { "$gte": 500.0 } },
+            { "price": { "$lt": 1000.0 } }
+        ]
+    };
+
+    assert_eq!(filter, expected);
+}
+
+#[test]
+fn test_and_flat_colliding_field_condition() {
+    let mut builder = empty::<Product>();
+
+    builder
+        .field::<product_fields::Price>()
+        .gte(500.0)
+        .lt(1000.0)
+        .apply();
+
+    let filter = builder.eq::<product_fields::Price, _>(750.0).and_flat();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "price": { "$gte": 500.0, "$lt": 1000.0 } },
+            { "price": 750.0 }
+        ]
+    };
+
+    assert_eq!(filter, expected);
+}
+
+#[test]
+fn test_and_flat_without_clause() {
+    let filter = empty::<Product>().and_flat();
+
+    assert_eq!(filter, bson::doc! {});
+}
+
+#[test]
+fn test_raw_and_with_typed_clauses() {
+    let result = empty::<Product>()
+        .eq::<product_fields::Brand, _>("Apple")
+        .raw_and(vec![
+            bson::doc! { "legacy.active": true },
+            bson::doc! { "legacy.score": { "$gte": 3 } },
+        ])
+        .lt::<product_fields::Price, _>(100.0)
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "brand": "Apple" },
+            { "legacy.active": true },
+            { "legacy.score": { "$gte": 3 } },
+            { "price": { "$lt": 100.0 } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_raw_or_with_typed_clauses() {
+    let result = empty::<Product>()
+        .raw_or(vec![
+            bson::doc! { "legacy_category": "phones" },
+            bson::doc! { "legacy_category": "tablets" },
+        ])
+        .gt::<product_fields::Stock, _>(0)
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            {
+                "$or": [
+                    { "legacy_category": "phones" },
+                    { "legacy_category": "tablets" }
+                ]
+            },
+            { "stock": { "$gt": 0 } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_raw_or_without_clause() {
+    let result = empty::<Product>().raw_or(vec![]).and();
+
+    assert_eq!(result, bson::doc! {});
+}
+
+type Branch = Box<dyn FnOnce(&mut FilterBuilder<Product>)>;
+
+#[test]
+fn test_any_of_multi_field_branches() {
+    let branches: Vec<Branch> = vec![
+        Box::new(|f| {
+            f.eq::<product_fields::Brand, _>("Apple")
+                .gt::<product_fields::Price, _>(1000.0);
+        }),
+        Box::new(|f| {
+            f.eq::<product_fields::Brand, _>("Samsung")
+                .lt::<product_fields::Stock, _>(10);
+        }),
+        Box::new(|_| {}),
+    ];
+
+    let result = empty::<Product>().any_of(branches).and();
+
+    let expected = bson::doc! {
+        "$or": [
+            { "$and": [{ "brand": "Apple" }, { "price": { "$gt": 1000.0 } }] },
+            { "$and": [{ "brand": "Samsung" }, { "stock": { "$lt": 10 } }] }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+type MutBranch = Box<dyn FnMut(&mut FilterBuilder<Product>)>;
+
+#[test]
+fn test_or_each_heterogeneous_branches() {
+    let branches: [MutBranch; 3] = [
+        Box::new(|f| {
+            f.eq::<product_fields::Brand, _>("Apple");
+        }),
+        Box::new(|f| {
+            f.lt::<product_fields::Price, _>(100.0);
+        }),
+        Box::new(|f| {
+            f.r#in::<product_fields::Stock, _>(vec![1, 2, 3]);
+        }),
+    ];
+
+    let result = empty::<Product>().or_each(branches).and();
+
+    let expected = bson::doc! {
+        "$or": [
+            { "brand": "Apple" },
+            { "price": { "$lt": 100.0 } },
+            { "stock": { "$in": [1, 2, 3] } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_any_of_without_branch() {
+    let result = empty::<Product>().any_of(Vec::<Branch>::new()).and();
+
+    assert_eq!(result, bson::doc! {});
+}
+
+#[test]
+fn test_any_of_skips_empty_branch() {
+    let branches: Vec<Branch> = vec![
+        Box::new(|_| {}),
+        Box::new(|f| {
+            f.eq::<product_fields::Brand, _>("Apple");
+        }),
+    ];
+
+    let result = empty::<Product>().any_of(branches).and();
+
+    assert_eq!(result, bson::doc! { "$or": [{ "brand": "Apple" }] });
+}
+
+#[test]
+fn test_or_without_clause_for_all_values() {
+    let result = empty::<Product>()
+        .or::<product_fields::Brand, _, _>(vec!["Apple", "Samsung"], |f, _| f)
+        .and();
+
+    assert_eq!(result, bson::doc! {});
+}
+
+#[test]
+fn test_try_any_of_branches() {
+    let branches: Vec<Branch> = vec![
+        Box::new(|f| {
+            f.eq::<product_fields::Brand, _>("Apple");
+        }),
+        Box::new(|f| {
+            f.lt::<product_fields::Price, _>(100.0);
+        }),
+    ];
+
+    let mut builder = empty::<Product>();
+    let Ok(builder) = builder.try_any_of(branches) else {
+        panic!("all the branches have a clause");
+    };
+
+    let expected = bson::doc! {
+        "$or": [
+            { "brand": "Apple" },
+            { "price": { "$lt": 100.0 } }
+        ]
+    };
+
+    assert_eq!(builder.and(), expected);
+}
+
+#[test]
+fn test_try_any_of_empty_branch() {
+    let branches: Vec<Branch> = vec![
+        Box::new(|f| {
+            f.eq::<product_fields::Brand, _>("Apple");
+        }),
+        Box::new(|_| {}),
+    ];
+
+    let mut builder = empty::<Product>();
+    let Err(error) = builder.try_any_of(branches) else {
+        panic!("the second branch is empty");
+    };
+
+    assert_eq!(error, EmptyBranchError::EmptyBranch(1));
+    assert_eq!(builder.and(), bson::doc! {});
+
+    let Err(error) = builder.try_any_of(Vec::<Branch>::new()) else {
+        panic!("there is no branch");
+    };
+
+    assert_eq!(error, EmptyBranchError::NoBranch);
+    assert_eq!(error.to_string(), "No branch: the $or has no branch at all");
+}
+
+#[test]
+fn test_all_of_multi_field_branches() {
+    let branches: Vec<Branch> = vec![
+        Box::new(|f| {
+            f.gte::<product_fields::Price, _>(100.0)
+                .lte::<product_fields::Price, _>(500.0);
+        }),
+        Box::new(|f| {
+            f.eq::<product_fields::Name, _>("Laptop");
+        }),
+    ];
+
+    let result = empty::<Product>()
+        .eq::<product_fields::Brand, _>("Apple")
+        .all_of(branches)
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "brand": "Apple" },
+            {
+                "$and": [
+                    { "$and": [{ "price": { "$gte": 100.0 } }, { "price": { "$lte": 500.0 } }] },
+                    { "name": "Laptop" }
+                ]
+            }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_all_of_mapped_branches() {
+    let brands = ["Apple", "Samsung"];
+
+    let result = empty::<Product>()
+        .all_of(brands.map(|brand| {
+            move |f: &mut FilterBuilder<Product>| {
+                f.ne::<product_fields::Brand, _>(brand);
+            }
+        }))
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "brand": { "$ne": "Apple" } },
+            { "brand": { "$ne": "Samsung" } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_any_of_empty_branches() {
+    let branches: Vec<Branch> = vec![Box::new(|_| {})];
+
+    let result = empty::<Product>().any_of(branches).and();
+
+    assert_eq!(result, bson::doc! {});
+}
+
+fn three_builders() -> Vec<tnuctipun::filters::FilterBuilder<Product>> {
+    let mut by_name = empty::<Product>();
+    by_name.eq::<product_fields::Name, _>("Laptop".to_string());
+
+    let mut by_price = empty::<Product>();
+    by_price
+        .gte::<product_fields::Price, _>(500.0)
+        .lte::<product_fields::Price, _>(1500.0);
+
+    let mut by_stock = empty::<Product>();
+    by_stock.gt::<product_fields::Stock, _>(0);
+
+    vec![by_name, by_price, by_stock]
+}
+
+#[test]
+fn test_and_all_three_builders() {
+    let filter = and_all(three_builders());
+
+    let expected = bson::doc! {
+        "$and": [
+            { "name": "Laptop" },
+            { "price": { "$gte": 500.0 } },
+            { "price": { "$lte": 1500.0 } },
+            { "stock": { "$gt": 0 } }
+        ]
+    };
+
+    assert_eq!(filter, expected);
+}
+
+#[test]
+fn test_or_all_three_builders() {
+    let filter = or_all(three_builders());
+
+    let expected = bson::doc! {
+        "$or": [
+            { "name": "Laptop" },
+            { "$and": [
+                { "price": { "$gte": 500.0 } },
+                { "price": { "$lte": 1500.0 } }
+            ]},
+            { "stock": { "$gt": 0 } }
+        ]
+    };
+
+    assert_eq!(filter, expected);
+}
+
+#[test]
+fn test_or_all_without_clause() {
+    assert_eq!(or_all::<Product>(vec![]), bson::doc! {});
+    assert_eq!(
+        or_all(vec![empty::<Product>(), empty::<Product>()]),
+        bson::doc! {}
+    );
+}
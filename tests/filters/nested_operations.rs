@@ -1,7 +1,9 @@
 //! Tests for nested field operations using with_lookup
 
 use super::test_fixtures::*;
+use serde::{Deserialize, Serialize};
 use tnuctipun::filters::empty;
+use tnuctipun::{FieldWitnesses, MongoComparable};
 
 #[test]
 fn test_simple_nested_lookup() {
@@ -17,3 +19,220 @@ fn test_simple_nested_lookup() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_on_field_query_in_nested_lookup() {
+    let result = empty::<User>()
+        .with_lookup::<user_fields::HomeAddress, _, address_fields::City, Address, _>(
+            |path| path.field::<address_fields::City>(),
+            |nested| nested.on::<address_fields::City>().ne("Portland"),
+        )
+        .and();
+
+    let expected = bson::doc! { "home_address.city": { "$ne": "Portland" } };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_field_condition_in_nested_lookup() {
+    let result = empty::<User>()
+        .with_lookup::<user_fields::HomeAddress, _, address_fields::City, Address, _>(
+            |path| path.field::<address_fields::City>(),
+            |nested| {
+                nested
+                    .field::<address_fields::City>()
+                    .ne("Portland")
+                    .exists(true)
+                    .apply()
+            },
+        )
+        .and();
+
+    let expected = bson::doc! {
+        "home_address.city": { "$ne": "Portland", "$exists": true }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_exists_in_nested_lookup() {
+    let result = empty::<User>()
+        .with_lookup::<user_fields::HomeAddress, _, address_fields::ZipCode, Address, _>(
+            |path| path.field::<address_fields::ZipCode>(),
+            |nested| nested.exists::<address_fields::ZipCode>(true),
+        )
+        .and();
+
+    let expected = bson::doc! { "home_address.zip_code": { "$exists": true } };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_exists_in_deeper_nested_lookup() {
+    let result = empty::<Employee>()
+        .with_lookup::<employee_fields::Company, _, company_fields::Address, Company, _>(
+            |path| path.field::<company_fields::Address>(),
+            |company| {
+                company
+                    .exists::<company_fields::Name>(false)
+                    .with_lookup::<company_fields::Address, _, address_fields::ZipCode, Address, _>(
+                        |path| path.field::<address_fields::ZipCode>(),
+                        |address| address.exists::<address_fields::ZipCode>(true),
+                    )
+            },
+        )
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "company.name": { "$exists": false } },
+            { "company.address.zip_code": { "$exists": true } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+// Nested struct and its witnesses defined in a separate module
+mod geo {
+    use serde::{Deserialize, Serialize};
+    use tnuctipun::{FieldWitnesses, MongoComparable};
+
+    #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    #[tnuctipun(field_naming = "camelCase")]
+    pub struct Location {
+        pub country_code: String,
+        #[tnuctipun(rename = "lat")]
+        pub latitude: f64,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+pub struct Store {
+    pub name: String,
+    pub location: geo::Location,
+}
+
+#[test]
+fn test_nested_lookup_with_witnesses_from_other_module() {
+    // Test with_lookup using field witnesses from another module path
+    let result = empty::<Store>()
+        .with_lookup::<store_fields::Location, _, geo::location_fields::CountryCode, geo::Location, _>(
+            |path| path.field::<geo::location_fields::CountryCode>(),
+            |nested| nested.eq::<geo::location_fields::CountryCode, _>("FR".to_string()),
+        )
+        .with_lookup::<store_fields::Location, _, geo::location_fields::Latitude, geo::Location, _>(
+            |path| path.field::<geo::location_fields::Latitude>(),
+            |nested| nested.gt::<geo::location_fields::Latitude, _>(45.0),
+        )
+        .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "location.countryCode": "FR" },
+            { "location.lat": { "$gt": 45.0 } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_nested_lookup_flattened_vs_grouped() {
+    let flattened = empty::<User>()
+        .eq::<user_fields::Name, _>("John".to_string())
+        .with_lookup::<user_fields::HomeAddress, _, address_fields::City, Address, _>(
+            |path| path.field::<address_fields::City>(),
+            |nested| {
+                nested
+                    .eq::<address_fields::City, _>("Paris".to_string())
+                    .eq::<address_fields::ZipCode, _>("75001".to_string())
+            },
+        )
+        .and();
+
+    let grouped = empty::<User>()
+        .eq::<user_fields::Name, _>("John".to_string())
+        .with_lookup_grouped::<user_fields::HomeAddress, _, address_fields::City, Address, _>(
+            |path| path.field::<address_fields::City>(),
+            |nested| {
+                nested
+                    .eq::<address_fields::City, _>("Paris".to_string())
+                    .eq::<address_fields::ZipCode, _>("75001".to_string())
+            },
+        )
+        .and();
+
+    let expected_flattened = bson::doc! {
+        "$and": [
+            { "name": "John" },
+            { "home_address.city": "Paris" },
+            { "home_address.zip_code": "75001" }
+        ]
+    };
+
+    let expected_grouped = bson::doc! {
+        "$and": [
+            { "name": "John" },
+            {
+                "$and": [
+                    { "home_address.city": "Paris" },
+                    { "home_address.zip_code": "75001" }
+                ]
+            }
+        ]
+    };
+
+    assert_eq!(flattened, expected_flattened);
+    assert_eq!(grouped, expected_grouped);
+}
+
+#[test]
+fn test_nested_lookup_grouped_without_condition() {
+    let result = empty::<User>()
+        .eq::<user_fields::Name, _>("John".to_string())
+        .with_lookup_grouped::<user_fields::HomeAddress, _, address_fields::City, Address, _>(
+            |path| path.field::<address_fields::City>(),
+            |nested| nested,
+        )
+        .and();
+
+    assert_eq!(result, bson::doc! { "name": "John" });
+}
+
+#[test]
+fn test_expr_eq_var_in_nested_lookup() {
+    let result = empty::<User>()
+        .with_lookup::<user_fields::HomeAddress, _, address_fields::City, Address, _>(
+            |path| path.field::<address_fields::City>(),
+            |nested| nested.expr_eq_var::<address_fields::City>("$$city"),
+        )
+        .and();
+
+    let expected = bson::doc! {
+        "$expr": { "$eq": ["$home_address.city", "$$city"] }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_with_prefix_constructor() {
+    let result =
+        tnuctipun::filters::FilterBuilder::<Address>::with_prefix(vec!["work_address".to_string()])
+            .eq::<address_fields::City, _>("Portland".to_string())
+            .exists::<address_fields::ZipCode>(true)
+            .and();
+
+    let expected = bson::doc! {
+        "$and": [
+            { "work_address.city": "Portland" },
+            { "work_address.zip_code": { "$exists": true } }
+        ]
+    };
+
+    assert_eq!(result, expected);
+}
@@ -37,3 +37,21 @@ fn test_operation_builder_chaining() {
 
     assert_eq!(clauses, &expected);
 }
+
+#[test]
+fn test_field_condition_applied_as_single_clause() {
+    let result = empty::<Product>()
+        .field::<product_fields::Price>()
+        .gte(1.0)
+        .lt(10.0)
+        .apply()
+        .field::<product_fields::Stock>()
+        .apply()
+        .and();
+
+    let expected = bson::doc! {
+        "price": { "$gte": 1.0, "$lt": 10.0 }
+    };
+
+    assert_eq!(result, expected);
+}
@@ -10,6 +10,7 @@ pub struct Product {
     pub name: String,
     pub price: f64,
     pub stock: i32,
+    pub sold: i64,
     pub categories: Vec<String>,
     pub brand: String,
 }
@@ -53,3 +54,56 @@ pub struct Employee {
     pub name: String,
     pub company: Company,
 }
+
+#[derive(Debug, Clone, FieldWitnesses, MongoComparable)]
+pub struct Event {
+    pub name: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub recorded_at: bson::DateTime,
+}
+
+#[derive(Debug, Clone, FieldWitnesses, MongoComparable)]
+pub struct Customer {
+    pub name: String,
+    pub nickname: Option<String>,
+    pub age: Option<i32>,
+    pub active: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TicketStatus {
+    Open,
+    Closed,
+}
+
+#[derive(Debug, Clone, FieldWitnesses)]
+pub struct Ticket {
+    pub title: String,
+    pub status: TicketStatus,
+}
+
+#[derive(Debug, Clone, FieldWitnesses, MongoComparable)]
+pub struct Student {
+    pub name: String,
+    pub scores: Vec<i32>,
+}
+
+#[derive(Debug, Clone, FieldWitnesses, MongoComparable)]
+pub struct Attachment {
+    pub name: String,
+    pub checksum: bson::Binary,
+    #[tnuctipun(bson_type = "binData")]
+    pub thumbnail: Vec<u8>,
+}
+
+#[derive(Debug, Clone, FieldWitnesses, MongoComparable)]
+pub struct OrderLine {
+    pub sku: String,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Clone, FieldWitnesses)]
+pub struct Order {
+    pub lines: Vec<OrderLine>,
+}
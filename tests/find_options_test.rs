@@ -0,0 +1,43 @@
+#![cfg(feature = "mongodb")]
+
+use tnuctipun::FieldWitnesses;
+use tnuctipun::{projection, sort};
+
+#[derive(FieldWitnesses)]
+#[allow(dead_code)]
+pub struct User {
+    pub name: String,
+    pub email: String,
+    pub age: i32,
+}
+
+#[test]
+fn test_projection_into_find_options() {
+    let mut builder = projection::empty::<User>();
+
+    builder
+        .includes::<user_fields::Name>()
+        .excludes::<user_fields::Email>();
+
+    let options = builder.into_find_options();
+
+    assert_eq!(
+        options.projection,
+        Some(bson::doc! { "name": 1, "email": 0 })
+    );
+    assert_eq!(options.sort, None);
+}
+
+#[test]
+fn test_projection_into_find_options_with_sort() {
+    let mut builder = projection::empty::<User>();
+
+    builder.includes::<user_fields::Name>();
+
+    let mut options = builder.into_find_options();
+
+    options.sort = Some(sort::empty::<User>().desc::<user_fields::Age>().build());
+
+    assert_eq!(options.projection, Some(bson::doc! { "name": 1 }));
+    assert_eq!(options.sort, Some(bson::doc! { "age": -1 }));
+}
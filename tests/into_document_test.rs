@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use tnuctipun::{FieldWitnesses, IntoMongoFilter, IntoMongoUpdate, MongoComparable};
+use tnuctipun::{filters, updates};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+pub struct Account {
+    pub id: String,
+    pub name: String,
+    pub balance: i64,
+}
+
+// Generic repository-like function, abstracting over the builder kinds
+fn update_one(filter: impl IntoMongoFilter, update: impl IntoMongoUpdate) -> bson::Document {
+    bson::doc! {
+        "q": filter.into_document(),
+        "u": update.into_document()
+    }
+}
+
+#[test]
+fn test_generic_function_with_builder_chains() {
+    let result = update_one(
+        filters::empty::<Account>().eq::<account_fields::Id, _>("acc-1"),
+        updates::empty::<Account>().inc::<account_fields::Balance, _>(10i64),
+    );
+
+    let expected = bson::doc! {
+        "q": { "id": "acc-1" },
+        "u": { "$inc": { "balance": 10i64 } }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_generic_function_with_owned_builders() {
+    let mut filter = filters::empty::<Account>();
+
+    filter
+        .eq::<account_fields::Id, _>("acc-2")
+        .gt::<account_fields::Balance, _>(0i64);
+
+    let mut update = updates::empty::<Account>();
+
+    update.set::<account_fields::Name, _>("Savings");
+
+    let result = update_one(filter, update);
+
+    let expected = bson::doc! {
+        "q": {
+            "$and": [
+                { "id": "acc-2" },
+                { "balance": { "$gt": 0i64 } }
+            ]
+        },
+        "u": { "$set": { "name": "Savings" } }
+    };
+
+    assert_eq!(result, expected);
+}
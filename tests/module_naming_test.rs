@@ -0,0 +1,57 @@
+use tnuctipun::filters::empty;
+use tnuctipun::{FieldName, tnuctipun_module};
+
+#[tnuctipun_module(field_naming = "camelCase")]
+mod model {
+    use serde::{Deserialize, Serialize};
+    use tnuctipun::{FieldWitnesses, MongoComparable};
+
+    #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    pub struct Address {
+        pub zip_code: String,
+        pub city_name: String,
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    pub struct User {
+        pub user_name: String,
+        pub home_address: Address,
+    }
+
+    // Struct-level strategy takes priority over the module one
+    #[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+    #[tnuctipun(field_naming = "PascalCase")]
+    #[allow(dead_code)]
+    pub struct Legacy {
+        pub user_name: String,
+    }
+}
+
+use model::*;
+
+#[test]
+fn test_module_field_naming_applies_to_all_structs() {
+    assert_eq!(user_fields::UserName::field_name(), "userName");
+    assert_eq!(user_fields::HomeAddress::field_name(), "homeAddress");
+    assert_eq!(address_fields::ZipCode::field_name(), "zipCode");
+    assert_eq!(address_fields::CityName::field_name(), "cityName");
+}
+
+#[test]
+fn test_module_field_naming_struct_level_priority() {
+    assert_eq!(legacy_fields::UserName::field_name(), "UserName");
+}
+
+#[test]
+fn test_module_field_naming_nested_lookup() {
+    let result = empty::<User>()
+        .with_lookup::<user_fields::HomeAddress, _, address_fields::ZipCode, Address, _>(
+            |path| path.field::<address_fields::ZipCode>(),
+            |nested| nested.eq::<address_fields::ZipCode, _>("75001"),
+        )
+        .and();
+
+    let expected = bson::doc! { "homeAddress.zipCode": "75001" };
+
+    assert_eq!(result, expected);
+}
@@ -96,6 +96,40 @@ pub struct PrimitiveTypes {
 #[derive(Debug, Clone, FieldWitnesses, MongoComparable)]
 pub struct TemporalTypes {
     pub timestamp: DateTime<Utc>,
+    pub recorded_at: bson::DateTime,
+    pub archived_at: Option<bson::DateTime>,
+}
+
+#[derive(Debug, Clone, FieldWitnesses, MongoComparable)]
+pub struct IdentifiedTypes {
+    #[tnuctipun(rename = "_id")]
+    pub id: bson::oid::ObjectId,
+    pub parent_id: Option<bson::oid::ObjectId>,
+}
+
+#[derive(Debug, Clone, FieldWitnesses, MongoComparable)]
+pub struct DeclaredBsonTypes {
+    #[tnuctipun(bson_type = "date", rename = "createdAt")]
+    pub created_at: String,
+    #[tnuctipun(bson_type = "long")]
+    pub counter: Option<i32>,
+}
+
+#[derive(Debug, Clone, FieldWitnesses, MongoComparable)]
+pub struct EmbeddingTypes {
+    pub timestamps: TemporalTypes,
+    pub identifiers: Option<IdentifiedTypes>,
+}
+
+#[test]
+fn test_embedded_struct_types() {
+    // Embedded struct fields are comparable with their own type
+    static_assert_implements_mongo_comparable!(EmbeddingTypes, TemporalTypes, TemporalTypes);
+    static_assert_implements_mongo_comparable!(
+        EmbeddingTypes,
+        Option<IdentifiedTypes>,
+        IdentifiedTypes
+    );
 }
 
 #[test]
@@ -178,10 +212,96 @@ fn test_mongo_ordered_numeric_and_temporal_types() {
         i32
     );
 
+    // DateTime field is ordered with itself.
+    static_assert_implements_mongo_ordered!(
+        TemporalTypes,
+        <TemporalTypes as tnuctipun::field_witnesses::HasField<temporaltypes_fields::Timestamp>>::Value,
+        DateTime<Utc>
+    );
+
     // DateTime field is ordered and comparable with i64 timestamps.
     static_assert_implements_mongo_ordered!(
         TemporalTypes,
         <TemporalTypes as tnuctipun::field_witnesses::HasField<temporaltypes_fields::Timestamp>>::Value,
         i64
     );
+
+    // bson::DateTime field is ordered with itself, and with the chrono dates it's compared to.
+    static_assert_implements_mongo_ordered!(TemporalTypes, bson::DateTime, bson::DateTime);
+    static_assert_implements_mongo_ordered!(TemporalTypes, bson::DateTime, DateTime<Utc>);
+    static_assert_implements_mongo_comparable!(TemporalTypes, bson::DateTime, DateTime<Utc>);
+
+    // Optional bson::DateTime field is comparable with the chrono dates.
+    static_assert_implements_mongo_comparable!(
+        TemporalTypes,
+        Option<bson::DateTime>,
+        DateTime<Utc>
+    );
+}
+
+#[test]
+fn test_mongo_ordered_string_types() {
+    // String field is ordered with strings, for lexicographic range queries
+    static_assert_implements_mongo_ordered!(PrimitiveTypes, String, String);
+    static_assert_implements_mongo_ordered!(PrimitiveTypes, String, &'static str);
+    static_assert_implements_mongo_ordered!(PrimitiveTypes, String, &'static String);
+}
+
+#[test]
+fn test_mongo_ordered_collection_items() {
+    // Collection fields are ordered with their ordered items, for the element range queries
+    static_assert_implements_mongo_ordered!(CollectionProduct, Vec<String>, String);
+    static_assert_implements_mongo_ordered!(CollectionProduct, BTreeSet<i32>, i32);
+}
+
+#[test]
+fn test_object_id_types() {
+    // ObjectId fields are comparable with ObjectId values, including when optional
+    static_assert_implements_mongo_comparable!(
+        IdentifiedTypes,
+        bson::oid::ObjectId,
+        bson::oid::ObjectId
+    );
+
+    static_assert_implements_mongo_comparable!(
+        IdentifiedTypes,
+        Option<bson::oid::ObjectId>,
+        bson::oid::ObjectId
+    );
+
+    let object_id = bson::oid::ObjectId::new();
+    let filter = tnuctipun::filters::empty::<IdentifiedTypes>()
+        .eq::<identifiedtypes_fields::Id, _>(object_id)
+        .and();
+
+    assert_eq!(filter, bson::doc! { "_id": object_id });
+}
+
+#[test]
+fn test_declared_bson_types() {
+    // String field declared as a date: comparable and ordered with dates (and i64 timestamps)
+    static_assert_implements_mongo_comparable!(DeclaredBsonTypes, String, DateTime<Utc>);
+    static_assert_implements_mongo_comparable!(DeclaredBsonTypes, String, i64);
+    static_assert_implements_mongo_ordered!(DeclaredBsonTypes, String, DateTime<Utc>);
+    static_assert_implements_mongo_ordered!(DeclaredBsonTypes, String, String);
+
+    // Optional field declared as long: comparable with the (optional) declared type
+    static_assert_implements_mongo_comparable!(DeclaredBsonTypes, Option<i32>, i64);
+    static_assert_implements_mongo_comparable!(DeclaredBsonTypes, Option<i32>, Option<i64>);
+
+    // The other attributes are still applied
+    let from = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+    let to = DateTime::<Utc>::from_timestamp(1_800_000_000, 0).unwrap();
+    let filter = tnuctipun::filters::empty::<DeclaredBsonTypes>()
+        .between_dates::<declaredbsontypes_fields::CreatedAt>(from, to)
+        .and();
+
+    let expected = bson::doc! {
+        "createdAt": {
+            "$gte": bson::DateTime::from_millis(1_700_000_000_000),
+            "$lte": bson::DateTime::from_millis(1_800_000_000_000)
+        }
+    };
+
+    assert_eq!(filter, expected);
 }
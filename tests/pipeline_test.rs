@@ -0,0 +1,90 @@
+use tnuctipun::{FieldWitnesses, MongoComparable};
+use tnuctipun::{filters, pipeline, projection, sort};
+
+#[derive(FieldWitnesses, MongoComparable)]
+#[allow(dead_code)]
+pub struct Product {
+    pub name: String,
+    pub price: f64,
+    pub stock: i32,
+}
+
+#[test]
+fn test_match_stage() {
+    let stage = pipeline::match_stage(
+        filters::empty::<Product>()
+            .gt::<product_fields::Stock, _>(0)
+            .lt::<product_fields::Price, _>(100.0),
+    );
+
+    let expected = bson::doc! {
+        "$match": {
+            "$and": [
+                { "stock": { "$gt": 0 } },
+                { "price": { "$lt": 100.0 } }
+            ]
+        }
+    };
+
+    assert_eq!(stage, expected);
+}
+
+#[test]
+fn test_sort_stage() {
+    let stage = pipeline::sort_stage(
+        sort::empty::<Product>()
+            .desc::<product_fields::Price>()
+            .asc::<product_fields::Name>(),
+    );
+
+    let expected = bson::doc! {
+        "$sort": { "price": -1, "name": 1 }
+    };
+
+    assert_eq!(stage, expected);
+}
+
+#[test]
+fn test_project_stage() {
+    let stage = pipeline::project_stage(
+        projection::empty::<Product>()
+            .includes::<product_fields::Name>()
+            .includes::<product_fields::Price>(),
+    );
+
+    let expected = bson::doc! {
+        "$project": { "name": 1, "price": 1 }
+    };
+
+    assert_eq!(stage, expected);
+}
+
+#[test]
+fn test_limit_stage() {
+    assert_eq!(pipeline::limit_stage(10), bson::doc! { "$limit": 10_i64 });
+}
+
+#[test]
+fn test_skip_stage() {
+    assert_eq!(pipeline::skip_stage(20), bson::doc! { "$skip": 20_i64 });
+    assert_eq!(
+        pipeline::skip_stage(u64::MAX),
+        bson::doc! { "$skip": i64::MAX }
+    );
+}
+
+#[test]
+fn test_pipeline_assembly() {
+    let stages: Vec<bson::Document> = vec![
+        pipeline::match_stage(filters::empty::<Product>().gt::<product_fields::Stock, _>(0)),
+        pipeline::sort_stage(sort::empty::<Product>().asc::<product_fields::Price>()),
+        pipeline::limit_stage(5),
+    ];
+
+    let operators: Vec<&String> = stages
+        .iter()
+        .map(|stage| stage.keys().next().unwrap())
+        .collect();
+
+    assert_eq!(operators, vec!["$match", "$sort", "$limit"]);
+}
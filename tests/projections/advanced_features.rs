@@ -58,3 +58,45 @@ fn projection_single_field_operations() {
     assert_eq!(include_doc, expected_include);
     assert_eq!(exclude_doc, expected_exclude);
 }
+
+#[test]
+fn projection_text_score() {
+    let doc = empty::<Article>()
+        .text_score::<article_fields::Score>()
+        .build();
+
+    let expected = bson::doc! {
+        "score": { "$meta": "textScore" }
+    };
+
+    assert_eq!(doc, expected);
+}
+
+#[test]
+fn projection_with_meta_sort() {
+    let mut sort_builder = tnuctipun::sort::empty::<Article>();
+
+    let projection_doc = empty::<Article>()
+        .includes::<article_fields::Title>()
+        .with_meta_sort::<article_fields::Score>(&mut sort_builder)
+        .build();
+
+    let sort_doc = sort_builder.asc::<article_fields::Title>().build();
+
+    let expected_projection = bson::doc! {
+        "title": 1,
+        "score": { "$meta": "textScore" }
+    };
+
+    let expected_sort = bson::doc! {
+        "score": { "$meta": "textScore" },
+        "title": 1
+    };
+
+    assert_eq!(projection_doc, expected_projection);
+    assert_eq!(sort_doc, expected_sort);
+
+    let sort_keys: Vec<&String> = sort_doc.keys().collect();
+
+    assert_eq!(sort_keys, vec!["score", "title"]);
+}
@@ -1,7 +1,7 @@
 //! Tests for basic projection operations: includes, excludes, project
 
 use super::test_fixtures::*;
-use tnuctipun::projection::{ProjectionBuilder, empty};
+use tnuctipun::projection::{BasicProjectionBuilder, ProjectionBuilder, empty};
 
 #[test]
 fn projection_includes_generates_correct_paths() {
@@ -94,3 +94,92 @@ fn projection_duplicate_field_handling() {
 
     assert_eq!(doc, expected);
 }
+
+#[test]
+fn projection_from_field_names() {
+    // Test building a projection from runtime field names
+    let doc = BasicProjectionBuilder::<User>::from_field_names(&["name", "age"])
+        .expect("known fields")
+        .build();
+
+    let expected = bson::doc! {
+        "name": 1,
+        "age": 1
+    };
+
+    assert_eq!(doc, expected);
+}
+
+#[test]
+fn projection_from_field_names_unknown_field() {
+    // Test that an unknown runtime field name is rejected
+    let result = BasicProjectionBuilder::<User>::from_field_names(&["name", "password"]);
+
+    let error = result.err().expect("unknown field");
+
+    assert_eq!(error.name(), "password");
+    assert_eq!(error.to_string(), "Unknown field: 'password'");
+}
+
+#[test]
+fn projection_only_id() {
+    // Test that only_id overrides prior projections and is idempotent
+    let doc = empty::<User>()
+        .includes::<user_fields::Name>()
+        .excludes::<user_fields::Email>()
+        .only_id()
+        .only_id()
+        .build();
+
+    let expected = bson::doc! {
+        "_id": 1
+    };
+
+    assert_eq!(doc, expected);
+    assert_eq!(doc.len(), 1);
+}
+
+#[test]
+fn projection_include_if_prefix() {
+    // Test including only the witnessed fields whose name starts with a prefix
+    let doc = empty::<Contact>()
+        .include_if(|name| name.starts_with("phone_"))
+        .build();
+
+    let expected = bson::doc! {
+        "phone_home": 1,
+        "phone_mobile": 1
+    };
+
+    assert_eq!(doc, expected);
+}
+
+#[test]
+fn projection_exclude_only() {
+    // Test excluding some runtime field names, all the other fields being returned
+    let doc = empty::<User>()
+        .exclude_only(&["email", "age"])
+        .expect("known fields")
+        .build();
+
+    let expected = bson::doc! {
+        "email": 0,
+        "age": 0
+    };
+
+    assert_eq!(doc, expected);
+}
+
+#[test]
+fn projection_exclude_only_unknown_field() {
+    // Test that an unknown runtime field name is rejected, without excluding the others
+    let mut builder = empty::<User>();
+
+    let error = builder
+        .exclude_only(&["email", "password"])
+        .err()
+        .expect("unknown field");
+
+    assert_eq!(error.name(), "password");
+    assert_eq!(builder.build(), bson::doc! {});
+}
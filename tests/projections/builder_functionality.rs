@@ -79,3 +79,36 @@ fn projection_builder_field_override_patterns() {
 
     assert_eq!(doc, expected);
 }
+
+#[test]
+fn projection_builder_stable_key_order() {
+    let mut builder = empty::<User>();
+
+    builder
+        .includes::<user_fields::Email>()
+        .includes::<user_fields::Name>()
+        .excludes::<user_fields::Id>()
+        .includes::<user_fields::Age>()
+        .excludes::<user_fields::Name>(); // Overridden field keeps its first position
+
+    let expected_keys = vec!["email", "name", "id", "age"];
+
+    for _ in 0..3 {
+        let doc = builder.build();
+        let keys: Vec<&String> = doc.keys().collect();
+
+        assert_eq!(keys, expected_keys);
+    }
+}
+
+#[test]
+fn projection_builder_is_send_and_sync_whatever_the_type() {
+    // Raw pointers are neither `Send` nor `Sync`
+    struct NotSendStruct {
+        _pointer: *const u8,
+    }
+
+    fn assert_send_sync<B: Send + Sync>() {}
+
+    assert_send_sync::<tnuctipun::projection::BasicProjectionBuilder<NotSendStruct>>();
+}
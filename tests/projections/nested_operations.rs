@@ -83,3 +83,67 @@ fn projection_with_lookup_projects_nested_fields() {
 
     assert_eq!(doc, expected);
 }
+
+#[test]
+fn projection_slice_array_field() {
+    let doc = empty::<Order>()
+        .includes::<order_fields::Id>()
+        .slice::<order_fields::Items>(-3)
+        .build();
+
+    let expected = bson::doc! {
+        "id": 1,
+        "items": { "$slice": -3 }
+    };
+
+    assert_eq!(doc, expected);
+}
+
+#[test]
+fn projection_slice_range_array_field() {
+    let doc = empty::<Order>()
+        .includes::<order_fields::Id>()
+        .slice_range::<order_fields::Items>(-5, 2)
+        .expect("positive limit")
+        .build();
+
+    let expected = bson::doc! {
+        "id": 1,
+        "items": { "$slice": [-5, 2] }
+    };
+
+    assert_eq!(doc, expected);
+}
+
+#[test]
+fn projection_slice_range_rejects_zero_limit() {
+    let mut builder = empty::<Order>();
+
+    let Err(error) = builder.slice_range::<order_fields::Items>(10, 0) else {
+        panic!("zero limit must be rejected");
+    };
+
+    assert_eq!(error.limit(), 0);
+
+    assert_eq!(builder.build(), bson::doc! {});
+}
+
+#[test]
+fn projection_slice_with_nested_include_on_same_array() {
+    let doc = empty::<Order>()
+        .slice::<order_fields::Items>(5)
+        .with_lookup::<order_fields::Items, _, orderitem_fields::Name, OrderItem, _>(
+            |path| path.element_field::<orderitem_fields::Name>(),
+            |nested| {
+                nested.includes::<orderitem_fields::Name>();
+            },
+        )
+        .build();
+
+    let expected = bson::doc! {
+        "items": { "$slice": 5 },
+        "items.name": 1
+    };
+
+    assert_eq!(doc, expected);
+}
@@ -30,3 +30,29 @@ pub struct UserWithProfile {
     pub id: String,
     pub profile: Profile,
 }
+
+#[derive(Deserialize, Serialize, FieldWitnesses)]
+pub struct Contact {
+    pub name: String,
+    pub phone_home: String,
+    pub phone_mobile: String,
+    pub email: String,
+}
+
+#[derive(Deserialize, Serialize, FieldWitnesses)]
+pub struct OrderItem {
+    pub name: String,
+    pub quantity: u32,
+}
+
+#[derive(Deserialize, Serialize, FieldWitnesses)]
+pub struct Order {
+    pub id: String,
+    pub items: Vec<OrderItem>,
+}
+
+#[derive(Deserialize, Serialize, FieldWitnesses)]
+pub struct Article {
+    pub title: String,
+    pub score: Option<f64>,
+}
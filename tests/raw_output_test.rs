@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use tnuctipun::{FieldWitnesses, MongoComparable};
+use tnuctipun::{filters, updates};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+pub struct Product {
+    pub name: String,
+    pub price: f64,
+    pub stock: i32,
+}
+
+#[test]
+fn test_filter_and_raw_buf_matches_document() {
+    let mut builder = filters::empty::<Product>();
+
+    builder
+        .eq::<product_fields::Name, _>("Laptop")
+        .gt::<product_fields::Price, _>(500.0);
+
+    let raw = builder.and_raw_buf().expect("valid raw document");
+
+    assert_eq!(
+        bson::Document::try_from(raw).expect("decodable"),
+        builder.and()
+    );
+}
+
+#[test]
+fn test_update_build_raw_buf_matches_document() {
+    let mut builder = updates::empty::<Product>();
+
+    builder
+        .set::<product_fields::Name, _>("Laptop")
+        .inc::<product_fields::Stock, _>(-1);
+
+    let raw = builder.build_raw_buf().expect("valid raw document");
+
+    assert_eq!(
+        bson::Document::try_from(raw).expect("decodable"),
+        builder.build()
+    );
+}
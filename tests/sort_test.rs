@@ -0,0 +1,120 @@
+use tnuctipun::FieldWitnesses;
+use tnuctipun::sort::{SortBuilder, empty};
+
+#[derive(FieldWitnesses)]
+#[allow(dead_code)]
+pub struct Address {
+    pub city: String,
+    pub zip: String,
+}
+
+#[derive(FieldWitnesses)]
+#[allow(dead_code)]
+pub struct User {
+    pub name: String,
+    pub age: i32,
+    pub address: Address,
+}
+
+#[test]
+fn test_sort_asc_and_desc() {
+    let doc = empty::<User>()
+        .desc::<user_fields::Age>()
+        .asc::<user_fields::Name>()
+        .build();
+
+    let expected = bson::doc! {
+        "age": -1,
+        "name": 1
+    };
+
+    assert_eq!(doc, expected);
+}
+
+#[test]
+fn test_sort_keeps_key_order() {
+    let doc = empty::<User>()
+        .asc::<user_fields::Name>()
+        .desc::<user_fields::Age>()
+        .build();
+
+    let keys: Vec<&String> = doc.keys().collect();
+
+    assert_eq!(keys, vec!["name", "age"]);
+}
+
+#[test]
+fn test_sort_nested_field() {
+    let doc = empty::<User>()
+        .with_lookup::<user_fields::Address, _, address_fields::City, Address, _>(
+            |path| path.field::<address_fields::City>(),
+            |nested| {
+                nested.asc::<address_fields::City>();
+            },
+        )
+        .desc::<user_fields::Age>()
+        .build();
+
+    let expected = bson::doc! {
+        "address.city": 1,
+        "age": -1
+    };
+
+    assert_eq!(doc, expected);
+}
+
+#[test]
+fn test_sort_parse_spec() {
+    let doc = SortBuilder::<User>::parse("-age, name").unwrap().build();
+
+    let expected = bson::doc! {
+        "age": -1,
+        "name": 1
+    };
+
+    let keys: Vec<&String> = doc.keys().collect();
+
+    assert_eq!(doc, expected);
+    assert_eq!(keys, vec!["age", "name"]);
+}
+
+#[test]
+fn test_sort_parse_empty_spec() {
+    let doc = SortBuilder::<User>::parse("").unwrap().build();
+
+    assert!(doc.is_empty());
+}
+
+#[test]
+fn test_sort_parse_unknown_field() {
+    let error = SortBuilder::<User>::parse("name,-email").err();
+
+    assert_eq!(error.as_ref().map(|e| e.name()), Some("email"));
+    assert_eq!(
+        error.map(|e| e.to_string()),
+        Some("Unknown field: 'email'".to_string())
+    );
+}
+
+#[test]
+fn test_sort_parse_nested_field_unsupported() {
+    // Only the top-level witnessed fields are known
+    let error = SortBuilder::<User>::parse("address.city").err();
+
+    assert_eq!(
+        error.map(|e| e.name().to_string()),
+        Some("address.city".to_string())
+    );
+}
+
+#[test]
+fn test_builder_is_send_and_sync_whatever_the_type() {
+    // Raw pointers are neither `Send` nor `Sync`
+    struct NotSendStruct {
+        _pointer: *const u8,
+    }
+
+    fn assert_send_sync<B: Send + Sync>() {}
+
+    assert_send_sync::<SortBuilder<NotSendStruct>>();
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "tracing")]
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use tnuctipun::{FieldWitnesses, MongoComparable};
+use tnuctipun::{filters, updates};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+#[derive(Debug, Clone, Serialize, Deserialize, FieldWitnesses, MongoComparable)]
+pub struct Product {
+    pub name: String,
+    pub price: f64,
+    pub stock: i32,
+}
+
+// Minimal subscriber recording the fields of each event as debug strings
+#[derive(Clone, Default)]
+struct CapturingSubscriber {
+    events: Arc<Mutex<Vec<HashMap<String, String>>>>,
+}
+
+struct FieldsVisitor<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for FieldsVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{value:?}"));
+    }
+}
+
+impl Subscriber for CapturingSubscriber {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = HashMap::new();
+
+        event.record(&mut FieldsVisitor(&mut fields));
+
+        self.events.lock().unwrap().push(fields);
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn test_filter_and_emits_event() {
+    let subscriber = CapturingSubscriber::default();
+
+    let doc = tracing::subscriber::with_default(subscriber.clone(), || {
+        filters::empty::<Product>()
+            .eq::<product_fields::Name, _>("Laptop")
+            .gt::<product_fields::Price, _>(500.0)
+            .and()
+    });
+
+    let events = subscriber.events.lock().unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["kind"], "\"filter\"");
+    assert_eq!(events[0]["size"], doc.to_vec().unwrap().len().to_string());
+    assert_eq!(events[0]["operators"], "{\"$and\", \"$gt\"}");
+}
+
+#[test]
+fn test_update_build_emits_event() {
+    let subscriber = CapturingSubscriber::default();
+
+    tracing::subscriber::with_default(subscriber.clone(), || {
+        updates::empty::<Product>()
+            .set::<product_fields::Name, _>("Laptop")
+            .inc::<product_fields::Stock, _>(1)
+            .build()
+    });
+
+    let events = subscriber.events.lock().unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["kind"], "\"update\"");
+    assert_eq!(events[0]["operators"], "{\"$inc\", \"$set\"}");
+}
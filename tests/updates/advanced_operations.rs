@@ -29,6 +29,77 @@ fn test_push_each_with_position_last() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_insert_before_last() {
+    let result = empty::<TestStruct>()
+        .insert_before_last::<ArrayFieldName, _, _>(vec!["a".to_string(), "b".to_string()], 2)
+        .build();
+
+    let expected = bson::doc! {
+        "$push": {
+            "array_field": {
+                "$each": ["a", "b"],
+                "$position": -2
+            }
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_insert_before_last_zero_appends() {
+    let result = empty::<TestStruct>()
+        .insert_before_last::<ArrayFieldName, _, _>(vec!["a".to_string()], 0)
+        .build();
+
+    let expected = bson::doc! {
+        "$push": {
+            "array_field": {
+                "$each": ["a"]
+            }
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_push_sorted_ascending() {
+    let result = empty::<TestStruct>()
+        .push_sorted::<ArrayFieldName, _, _>(vec!["b".to_string(), "a".to_string()], false)
+        .build();
+
+    let expected = bson::doc! {
+        "$push": {
+            "array_field": {
+                "$each": ["b", "a"],
+                "$sort": 1
+            }
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_push_sorted_descending() {
+    let result = empty::<TestStruct>()
+        .push_sorted::<ArrayFieldName, _, _>(vec!["a".to_string()], true)
+        .build();
+
+    let expected = bson::doc! {
+        "$push": {
+            "array_field": {
+                "$each": ["a"],
+                "$sort": -1
+            }
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
 // Tests for PushEach with different modifiers
 #[test]
 fn test_push_each_basic() {
@@ -51,6 +122,48 @@ fn test_push_each_basic() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_push_each_same_field_twice() {
+    let result = empty::<TestStruct>()
+        .push_each::<ArrayFieldName, _, _, _>(vec!["1".to_string(), "2".to_string()])
+        .push_each::<ArrayFieldName, _, _, _>(vec!["3".to_string()])
+        .build();
+
+    let expected = bson::doc! {
+        "$push": {
+            "array_field": {
+                "$each": ["1", "2", "3"]
+            }
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_push_each_same_field_twice_keeps_modifiers() {
+    let result = empty::<TestStruct>()
+        .push_each::<ArrayFieldName, _, _, _>(PushEach {
+            values: vec!["1".to_string(), "2".to_string()],
+            slice: Some(PushEachSlice::PushLastSlice(10)),
+            sort: None,
+            position: None,
+        })
+        .push::<ArrayFieldName, _>("3".to_string())
+        .build();
+
+    let expected = bson::doc! {
+        "$push": {
+            "array_field": {
+                "$each": ["1", "2", "3"],
+                "$slice": -10
+            }
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_push_each_with_slice_first() {
     let result = empty::<TestStruct>()
@@ -353,6 +466,52 @@ fn test_untyped_with_conditional_expression() {
 }
 
 // Tests for PushEach enum conversions
+#[test]
+fn test_push_each_with_slice_and_sort() {
+    let result = empty::<TestStruct>()
+        .push_each_with::<ArrayFieldName, _, _>(vec!["b".to_string(), "a".to_string()])
+        .slice(PushEachSlice::PushLastSlice(5))
+        .sort(PushEachSort::PushSortAscending)
+        .done()
+        .set::<TestFieldName, _>("value")
+        .build();
+
+    let expected = bson::doc! {
+        "$push": {
+            "array_field": {
+                "$each": ["b", "a"],
+                "$slice": -5,
+                "$sort": 1
+            }
+        },
+        "$set": {
+            "test_field": "value"
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_push_each_with_commits_on_drop() {
+    let mut builder = empty::<TestStruct>();
+
+    builder
+        .push_each_with::<ArrayFieldName, _, _>(vec!["1".to_string()])
+        .position(PushEachPosition::PushTakeFirst(0));
+
+    let expected = bson::doc! {
+        "$push": {
+            "array_field": {
+                "$each": ["1"],
+                "$position": 0
+            }
+        }
+    };
+
+    assert_eq!(builder.build(), expected);
+}
+
 #[test]
 fn test_push_each_slice_conversions() {
     let empty_slice: Bson = PushEachSlice::PushEmptySlice.into();
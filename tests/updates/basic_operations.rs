@@ -75,6 +75,192 @@ fn test_set_operations_different_bson_types() {
     assert_eq!(doc, expected_doc);
 }
 
+#[test]
+fn test_set_once_operation() {
+    let result = empty::<TestStruct>()
+        .set_once::<TestFieldName, _>("value")
+        .unwrap()
+        .set_once::<AnotherFieldName, _>(42)
+        .unwrap()
+        .build();
+
+    let expected = bson::doc! {
+        "$set": {
+            "test_field": "value",
+            "another_field": 42
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_set_once_operation_same_field_twice() {
+    let mut builder = empty::<TestStruct>();
+
+    builder.set::<TestFieldName, _>("first");
+
+    let Err(error) = builder.set_once::<TestFieldName, _>("second") else {
+        panic!("Expected a duplicate path error");
+    };
+
+    assert_eq!(error.path(), "test_field");
+    assert_eq!(
+        error.to_string(),
+        "Duplicate path: 'test_field' is already set"
+    );
+
+    let expected = bson::doc! {
+        "$set": {
+            "test_field": "first"
+        }
+    };
+
+    assert_eq!(builder.build(), expected);
+}
+
+#[test]
+fn test_set_once_operation_other_operator_same_field() {
+    let result = empty::<TestStruct>()
+        .inc::<AnotherFieldName, _>(1)
+        .set_once::<AnotherFieldName, _>(2)
+        .map(|builder| builder.build());
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_set_serialized_enum() {
+    let result = empty::<Ticket>()
+        .set_serialized::<ticket_fields::Status, _>(&TicketStatus::Open)
+        .unwrap()
+        .build();
+
+    let expected = bson::doc! {
+        "$set": {
+            "status": "open"
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_set_serialized_enum_variant_with_fields() {
+    let status = TicketStatus::Closed {
+        reason: "duplicate".to_string(),
+    };
+
+    let result = empty::<Ticket>()
+        .set_serialized::<ticket_fields::Status, _>(&status)
+        .unwrap()
+        .build();
+
+    let expected = bson::doc! {
+        "$set": {
+            "status": { "closed": { "reason": "duplicate" } }
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_set_date_from_system_time() {
+    let started_at = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(1_500);
+
+    let result = empty::<Session>()
+        .set_date::<session_fields::StartedAt, _>(started_at)
+        .build();
+
+    let expected = bson::doc! {
+        "$set": {
+            "started_at": bson::DateTime::from_millis(1_500)
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[cfg(feature = "bson-chrono")]
+#[test]
+fn test_set_date_from_chrono() {
+    let expires_at = chrono::DateTime::from_timestamp_millis(1_700_000_000_123).unwrap();
+
+    let result = empty::<Session>()
+        .set_date::<session_fields::ExpiresAt, _>(expires_at)
+        .build();
+
+    let expected = bson::doc! {
+        "$set": {
+            "expires_at": bson::DateTime::from_millis(1_700_000_000_123)
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_max_date_latest_wins() {
+    let result = empty::<Session>()
+        .max_date::<session_fields::StartedAt, _>(bson::DateTime::from_millis(1_700_000_000_000))
+        .build();
+
+    let expected = bson::doc! {
+        "$max": {
+            "started_at": bson::DateTime::from_millis(1_700_000_000_000)
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_min_date_from_system_time() {
+    let started_at = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(2_500);
+
+    let result = empty::<Session>()
+        .min_date::<session_fields::StartedAt, _>(started_at)
+        .build();
+
+    let expected = bson::doc! {
+        "$min": {
+            "started_at": bson::DateTime::from_millis(2_500)
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_min_max_date_on_optional_date_field() {
+    let result = empty::<Session>()
+        .min_date::<session_fields::StartedAt, _>(bson::DateTime::from_millis(2_500))
+        .max_date::<session_fields::ExpiresAt, _>(bson::DateTime::from_millis(9_000))
+        .build();
+
+    let expected = bson::doc! {
+        "$min": {
+            "started_at": bson::DateTime::from_millis(2_500)
+        },
+        "$max": {
+            "expires_at": bson::DateTime::from_millis(9_000)
+        }
+    };
+
+    assert_eq!(result, expected);
+
+    // The written dates can be read back into the date fields
+    let mut stored = result.get_document("$min").unwrap().clone();
+
+    stored.extend(result.get_document("$max").unwrap().clone());
+
+    let session: Session = bson::deserialize_from_document(stored).unwrap();
+
+    assert_eq!(session.started_at, bson::DateTime::from_millis(2_500));
+    assert_eq!(session.expires_at, Some(bson::DateTime::from_millis(9_000)));
+}
+
 // Tests for $unset operation
 #[test]
 fn test_single_unset_operation() {
@@ -106,6 +292,23 @@ fn test_multiple_unset_operations() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_unset_optional_operations() {
+    let result = empty::<Profile>()
+        .unset_optional::<profile_fields::Nickname>()
+        .unset_optional::<profile_fields::Tags>()
+        .build();
+
+    let expected = bson::doc! {
+        "$unset": {
+            "nickname": bson::Bson::Null,
+            "tags": bson::Bson::Null
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
 // Tests for $inc operation
 #[test]
 fn test_single_inc_operation() {
@@ -150,6 +353,99 @@ fn test_multiple_inc_operations() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_dec_operation() {
+    let result = empty::<TestStruct>().dec::<NumericFieldName, _>(3).build();
+
+    let expected = bson::doc! {
+        "$inc": {
+            "numeric_field": -3
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_dec_operation_decimal() {
+    let result = empty::<TestStruct>()
+        .dec::<NumericFieldName, _>(0.5f64)
+        .build();
+
+    let expected = bson::doc! {
+        "$inc": {
+            "numeric_field": -0.5
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_inc_decimal128_beyond_i64() {
+    let amount = i128::from(i64::MAX) * 4;
+    let result = empty::<TestStruct>()
+        .inc_decimal128::<NumericFieldName, _>(amount)
+        .unwrap()
+        .build();
+
+    let expected_amount: bson::Decimal128 = "36893488147419103228".parse().unwrap();
+    let expected = bson::doc! {
+        "$inc": {
+            "numeric_field": expected_amount
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_inc_decimal128_negative() {
+    let result = empty::<TestStruct>()
+        .inc_decimal128::<NumericFieldName, _>(-(i128::from(u64::MAX)))
+        .unwrap()
+        .build();
+
+    let expected_amount: bson::Decimal128 = "-18446744073709551615".parse().unwrap();
+    let expected = bson::doc! {
+        "$inc": {
+            "numeric_field": expected_amount
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_inc_decimal128_overflow() {
+    // u128::MAX has 39 digits, beyond the 34 digits of Decimal128
+    let mut builder = empty::<TestStruct>();
+
+    assert!(
+        builder
+            .inc_decimal128::<NumericFieldName, _>(u128::MAX)
+            .is_err()
+    );
+    assert_eq!(builder.build(), bson::doc! {});
+}
+
+#[test]
+fn test_mul_decimal128() {
+    let result = empty::<TestStruct>()
+        .mul_decimal128::<NumericFieldName, _>(10_u128.pow(30))
+        .unwrap()
+        .build();
+
+    let expected_factor: bson::Decimal128 = format!("1{}", "0".repeat(30)).parse().unwrap();
+    let expected = bson::doc! {
+        "$mul": {
+            "numeric_field": expected_factor
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
 // Tests for $mul operation
 #[test]
 fn test_single_mul_operation() {
@@ -271,6 +567,22 @@ fn test_max_operation_same_field_multiple_times() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_max_operation_same_field_last_wins_over_greatest() {
+    let result = empty::<TestStruct>()
+        .max::<NumericFieldName, _>(20)
+        .max::<NumericFieldName, _>(10)
+        .build();
+
+    let expected = bson::doc! {
+        "$max": {
+            "numeric_field": 10  // last max operation wins, even if lower
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
 // Tests for $min operation
 #[test]
 fn test_single_min_operation() {
@@ -348,6 +660,22 @@ fn test_min_operation_same_field_multiple_times() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_min_operation_same_field_last_wins_over_lowest() {
+    let result = empty::<TestStruct>()
+        .min::<NumericFieldName, _>(15)
+        .min::<NumericFieldName, _>(30)
+        .build();
+
+    let expected = bson::doc! {
+        "$min": {
+            "numeric_field": 30  // last min operation wins, even if greater
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_max_and_min_combined_operations() {
     let result = empty::<TestStruct>()
@@ -427,6 +755,36 @@ fn test_multiple_rename_operations() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_rename_pairs_macro() {
+    #[derive(tnuctipun::FieldWitnesses)]
+    #[allow(dead_code)]
+    struct MigratedUser {
+        pub user_id: String,
+        pub full_name: String,
+        pub years: i32,
+    }
+
+    let mut builder = empty::<User>();
+
+    let result = tnuctipun::rename_pairs!(builder, MigratedUser;
+        (UserId, migrateduser_fields::UserId),
+        (UserName, migrateduser_fields::FullName),
+        (UserAge, migrateduser_fields::Years),
+    )
+    .build();
+
+    let expected = bson::doc! {
+        "$rename": {
+            "id": "user_id",
+            "name": "full_name",
+            "age": "years"
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
 // Tests for $currentDate operation
 #[test]
 fn test_current_date_operation_date_type() {
@@ -458,6 +816,23 @@ fn test_current_date_operation_timestamp_type() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_touch_operation() {
+    let result = empty::<TestStruct>()
+        .touch::<TestFieldName>()
+        .current_date::<AnotherFieldName>(CurrentDateType::Timestamp)
+        .build();
+
+    let expected = bson::doc! {
+        "$currentDate": {
+            "test_field": true,
+            "another_field": "timestamp"
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_multiple_current_date_operations() {
     let result = empty::<TestStruct>()
@@ -500,7 +875,9 @@ fn test_multiple_add_to_set_operations() {
 
     let expected = bson::doc! {
         "$addToSet": {
-            "array_field": "item2"  // last one wins due to field overwriting
+            "array_field": {
+                "$each": ["item1", "item2"]  // values are accumulated
+            }
         }
     };
 
@@ -538,6 +915,60 @@ fn test_pop_operation_last() {
     assert_eq!(result, expected);
 }
 
+// Tests for bounded arrays (trimming with $push and $slice)
+#[test]
+fn test_keep_last_operation() {
+    let result = empty::<TestStruct>().keep_last::<ArrayFieldName>(3).build();
+
+    let expected = bson::doc! {
+        "$push": {
+            "array_field": {
+                "$each": [],
+                "$slice": -3
+            }
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_keep_first_operation() {
+    let result = empty::<TestStruct>()
+        .keep_first::<ArrayFieldName>(3)
+        .build();
+
+    let expected = bson::doc! {
+        "$push": {
+            "array_field": {
+                "$each": [],
+                "$slice": 3
+            }
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_keep_last_after_push() {
+    let result = empty::<TestStruct>()
+        .push::<ArrayFieldName, _>("latest".to_string())
+        .keep_last::<ArrayFieldName>(10)
+        .build();
+
+    let expected = bson::doc! {
+        "$push": {
+            "array_field": {
+                "$each": ["latest"],
+                "$slice": -10
+            }
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
 // Tests for $pull operation with expressions
 #[test]
 fn test_pull_expr_operation() {
@@ -603,6 +1034,21 @@ fn test_pull_all_operation() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_pull_all_operation_from_iterator() {
+    let result = empty::<TestStruct>()
+        .pull_all::<ArrayFieldName, _>((1..=3).map(|i| format!("item{i}")))
+        .build();
+
+    let expected = bson::doc! {
+        "$pullAll": {
+            "array_field": ["item1", "item2", "item3"]
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
 // Tests for $push operation
 #[test]
 fn test_push_operation() {
@@ -626,10 +1072,12 @@ fn test_push_operation_multiple_items() {
         .push::<ArrayFieldName, _>("item2".to_string())
         .build();
 
-    // The second push overwrites the first one due to field overwriting
+    // The pushed values are accumulated
     let expected = bson::doc! {
         "$push": {
-            "array_field": "item2"
+            "array_field": {
+                "$each": ["item1", "item2"]
+            }
         }
     };
 
@@ -659,6 +1107,24 @@ fn test_add_to_set_each_operation() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_add_to_set_each_operation_same_field_twice() {
+    let result = empty::<TestStruct>()
+        .add_to_set_each::<ArrayFieldName, _, _>(vec!["item1".to_string(), "item2".to_string()])
+        .add_to_set_each::<ArrayFieldName, _, _>(vec!["item3".to_string()])
+        .build();
+
+    let expected = bson::doc! {
+        "$addToSet": {
+            "array_field": {
+                "$each": ["item1", "item2", "item3"]
+            }
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_add_to_set_each_empty_collection() {
     let values: Vec<String> = vec![];
@@ -713,6 +1179,47 @@ fn test_add_to_set_each_multiple_fields() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_add_to_set_each_iterator_adaptor() {
+    #[derive(tnuctipun::FieldWitnesses)]
+    struct Game {
+        pub scores: Vec<i32>,
+    }
+
+    let result = empty::<Game>()
+        .add_to_set_each::<game_fields::Scores, _, _>((1..=3).map(|n| n * 10))
+        .build();
+
+    let expected = bson::doc! {
+        "$addToSet": {
+            "scores": {
+                "$each": [10, 20, 30]
+            }
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_add_to_set_each_borrowed_iterator() {
+    let names = ["rust", "mongodb"];
+
+    let result = empty::<TestStruct>()
+        .add_to_set_each::<ArrayFieldName, _, _>(names.iter().map(|name| name.to_uppercase()))
+        .build();
+
+    let expected = bson::doc! {
+        "$addToSet": {
+            "array_field": {
+                "$each": ["RUST", "MONGODB"]
+            }
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_add_to_set_each_different_types() {
     let string_values = vec![
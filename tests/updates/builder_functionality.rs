@@ -1,7 +1,9 @@
 //! Tests for UpdateBuilder functionality, method chaining, and builder patterns
 
 use super::test_fixtures::*;
-use tnuctipun::updates::{UpdateBuilder, empty};
+use tnuctipun::updates::{
+    EmptyUpdateError, InvalidUpdateError, UpdateBuilder, UpdateOperation, empty,
+};
 
 #[test]
 fn test_empty_builder_produces_empty_document() {
@@ -10,6 +12,175 @@ fn test_empty_builder_produces_empty_document() {
     assert!(doc.is_empty());
 }
 
+#[test]
+fn test_try_build_empty_builder() {
+    let result = empty::<TestStruct>().try_build();
+
+    assert_eq!(result, Err(InvalidUpdateError::Empty(EmptyUpdateError)));
+}
+
+#[test]
+fn test_try_build_with_operation() {
+    let result = empty::<TestStruct>()
+        .set::<TestFieldName, _>("value")
+        .try_build();
+
+    let expected = bson::doc! {
+        "$set": {
+            "test_field": "value"
+        }
+    };
+
+    assert_eq!(result, Ok(expected));
+}
+
+#[test]
+fn test_try_build_set_and_set_on_insert() {
+    let result = empty::<TestStruct>()
+        .set::<TestFieldName, _>("value")
+        .set_on_insert::<NumericFieldName, _>(0)
+        .try_build();
+
+    let expected = bson::doc! {
+        "$set": { "test_field": "value" },
+        "$setOnInsert": { "numeric_field": 0 }
+    };
+
+    assert_eq!(result, Ok(expected));
+}
+
+#[test]
+fn test_try_build_conflicting_set_on_insert_path() {
+    let result = empty::<TestStruct>()
+        .set::<TestFieldName, _>("value")
+        .set_on_insert::<TestFieldName, _>("initial")
+        .try_build();
+
+    assert_eq!(
+        result,
+        Err(InvalidUpdateError::ConflictingPath {
+            path: "test_field".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_try_build_conflicting_set_on_insert_parent_path() {
+    let result = empty::<User>()
+        .with_lookup::<UserHomeAddress, _, AddressCity, Address, _>(
+            |path| path.field::<AddressCity>(),
+            |nested| {
+                nested.set::<AddressCity, _>("Paris".to_string());
+            },
+        )
+        .set_on_insert::<UserHomeAddress, _>(bson::doc! { "city": "Lyon" })
+        .try_build();
+
+    assert_eq!(
+        result,
+        Err(InvalidUpdateError::ConflictingPath {
+            path: "home_address".to_string()
+        })
+    );
+
+    // Sibling paths sharing a name prefix don't conflict
+    let result = empty::<User>()
+        .set::<UserName, _>("Jane".to_string())
+        .set_on_insert::<UserId, _>("name_id".to_string())
+        .try_build();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_try_build_duplicate_path_from_merged_builders() {
+    let result = empty::<User>()
+        .with_lookup::<UserHomeAddress, _, AddressCity, Address, _>(
+            |path| path.field::<AddressCity>(),
+            |nested| {
+                nested.set::<AddressCity, _>("Paris".to_string());
+            },
+        )
+        .with_lookup::<UserHomeAddress, _, AddressCity, Address, _>(
+            |path| path.field::<AddressCity>(),
+            |nested| {
+                nested.set::<AddressCity, _>("Lyon".to_string());
+            },
+        )
+        .try_build();
+
+    let Err(InvalidUpdateError::DuplicatePath(error)) = result else {
+        panic!("Expected a duplicate path error");
+    };
+
+    assert_eq!(error.path(), "home_address.city");
+}
+
+#[test]
+fn test_try_build_conflicting_operators_on_same_path() {
+    let result = empty::<TestStruct>()
+        .inc::<NumericFieldName, _>(1)
+        .set::<NumericFieldName, _>(0)
+        .try_build();
+
+    // Reported in the canonical order of the operators ($set first), whatever the call order
+    assert_eq!(
+        result,
+        Err(InvalidUpdateError::ConflictingPath {
+            path: "numeric_field".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_try_build_conflicting_parent_path_same_operator() {
+    let result = empty::<User>()
+        .with_lookup::<UserHomeAddress, _, AddressCity, Address, _>(
+            |path| path.field::<AddressCity>(),
+            |nested| {
+                nested.set::<AddressCity, _>("Paris".to_string());
+            },
+        )
+        .set::<UserHomeAddress, _>(bson::doc! { "city": "Lyon" })
+        .try_build();
+
+    assert_eq!(
+        result,
+        Err(InvalidUpdateError::ConflictingPath {
+            path: "home_address".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_try_build_conflicting_rename_target() {
+    let result = empty::<TestStruct>()
+        .rename::<AnotherFieldName>("test_field")
+        .set::<TestFieldName, _>("value")
+        .try_build();
+
+    assert_eq!(
+        result,
+        Err(InvalidUpdateError::ConflictingPath {
+            path: "test_field".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_try_build_accumulated_push_path() {
+    let result = empty::<TestStruct>()
+        .push::<ArrayFieldName, _>("a".to_string())
+        .push::<ArrayFieldName, _>("b".to_string())
+        .try_build();
+
+    let expected = bson::doc! {
+        "$push": { "array_field": { "$each": ["a", "b"] } }
+    };
+
+    assert_eq!(result, Ok(expected));
+}
+
 #[test]
 fn test_method_chaining_works_with_mut_self_pattern() {
     // This test demonstrates that method chaining now works fully,
@@ -80,6 +251,22 @@ fn test_set_operations_with_prefix() {
     assert_eq!(doc, expected_doc);
 }
 
+#[test]
+fn test_with_prefix_constructor() {
+    let doc =
+        UpdateBuilder::<TestStruct>::with_prefix(vec!["parent".to_string(), "child".to_string()])
+            .set::<TestFieldName, _>("nested_value")
+            .inc::<NumericFieldName, _>(1)
+            .build();
+
+    let expected_doc = bson::doc! {
+        "$set": { "parent.child.test_field": "nested_value" },
+        "$inc": { "parent.child.numeric_field": 1 }
+    };
+
+    assert_eq!(doc, expected_doc);
+}
+
 #[test]
 fn test_comprehensive_document_structure() {
     let doc = empty::<TestStruct>()
@@ -101,3 +288,50 @@ fn test_comprehensive_document_structure() {
     // Single assertion covers structure, field names, values, and types
     assert_eq!(doc, expected_doc);
 }
+
+#[test]
+fn test_builder_is_send_and_sync_whatever_the_type() {
+    // Raw pointers are neither `Send` nor `Sync`
+    struct NotSendStruct {
+        _pointer: *const u8,
+    }
+
+    fn assert_send_sync<B: Send + Sync>() {}
+
+    assert_send_sync::<UpdateBuilder<NotSendStruct>>();
+}
+
+#[test]
+fn test_build_canonical_operator_order() {
+    let doc = empty::<TestStruct>()
+        .touch::<TestFieldName>()
+        .inc::<NumericFieldName, _>(1)
+        .set::<AnotherFieldName, _>(100)
+        .build();
+
+    assert_eq!(
+        doc.keys().collect::<Vec<_>>(),
+        ["$set", "$inc", "$currentDate"]
+    );
+}
+
+#[test]
+fn test_build_ordered_current_date_first() {
+    let doc = empty::<TestStruct>()
+        .set::<AnotherFieldName, _>(100)
+        .inc::<NumericFieldName, _>(1)
+        .touch::<TestFieldName>()
+        .build_ordered(&[UpdateOperation::CurrentDate]);
+
+    let expected = bson::doc! {
+        "$currentDate": { "test_field": true },
+        "$set": { "another_field": 100 },
+        "$inc": { "numeric_field": 1 }
+    };
+
+    assert_eq!(doc, expected);
+    assert_eq!(
+        doc.keys().collect::<Vec<_>>(),
+        ["$currentDate", "$set", "$inc"]
+    );
+}
@@ -1,4 +1,4 @@
-//! Tests for conditional update operations using `if_some`
+//! Tests for conditional update operations using `if_some` and `set_if_changed`
 
 use super::test_fixtures::*;
 use tnuctipun::updates::empty;
@@ -256,3 +256,38 @@ fn test_if_some_with_nested_options() {
 
     assert_eq!(doc3, expected3);
 }
+
+#[test]
+fn test_set_if_changed_with_changed_value_applies_set() {
+    let current = "old_value".to_string();
+
+    let doc = empty::<TestStruct>()
+        .set_if_changed::<TestFieldName, _>("new_value".to_string(), &current)
+        .build();
+
+    let expected_doc = bson::doc! {
+        "$set": {
+            "test_field": "new_value"
+        }
+    };
+
+    assert_eq!(doc, expected_doc);
+}
+
+#[test]
+fn test_set_if_changed_with_unchanged_value_skips_set() {
+    let current = "same_value".to_string();
+
+    let doc = empty::<TestStruct>()
+        .set::<AnotherFieldName, _>("base_value")
+        .set_if_changed::<TestFieldName, _>("same_value".to_string(), &current)
+        .build();
+
+    let expected_doc = bson::doc! {
+        "$set": {
+            "another_field": "base_value"
+        }
+    };
+
+    assert_eq!(doc, expected_doc);
+}
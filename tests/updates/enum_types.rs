@@ -5,6 +5,7 @@ use tnuctipun::updates::{CurrentDateType, PopStrategy, UpdateOperation};
 #[test]
 fn test_update_operation_to_string() {
     assert_eq!(UpdateOperation::Set.to_string(), "$set");
+    assert_eq!(UpdateOperation::SetOnInsert.to_string(), "$setOnInsert");
     assert_eq!(UpdateOperation::Unset.to_string(), "$unset");
     assert_eq!(UpdateOperation::Inc.to_string(), "$inc");
     assert_eq!(UpdateOperation::Mul.to_string(), "$mul");
@@ -34,6 +35,7 @@ fn test_update_operation_all_variants_covered() {
     // This test ensures all enum variants are explicitly tested
     let operations = [
         UpdateOperation::Set,
+        UpdateOperation::SetOnInsert,
         UpdateOperation::Unset,
         UpdateOperation::Inc,
         UpdateOperation::Mul,
@@ -48,6 +50,7 @@ fn test_update_operation_all_variants_covered() {
 
     let expected_strings = [
         "$set",
+        "$setOnInsert",
         "$unset",
         "$inc",
         "$mul",
@@ -72,6 +75,7 @@ fn test_update_operation_string_format() {
     // Test that all operations start with '$' as expected by MongoDB
     let operations = vec![
         UpdateOperation::Set,
+        UpdateOperation::SetOnInsert,
         UpdateOperation::Unset,
         UpdateOperation::Inc,
         UpdateOperation::Mul,
@@ -1,7 +1,7 @@
 //! Tests for nested field operations using with_lookup and with_field
 
 use super::test_fixtures::*;
-use tnuctipun::updates::empty;
+use tnuctipun::updates::{ArrayFilterError, CurrentDateType, empty};
 
 // Tests for with_lookup function
 #[test]
@@ -25,6 +25,32 @@ fn test_with_lookup_single_nested_field() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_with_lookup_chained_returning_builder() {
+    // Same closure style as for FilterBuilder::with_lookup
+    let result = empty::<User>()
+        .with_lookup_chained::<UserHomeAddress, _, AddressCity, Address, _>(
+            |path| path.field::<AddressCity>(),
+            |nested| {
+                nested
+                    .set::<AddressCity, _>("New York".to_string())
+                    .set::<AddressZipCode, _>("10001".to_string())
+            },
+        )
+        .set::<UserName, _>("John".to_string())
+        .build();
+
+    let expected = bson::doc! {
+        "$set": {
+            "home_address.city": "New York",
+            "home_address.zip_code": "10001",
+            "name": "John"
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_with_lookup_multiple_nested_fields() {
     // Test updating multiple nested fields within the same nested object
@@ -345,3 +371,252 @@ fn test_mixed_with_field_and_with_lookup() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_with_array_filter_two_identifiers() {
+    let spec = empty::<Order>()
+        .with_array_filter::<order_fields::Lines, OrderLine, _, _>(
+            "cheap",
+            |filter| {
+                filter.lt::<orderline_fields::Price, _>(10.0);
+            },
+            |nested| {
+                nested.mul::<orderline_fields::Price, _>(1.1);
+            },
+        )
+        .expect("valid array filter")
+        .with_array_filter::<order_fields::Lines, OrderLine, _, _>(
+            "restock",
+            |filter| {
+                filter
+                    .eq::<orderline_fields::Sku, _>("A1".to_string())
+                    .lte::<orderline_fields::Quantity, _>(0);
+            },
+            |nested| {
+                nested.set::<orderline_fields::Quantity, _>(100);
+            },
+        )
+        .expect("valid array filter")
+        .build_spec();
+
+    let expected_update = bson::doc! {
+        "$mul": { "lines.$[cheap].price": 1.1 },
+        "$set": { "lines.$[restock].quantity": 100 }
+    };
+
+    let expected_filters = vec![
+        bson::doc! { "cheap.price": { "$lt": 10.0 } },
+        bson::doc! {
+            "$and": [
+                { "restock.sku": "A1" },
+                { "restock.quantity": { "$lte": 0 } }
+            ]
+        },
+    ];
+
+    assert_eq!(spec.update, expected_update);
+    assert_eq!(spec.array_filters, expected_filters);
+}
+
+#[test]
+fn test_with_array_filter_invalid_identifier() {
+    for identifier in ["", "Cheap", "1st", "cheap.line", "cheap_line"] {
+        let mut builder = empty::<Order>();
+
+        let result = builder.with_array_filter::<order_fields::Lines, OrderLine, _, _>(
+            identifier,
+            |filter| {
+                filter.lt::<orderline_fields::Price, _>(10.0);
+            },
+            |nested| {
+                nested.mul::<orderline_fields::Price, _>(1.1);
+            },
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(ArrayFilterError::InvalidIdentifier(identifier.to_string()))
+        );
+        assert_eq!(builder.build_spec(), empty::<Order>().build_spec());
+    }
+}
+
+#[test]
+fn test_with_array_filter_empty_condition() {
+    let mut builder = empty::<Order>();
+
+    let result = builder.with_array_filter::<order_fields::Lines, OrderLine, _, _>(
+        "line",
+        |_| {},
+        |nested| {
+            nested.set::<orderline_fields::Quantity, _>(0);
+        },
+    );
+
+    let Err(error) = result else {
+        panic!("Expected an empty condition error");
+    };
+
+    assert_eq!(error, ArrayFilterError::EmptyCondition("line".to_string()));
+    assert_eq!(
+        error.to_string(),
+        "Empty array filter: the condition for 'line' adds no clause"
+    );
+    assert!(builder.build().is_empty());
+}
+
+#[test]
+fn test_with_lookup_positional_element() {
+    let result = empty::<Order>()
+        .with_lookup::<order_fields::Lines, _, orderline_fields::Quantity, OrderLine, _>(
+            |path| path.positional::<orderline_fields::Quantity>(),
+            |nested| {
+                nested
+                    .set::<orderline_fields::Sku, _>("B2".to_string())
+                    .inc::<orderline_fields::Quantity, _>(-1);
+            },
+        )
+        .build();
+
+    let expected = bson::doc! {
+        "$set": { "lines.$.sku": "B2" },
+        "$inc": { "lines.$.quantity": -1 }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_build_spec_without_array_filters() {
+    let spec = empty::<User>()
+        .set::<UserName, _>("John".to_string())
+        .build_spec();
+
+    assert_eq!(spec.update, bson::doc! { "$set": { "name": "John" } });
+    assert!(spec.array_filters.is_empty());
+}
+
+#[test]
+fn test_with_lookup_rename_keeps_destination_verbatim() {
+    let result = empty::<User>()
+        .with_lookup::<UserHomeAddress, _, AddressCity, Address, _>(
+            |path| path.field::<AddressCity>(),
+            |nested| {
+                nested.rename::<AddressCity>("town");
+            },
+        )
+        .build();
+
+    let expected = bson::doc! {
+        "$rename": {
+            "home_address.city": "town"
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_with_lookup_rename_sibling_prefixes_destination() {
+    let result = empty::<User>()
+        .with_lookup::<UserHomeAddress, _, AddressCity, Address, _>(
+            |path| path.field::<AddressCity>(),
+            |nested| {
+                nested.rename_sibling::<AddressCity>("town");
+            },
+        )
+        .build();
+
+    let expected = bson::doc! {
+        "$rename": {
+            "home_address.city": "home_address.town"
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_rename_sibling_without_prefix() {
+    let result = empty::<User>()
+        .rename_sibling::<UserName>("full_name")
+        .build();
+
+    let expected = bson::doc! {
+        "$rename": {
+            "name": "full_name"
+        }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_inc_in_nested_counter() {
+    let result = empty::<Article>()
+        .inc_in::<article_fields::Stats, stats_fields::Views, _>(1)
+        .inc_in::<article_fields::Stats, stats_fields::Likes, _>(-2i64)
+        .build();
+
+    let expected = bson::doc! {
+        "$inc": { "stats.views": 1, "stats.likes": -2i64 }
+    };
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_inc_in_within_lookup_prefix() {
+    #[derive(tnuctipun::FieldWitnesses)]
+    struct Feed {
+        pub featured: Article,
+    }
+
+    let result = empty::<Feed>()
+        .with_lookup::<feed_fields::Featured, _, article_fields::Stats, Article, _>(
+            |path| path.field::<article_fields::Stats>(),
+            |nested| {
+                nested.inc_in::<article_fields::Stats, stats_fields::Views, _>(1);
+            },
+        )
+        .build();
+
+    assert_eq!(result, bson::doc! { "$inc": { "featured.stats.views": 1 } });
+}
+
+#[test]
+fn test_current_date_within_lookup_prefix() {
+    #[derive(tnuctipun::FieldWitnesses)]
+    struct Meta {
+        pub created_at: bson::DateTime,
+        pub updated_at: bson::DateTime,
+    }
+
+    #[derive(tnuctipun::FieldWitnesses)]
+    struct Page {
+        pub title: String,
+        pub meta: Meta,
+    }
+
+    let result = empty::<Page>()
+        .set::<page_fields::Title, _>("Home".to_string())
+        .with_lookup::<page_fields::Meta, _, meta_fields::UpdatedAt, Meta, _>(
+            |path| path.field::<meta_fields::UpdatedAt>(),
+            |nested| {
+                nested
+                    .touch::<meta_fields::UpdatedAt>()
+                    .current_date::<meta_fields::CreatedAt>(CurrentDateType::Timestamp);
+            },
+        )
+        .build();
+
+    let expected = bson::doc! {
+        "$set": { "title": "Home" },
+        "$currentDate": {
+            "meta.updated_at": true,
+            "meta.created_at": "timestamp"
+        }
+    };
+
+    assert_eq!(result, expected);
+}
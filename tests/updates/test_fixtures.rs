@@ -407,3 +407,58 @@ impl HasField<EmployeeCompany> for Employee {
         &self.company
     }
 }
+
+// Derived fixtures for array element updates
+#[derive(Debug, Clone, tnuctipun::FieldWitnesses, tnuctipun::MongoComparable)]
+pub struct OrderLine {
+    pub sku: String,
+    pub quantity: i32,
+    pub price: f64,
+}
+
+#[derive(Debug, Clone, tnuctipun::FieldWitnesses)]
+pub struct Order {
+    pub lines: Vec<OrderLine>,
+}
+
+// Derived fixtures for serialized values
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TicketStatus {
+    Open,
+    Closed { reason: String },
+}
+
+#[derive(Debug, Clone, tnuctipun::FieldWitnesses)]
+pub struct Ticket {
+    pub status: TicketStatus,
+}
+
+// Derived fixtures for date fields
+#[derive(Debug, Clone, serde::Deserialize, tnuctipun::FieldWitnesses)]
+pub struct Session {
+    pub started_at: bson::DateTime,
+    pub expires_at: Option<bson::DateTime>,
+}
+
+// Derived fixtures for optional fields
+#[derive(Debug, Clone, serde::Deserialize, tnuctipun::FieldWitnesses)]
+pub struct Profile {
+    pub name: String,
+    pub nickname: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+// Derived fixtures for nested counters
+#[derive(Debug, Clone, tnuctipun::FieldWitnesses)]
+pub struct Stats {
+    pub views: i32,
+    pub likes: i64,
+}
+
+#[derive(Debug, Clone, tnuctipun::FieldWitnesses)]
+pub struct Article {
+    pub title: String,
+    pub stats: Stats,
+}
@@ -0,0 +1,81 @@
+use syn::{LitStr, PathArguments, Type};
+
+// The BSON types supported by the #[tnuctipun(bson_type = "...")] field attribute:
+// their `$type` alias (as used by the MongoDB `$type` operator), and the name of the Rust type
+// whose compatibility rules apply to the field (see MongoComparable)
+const SUPPORTED_BSON_TYPES: &[(&str, &str)] = &[
+    ("double", "f64"),
+    ("string", "String"),
+    ("int", "i32"),
+    ("long", "i64"),
+    ("bool", "bool"),
+    ("date", "DateTime"),
+    ("objectId", "ObjectId"),
+    ("decimal", "Decimal128"),
+    ("binData", "Binary"),
+];
+
+// Helper function to get the `$type` alias and the type name of a supported BSON type
+fn lookup_bson_type(bson_type: &str) -> Option<(&'static str, &'static str)> {
+    SUPPORTED_BSON_TYPES
+        .iter()
+        .find(|(alias, _)| *alias == bson_type)
+        .copied()
+}
+
+// Helper function to parse the value of a bson_type attribute, failing if it's not supported
+pub fn parse_bson_type(bson_type: &LitStr) -> syn::Result<(&'static str, &'static str)> {
+    lookup_bson_type(&bson_type.value()).ok_or_else(|| {
+        let supported = SUPPORTED_BSON_TYPES
+            .iter()
+            .map(|(alias, _)| format!("'{alias}'"))
+            .collect::<Vec<_>>();
+
+        syn::Error::new(
+            bson_type.span(),
+            format!(
+                "Invalid bson_type attribute: '{}'. Supported options are: {}",
+                bson_type.value(),
+                supported.join(", ")
+            ),
+        )
+    })
+}
+
+// Helper function to check if a type is `bson::DateTime`, which (unlike `chrono::DateTime<Tz>`,
+// serialized as a string) is serialized as a BSON date: both are named `DateTime`, but only
+// the chrono one has generic arguments
+pub fn is_bson_date_time(ty: &Type) -> bool {
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+    {
+        return segment.ident == "DateTime" && matches!(segment.arguments, PathArguments::None);
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_lookup_bson_type() {
+        assert_eq!(lookup_bson_type("date"), Some(("date", "DateTime")));
+        assert_eq!(lookup_bson_type("long"), Some(("long", "i64")));
+        assert_eq!(lookup_bson_type("objectId"), Some(("objectId", "ObjectId")));
+        assert_eq!(lookup_bson_type("timestamp"), None);
+    }
+
+    #[test]
+    fn test_is_bson_date_time() {
+        assert!(is_bson_date_time(&parse_quote!(bson::DateTime)));
+        assert!(is_bson_date_time(&parse_quote!(DateTime)));
+        assert!(!is_bson_date_time(&parse_quote!(
+            chrono::DateTime<chrono::Utc>
+        )));
+        assert!(!is_bson_date_time(&parse_quote!(Option<bson::DateTime>)));
+        assert!(!is_bson_date_time(&parse_quote!(i64)));
+    }
+}
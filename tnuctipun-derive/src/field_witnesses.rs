@@ -1,6 +1,11 @@
+use crate::bson_types::{is_bson_date_time, parse_bson_type};
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Attribute, Data, DeriveInput, Fields, Lit, Visibility, parse_macro_input};
+use syn::punctuated::Punctuated;
+use syn::{
+    Attribute, Data, DeriveInput, Fields, Lit, LitBool, LitStr, Meta, Token, Type, Visibility,
+    parse_macro_input,
+};
 
 /// Derive macro for generating field witnesses with full attribute support.
 ///
@@ -27,11 +32,16 @@ use syn::{Attribute, Data, DeriveInput, Fields, Lit, Visibility, parse_macro_inp
 /// - `#[tnuctipun(include_private = true)]` - Include private fields in witness generation
 ///   - If not specified or set to false, private fields are skipped
 ///   - When true, both public and private fields generate witnesses
+/// - `#[tnuctipun(field_enum)]` - Also generate a `<StructName>Field` enum
+///   - One variant per witnessed field, named as the struct markers
+///   - `ALL` lists the variants, and `as_str()` returns the MongoDB field name
 ///
 /// ## Field-level attributes
 ///
 /// - `#[tnuctipun(rename = "name")]` - Override the MongoDB field name for this specific field
 /// - `#[tnuctipun(skip)]` - Skip generating witnesses for this field
+///   - The fields with `#[serde(skip)]` or `#[serde(skip_serializing)]` are skipped by default,
+///     as they are not stored; `#[tnuctipun(skip = false)]` keeps their witnesses
 ///
 /// Built-in field naming strategies
 struct FieldNaming;
@@ -51,12 +61,14 @@ impl FieldNaming {
 struct ContainerAttributes {
     field_naming_strategy: Option<String>, // Built-in strategy name only
     include_private: bool,                 // Whether to include private fields (default: false)
+    field_enum: bool,                      // Whether to generate the field enum (default: false)
 }
 
 #[derive(Debug, Default)]
 struct FieldAttributes {
     rename: Option<String>,
     skip: bool,
+    bson_type: Option<LitStr>, // Declared BSON type (see MongoComparable)
 }
 
 /// Procedural macro to generate field witnesses for a struct.
@@ -65,6 +77,9 @@ struct FieldAttributes {
 /// - Struct marker types for each field (always in PascalCase, following Rust naming conventions)
 /// - FieldName implementations for each field marker (returns MongoDB field names)
 /// - HasField implementations to access field values with type safety
+/// - A FieldNames implementation listing the MongoDB names of all witnessed fields
+/// - A `FIELDS` associated constant pairing these names with the `TypeId` of the markers
+/// - OptionalField implementations for the `Option` fields and the fields with a serde default
 ///
 /// Note: The generated field witnesses are scoped within a module named `<struct_name>_fields`
 /// at the same module level as the derived struct. This prevents naming conflicts when multiple
@@ -91,11 +106,16 @@ struct FieldAttributes {
 /// - `#[tnuctipun(include_private = true)]` - Include private fields in witness generation
 ///   - If not specified or set to false, private fields are skipped
 ///   - When true, both public and private fields generate witnesses
+/// - `#[tnuctipun(field_enum)]` - Also generate a `<StructName>Field` enum
+///   - One variant per witnessed field, named as the struct markers
+///   - `ALL` lists the variants, and `as_str()` returns the MongoDB field name
 ///
 /// ## Field-level attributes
 ///
 /// - `#[tnuctipun(rename = "name")]` - Override the MongoDB field name for this specific field
 /// - `#[tnuctipun(skip)]` - Skip generating witnesses for this field
+///   - The fields with `#[serde(skip)]` or `#[serde(skip_serializing)]` are skipped by default,
+///     as they are not stored; `#[tnuctipun(skip = false)]` keeps their witnesses
 ///
 /// # Examples
 ///
@@ -195,6 +215,7 @@ pub fn derive_field_witnesses(input: TokenStream) -> TokenStream {
 
     // Parse container-level attributes
     let container_attrs = parse_container_attributes(&input.attrs);
+    let container_serde_default = has_serde_default(&input.attrs);
 
     // Get the fields of the struct
     let fields = match input.data {
@@ -221,8 +242,8 @@ pub fn derive_field_witnesses(input: TokenStream) -> TokenStream {
         struct_name.span(),
     );
 
-    // Generate field witness types within the module
-    let field_witness_types = fields
+    // Generate field witness types within the module, along with the MongoDB field names
+    let witnessed_fields = fields
         .iter()
         .filter_map(|field| {
             let field_name = field.ident.as_ref().unwrap();
@@ -261,20 +282,65 @@ pub fn derive_field_witnesses(input: TokenStream) -> TokenStream {
                 quote! { #field_name_str }
             };
 
-            Some(quote! {
+            let witness_type = quote! {
                 #[doc = concat!("Field witness for a field of `", stringify!(#struct_name), "`")]
-                #[derive(Debug, Clone)]
+                #[derive(Debug, Clone, PartialEq, Eq, Hash)]
                 pub struct #struct_marker_name;
 
+                impl #struct_marker_name {
+                    /// The MongoDB name of the field, usable in `const` contexts
+                    pub const FIELD: &'static str = #mongo_field_name_expr;
+                }
+
                 impl ::tnuctipun::field_witnesses::FieldName for #struct_marker_name {
                     fn field_name() -> &'static str {
-                        #mongo_field_name_expr
+                        Self::FIELD
                     }
                 }
-            })
+            };
+
+            Some((witness_type, mongo_field_name_expr, struct_marker_name))
         })
         .collect::<Vec<_>>();
 
+    let field_witness_types = witnessed_fields.iter().map(|(ty, _, _)| ty);
+    let mongo_field_names = witnessed_fields.iter().map(|(_, name, _)| name);
+    let field_entries = witnessed_fields.iter().map(|(_, name, marker)| {
+        quote! { (#name, ::std::any::TypeId::of::<#fields_mod_name::#marker>()) }
+    });
+
+    // Generate the field enum if requested with #[tnuctipun(field_enum)]
+    let field_enum = if container_attrs.field_enum {
+        let enum_name = syn::Ident::new(&format!("{struct_name}Field"), struct_name.span());
+        let variants = witnessed_fields
+            .iter()
+            .map(|(_, _, marker)| marker)
+            .collect::<Vec<_>>();
+        let doc = format!("Witnessed fields of `{struct_name}`, for runtime iteration");
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum #enum_name {
+                #(#variants),*
+            }
+
+            impl #enum_name {
+                /// All the variants, in field declaration order
+                pub const ALL: &'static [#enum_name] = &[#(#enum_name::#variants),*];
+
+                /// Returns the MongoDB field name
+                pub fn as_str(&self) -> &'static str {
+                    match *self {
+                        #(#enum_name::#variants => <#fields_mod_name::#variants as ::tnuctipun::field_witnesses::FieldName>::field_name()),*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     // Generate HasField implementations outside the module to avoid path issues
     let has_field_impls = fields.iter().filter_map(|field| {
         let field_name = field.ident.as_ref().unwrap();
@@ -296,14 +362,42 @@ pub fn derive_field_witnesses(input: TokenStream) -> TokenStream {
             field_name.span()
         );
 
+        // Fields which can be missing from the documents (unset) without breaking deserialization
+        let optional_field_impl = if is_option_type(field_type)
+            || container_serde_default
+            || has_serde_default(&field.attrs)
+        {
+            quote! {
+                impl ::tnuctipun::field_witnesses::OptionalField<#fields_mod_name::#struct_marker_name> for #struct_name {}
+            }
+        } else {
+            quote! {}
+        };
+
+        // The declared BSON type takes priority
+        let bson_type = match field_attrs.bson_type.as_ref().map(parse_bson_type) {
+            Some(Ok((alias, _))) => Some(alias),
+            Some(Err(error)) => return Some(error.to_compile_error()),
+            None => infer_bson_type(field_type),
+        };
+        let bson_type_const = bson_type.map(|alias| {
+            quote! {
+                const BSON_TYPE: Option<&'static str> = Some(#alias);
+            }
+        });
+
         Some(quote! {
             impl ::tnuctipun::field_witnesses::HasField<#fields_mod_name::#struct_marker_name> for #struct_name {
                 type Value = #field_type;
 
+                #bson_type_const
+
                 fn get_field(&self) -> &Self::Value {
                     &self.#field_name
                 }
             }
+
+            #optional_field_impl
         })
     }).collect::<Vec<_>>();
 
@@ -311,6 +405,17 @@ pub fn derive_field_witnesses(input: TokenStream) -> TokenStream {
     let expanded = quote! {
         impl ::tnuctipun::field_witnesses::NonEmptyStruct for #struct_name {}
 
+        impl ::tnuctipun::field_witnesses::FieldNames for #struct_name {
+            fn field_names() -> &'static [&'static str] {
+                &[#(#mongo_field_names),*]
+            }
+        }
+
+        impl #struct_name {
+            /// The MongoDB name and the marker `TypeId` of each witnessed field, in declaration order
+            pub const FIELDS: &'static [(&'static str, ::std::any::TypeId)] = &[#(#field_entries),*];
+        }
+
         // Create a module containing all field witnesses specifically for this struct
         // This prevents naming conflicts when multiple structs have fields with the same name
         pub mod #fields_mod_name {
@@ -319,6 +424,8 @@ pub fn derive_field_witnesses(input: TokenStream) -> TokenStream {
 
         // Generate HasField implementations outside the module
         #(#has_field_impls)*
+
+        #field_enum
     };
 
     // Convert back to token stream
@@ -365,6 +472,8 @@ fn parse_container_attributes(attrs: &[Attribute]) -> ContainerAttributes {
                             "include_private attribute must be a boolean value (true or false)",
                         ));
                     }
+                } else if meta.path.is_ident("field_enum") {
+                    container_attrs.field_enum = true;
                 }
                 Ok(())
             });
@@ -381,6 +490,7 @@ fn is_field_private(visibility: &Visibility) -> bool {
 
 fn parse_field_attributes(attrs: &[Attribute]) -> FieldAttributes {
     let mut field_attrs = FieldAttributes::default();
+    let mut explicit_skip = None;
 
     for attr in attrs {
         if attr.path().is_ident("tnuctipun") {
@@ -392,7 +502,20 @@ fn parse_field_attributes(attrs: &[Attribute]) -> FieldAttributes {
                         field_attrs.rename = Some(lit_str.value());
                     }
                 } else if meta.path.is_ident("skip") {
-                    field_attrs.skip = true;
+                    if meta.input.peek(Token![=]) {
+                        let value: LitBool = meta.value()?.parse()?;
+
+                        explicit_skip = Some(value.value);
+                    } else {
+                        explicit_skip = Some(true);
+                    }
+                } else if meta.path.is_ident("bson_type") {
+                    if let Lit::Str(lit_str) = meta.value()?.parse()? {
+                        field_attrs.bson_type = Some(lit_str);
+                    }
+                } else if meta.input.peek(Token![=]) {
+                    // Skip the value of the other attributes handled by MongoComparable
+                    let _: Lit = meta.value()?.parse()?;
                 }
 
                 Ok(())
@@ -400,9 +523,81 @@ fn parse_field_attributes(attrs: &[Attribute]) -> FieldAttributes {
         }
     }
 
+    // The fields not serialized by serde are not stored, so skipped unless explicitly kept
+    field_attrs.skip = explicit_skip.unwrap_or_else(|| {
+        has_serde_meta(attrs, "skip") || has_serde_meta(attrs, "skip_serializing")
+    });
+
     field_attrs
 }
 
+/// Check if a type is an `Option` (whatever the path prefix)
+fn is_option_type(ty: &Type) -> bool {
+    if let Type::Path(type_path) = ty {
+        type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option")
+    } else {
+        false
+    }
+}
+
+/// Infer the `$type` alias of the BSON value a Rust type is serialized as (e.g. "string")
+///
+/// The `Option` and `Box` types are looked through, and `None` is returned for the types
+/// whose serialization can't be known from their name (e.g. the embedded structs), or isn't
+/// the obvious one (e.g. `chrono::DateTime`, serialized as a string by default).
+fn infer_bson_type(ty: &Type) -> Option<&'static str> {
+    match ty {
+        Type::Reference(reference) => infer_bson_type(&reference.elem),
+        Type::Array(_) | Type::Slice(_) => Some("array"),
+        Type::Path(type_path) => {
+            let segment = type_path.path.segments.last()?;
+
+            match segment.ident.to_string().as_str() {
+                "Option" | "Box" => match &segment.arguments {
+                    syn::PathArguments::AngleBracketed(args) => match args.args.first()? {
+                        syn::GenericArgument::Type(inner) => infer_bson_type(inner),
+                        _ => None,
+                    },
+                    _ => None,
+                },
+                "String" | "str" | "char" => Some("string"),
+                "i8" | "i16" | "i32" | "u8" | "u16" => Some("int"),
+                "i64" | "u32" | "u64" => Some("long"),
+                "f32" | "f64" => Some("double"),
+                "bool" => Some("bool"),
+                "DateTime" if is_bson_date_time(ty) => Some("date"),
+                "ObjectId" => Some("objectId"),
+                "Decimal128" => Some("decimal"),
+                "Binary" => Some("binData"),
+                "Document" | "HashMap" | "BTreeMap" => Some("object"),
+                "Vec" | "VecDeque" | "HashSet" | "BTreeSet" => Some("array"),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Check if the attributes include `#[serde(default)]` or `#[serde(default = "...")]`
+fn has_serde_default(attrs: &[Attribute]) -> bool {
+    has_serde_meta(attrs, "default")
+}
+
+/// Check if the attributes include a `#[serde(...)]` item with the given name (e.g. `skip`)
+fn has_serde_meta(attrs: &[Attribute], name: &str) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("serde")
+            && attr
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .map(|metas| metas.iter().any(|meta| meta.path().is_ident(name)))
+                .unwrap_or(false)
+    })
+}
+
 // Built-in field name transformations - called at macro expansion time
 
 /// Convert snake_case to PascalCase for struct marker names.
@@ -675,4 +870,75 @@ mod tests {
 
         assert!(!is_field_private(&visibility));
     }
+
+    #[test]
+    fn test_is_option_type() {
+        use syn::parse_quote;
+
+        assert!(is_option_type(&parse_quote!(Option<String>)));
+        assert!(is_option_type(&parse_quote!(std::option::Option<i32>)));
+        assert!(!is_option_type(&parse_quote!(Vec<Option<i32>>)));
+        assert!(!is_option_type(&parse_quote!(&'static str)));
+    }
+
+    #[test]
+    fn test_has_serde_default() {
+        use syn::{ItemStruct, parse_quote};
+
+        let item: ItemStruct = parse_quote! {
+            #[serde(rename_all = "camelCase", default)]
+            struct User { name: String }
+        };
+
+        assert!(has_serde_default(&item.attrs));
+
+        let item: ItemStruct = parse_quote! {
+            #[serde(default = "default_name")]
+            struct User { name: String }
+        };
+
+        assert!(has_serde_default(&item.attrs));
+
+        let item: ItemStruct = parse_quote! {
+            #[serde(rename = "user", deny_unknown_fields)]
+            #[tnuctipun(include_private = true)]
+            struct User { name: String }
+        };
+
+        assert!(!has_serde_default(&item.attrs));
+    }
+
+    #[test]
+    fn test_parse_field_attributes_serde_skip() {
+        use syn::{Field, parse_quote};
+
+        let field: Field = parse_quote! {
+            #[serde(skip)]
+            pub cache: String
+        };
+
+        assert!(parse_field_attributes(&field.attrs).skip);
+
+        let field: Field = parse_quote! {
+            #[serde(rename = "pwd", skip_serializing)]
+            pub password: String
+        };
+
+        assert!(parse_field_attributes(&field.attrs).skip);
+
+        let field: Field = parse_quote! {
+            #[serde(skip)]
+            #[tnuctipun(skip = false)]
+            pub local_state: String
+        };
+
+        assert!(!parse_field_attributes(&field.attrs).skip);
+
+        let field: Field = parse_quote! {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub device: Option<String>
+        };
+
+        assert!(!parse_field_attributes(&field.attrs).skip);
+    }
 }
@@ -1,14 +1,24 @@
 use proc_macro::TokenStream;
 
+mod bson_types;
 mod field_witnesses;
+mod module_attributes;
 mod mongo_comparable;
 
 /// Procedural macro to generate field witnesses for a struct.
 ///
 /// This macro automatically generates:
-/// - Struct marker types for each field (always in PascalCase following Rust conventions)
-/// - FieldName implementations for each field marker (returns MongoDB field names)
-/// - HasField implementations to access field values with type safety
+/// - Struct marker types for each field (always in PascalCase following Rust conventions),
+///   deriving `Debug`, `Clone`, `PartialEq`, `Eq` and `Hash` (e.g. usable as map keys)
+/// - FieldName implementations for each field marker (returns MongoDB field names), along
+///   with a `FIELD` associated constant on the marker, usable in `const` contexts
+/// - HasField implementations to access field values with type safety, along with the
+///   `BSON_TYPE` tag (e.g. `"string"` or `"array"`) inferred from the field type
+///   (or declared with `#[tnuctipun(bson_type = "...")]`), e.g. to build `$type` checks
+/// - A `FIELDS` associated constant listing the MongoDB name and the marker `TypeId`
+///   of each witnessed field, e.g. for tooling enumerating the fields at the type level
+/// - OptionalField implementations for the `Option` fields and the fields with a serde
+///   default, which can be safely unset
 ///
 /// Note: The generated field witnesses are scoped within a module named `<struct_name>_fields`
 /// at the same module level as the derived struct. This prevents naming conflicts when multiple
@@ -25,14 +35,21 @@ mod mongo_comparable;
 ///
 /// - `#[tnuctipun(field_naming = "strategy")]` - Apply a naming strategy to MongoDB field names only
 ///   - Built-in strategies: "PascalCase", "camelCase"
+///   - Only applies to the fields of this struct, not to the nested structs navigated with
+///     `with_lookup` (see `tnuctipun_module` to apply a strategy to all the structs of a module)
 /// - `#[tnuctipun(include_private = true)]` - Include private fields in witness generation
 ///   - If not specified or set to false, private fields are skipped
 ///   - When true, both public and private fields generate witnesses
+/// - `#[tnuctipun(field_enum)]` - Also generate a `<StructName>Field` enum
+///   - One variant per witnessed field, named as the struct markers
+///   - `ALL` lists the variants, and `as_str()` returns the MongoDB field name
 ///
 /// ## Field-level attributes
 ///
 /// - `#[tnuctipun(rename = "name")]` - Override the MongoDB field name for this specific field
 /// - `#[tnuctipun(skip)]` - Skip generating witnesses for this field
+///   - The fields with `#[serde(skip)]` or `#[serde(skip_serializing)]` are skipped by default,
+///     as they are not stored; `#[tnuctipun(skip = false)]` keeps their witnesses
 ///
 /// # Examples
 ///
@@ -144,6 +161,17 @@ pub fn derive_field_witnesses(input: TokenStream) -> TokenStream {
 /// - If not specified or set to false, private fields are skipped
 /// - When true, both public and private fields generate MongoComparable implementations
 ///
+/// It also supports a field-level attribute:
+///
+/// - `#[tnuctipun(bson_type = "type")]` - Declare the BSON type actually stored for the field,
+///   when it doesn't match the Rust type (e.g. a `String` storing an ISO date)
+///   - Supported types: "double", "string", "int", "long", "bool", "date", "objectId", "decimal",
+///     "binData" (e.g. for a `Vec<u8>` serialized as binary with `serde_bytes`)
+///   - The field is then comparable with the values of the declared type (and of its compatible
+///     types), instead of those compatible with the Rust type, and ordered if the declared type is
+///   - As comparability is generated per field type, the annotated field must be the only one
+///     of its Rust type in the struct (otherwise a compile error is raised)
+///
 /// Example:
 ///
 /// ```ignore
@@ -158,11 +186,17 @@ pub fn derive_field_witnesses(input: TokenStream) -> TokenStream {
 ///
 /// // The macro generates MongoComparable implementations like:
 /// // impl MongoComparable<String, String> for User {}
+/// // impl<'a> MongoComparable<String, &'a str> for User {}
+/// // impl<'a> MongoComparable<String, &'a String> for User {}
 /// // impl MongoComparable<i32, i32> for User {}  
 /// // impl MongoComparable<Vec<String>, String> for User {}
 /// // And many other compatible type combinations...
 /// ```
 ///
+/// Each field is comparable with its own type, whatever it is: the embedded struct fields
+/// (e.g. `home_address: Address`) can be compared with an `Address` value (see
+/// `FilterBuilder::eq_embedded_value`).
+///
 /// With private field inclusion:
 ///
 /// ```ignore
@@ -176,7 +210,53 @@ pub fn derive_field_witnesses(input: TokenStream) -> TokenStream {
 ///
 /// The macro generates trait implementations that enable type-safe MongoDB operations
 /// by providing evidence that specific field types can be compared with specific value types.
-#[proc_macro_derive(MongoComparable)]
+///
+/// Numeric fields are comparable with the narrower numeric types the `bson` crate can convert
+/// (e.g. `i32` and `u32` values for an `i64` field), so the integer literals don't need to be
+/// annotated. As `bson` has no conversion for `u8`, `i8` or `u16`, such values must be widened
+/// first (e.g. `u32::from(count)`).
+///
+/// Besides the numeric and date fields, the `String` fields are ordered (lexicographically,
+/// or per the query collation), so range filters such as `gte` and `lt` apply to them.
+/// The collection fields (e.g. `Vec<i32>`) are ordered with their item type when it's ordered,
+/// as MongoDB applies the range operators to the array elements.
+#[proc_macro_derive(MongoComparable, attributes(tnuctipun))]
 pub fn derive_mongo_comparable(input: TokenStream) -> TokenStream {
     mongo_comparable::derive_mongo_comparable(input)
 }
+
+/// Attribute macro applying module-level settings to the `FieldWitnesses` structs of a module.
+///
+/// As the container-level `field_naming` strategy only affects the fields of the struct it's
+/// set on, nested structs would each need the same attribute. Applied to an inline `mod`, this
+/// macro adds `#[tnuctipun(field_naming = "...")]` to every struct deriving `FieldWitnesses`
+/// in the module, unless the struct already sets its own `field_naming` (which takes priority).
+///
+/// ## Attributes
+///
+/// - `field_naming = "strategy"` - The naming strategy: "PascalCase" or "camelCase"
+///
+/// Example:
+///
+/// ```ignore
+/// use tnuctipun::tnuctipun_module;
+///
+/// #[tnuctipun_module(field_naming = "camelCase")]
+/// mod model {
+///     use tnuctipun::FieldWitnesses;
+///
+///     #[derive(FieldWitnesses)]
+///     pub struct Address {
+///         pub zip_code: String,        // -> "zipCode"
+///     }
+///
+///     #[derive(FieldWitnesses)]
+///     pub struct User {
+///         pub home_address: Address,   // -> "homeAddress"
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn tnuctipun_module(attr: TokenStream, item: TokenStream) -> TokenStream {
+    module_attributes::tnuctipun_module(attr, item)
+}
@@ -0,0 +1,137 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Item, ItemMod, Lit, LitStr, Path, Token, parse_macro_input, parse_quote};
+
+// This function implements the tnuctipun_module attribute macro.
+// It applies module-level settings to each struct deriving FieldWitnesses in an inline module,
+// unless the struct itself already specifies them (struct-level attributes take priority).
+pub fn tnuctipun_module(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut field_naming: Option<LitStr> = None;
+
+    let attr_parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("field_naming") {
+            let value: LitStr = meta.value()?.parse()?;
+
+            match value.value().as_str() {
+                "PascalCase" | "pascal_case" | "camelCase" | "camel_case" => {
+                    field_naming = Some(value);
+
+                    Ok(())
+                }
+                strategy => Err(meta.error(format!(
+                    "Invalid field_naming attribute: '{strategy}'. \
+                     Supported options are: 'PascalCase', 'camelCase'"
+                ))),
+            }
+        } else {
+            Err(meta.error("Unsupported tnuctipun_module attribute, expected 'field_naming'"))
+        }
+    });
+
+    parse_macro_input!(attr with attr_parser);
+
+    let mut module = parse_macro_input!(item as ItemMod);
+
+    let Some((_, items)) = &mut module.content else {
+        return quote! {
+            compile_error!("tnuctipun_module only works with inline modules");
+        }
+        .into();
+    };
+
+    if let Some(strategy) = field_naming {
+        for item in items.iter_mut() {
+            if let Item::Struct(item_struct) = item
+                && derives_field_witnesses(&item_struct.attrs)
+                && !has_field_naming(&item_struct.attrs)
+            {
+                item_struct
+                    .attrs
+                    .push(parse_quote! { #[tnuctipun(field_naming = #strategy)] });
+            }
+        }
+    }
+
+    TokenStream::from(quote! { #module })
+}
+
+/// Check if the attributes include `#[derive(FieldWitnesses)]` (whatever the path prefix)
+fn derives_field_witnesses(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("derive")
+            && attr
+                .parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+                .map(|paths| {
+                    paths.iter().any(|path| {
+                        path.segments
+                            .last()
+                            .is_some_and(|segment| segment.ident == "FieldWitnesses")
+                    })
+                })
+                .unwrap_or(false)
+    })
+}
+
+/// Check if the attributes already include `#[tnuctipun(field_naming = ...)]`
+fn has_field_naming(attrs: &[Attribute]) -> bool {
+    let mut found = false;
+
+    for attr in attrs {
+        if attr.path().is_ident("tnuctipun") {
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("field_naming") {
+                    found = true;
+                }
+
+                if meta.input.peek(Token![=]) {
+                    let _: Lit = meta.value()?.parse()?;
+                }
+
+                Ok(())
+            });
+        }
+    }
+
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::ItemStruct;
+
+    #[test]
+    fn test_derives_field_witnesses() {
+        let item: ItemStruct = parse_quote! {
+            #[derive(Debug, tnuctipun::FieldWitnesses)]
+            struct User { name: String }
+        };
+
+        assert!(derives_field_witnesses(&item.attrs));
+
+        let item: ItemStruct = parse_quote! {
+            #[derive(Debug, Clone)]
+            struct User { name: String }
+        };
+
+        assert!(!derives_field_witnesses(&item.attrs));
+    }
+
+    #[test]
+    fn test_has_field_naming() {
+        let item: ItemStruct = parse_quote! {
+            #[tnuctipun(include_private = true, field_naming = "PascalCase")]
+            struct User { name: String }
+        };
+
+        assert!(has_field_naming(&item.attrs));
+
+        let item: ItemStruct = parse_quote! {
+            #[tnuctipun(field_enum, include_private = true)]
+            struct User { name: String }
+        };
+
+        assert!(!has_field_naming(&item.attrs));
+    }
+}
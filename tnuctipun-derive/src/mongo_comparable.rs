@@ -1,13 +1,21 @@
+use crate::bson_types::{is_bson_date_time, parse_bson_type};
 use proc_macro::TokenStream;
 use quote::quote;
-use std::collections::HashSet;
-use syn::{Attribute, Data, DeriveInput, Fields, Lit, Type, Visibility, parse_macro_input};
+use std::collections::{HashMap, HashSet};
+use syn::{
+    Attribute, Data, DeriveInput, Fields, Lit, LitStr, Token, Type, Visibility, parse_macro_input,
+};
 
 #[derive(Debug, Default)]
 struct ContainerAttributes {
     include_private: bool, // Whether to include private fields (default: false)
 }
 
+#[derive(Debug, Default)]
+struct FieldAttributes {
+    bson_type: Option<LitStr>, // Effective BSON type overriding the compatibility set
+}
+
 /// Check if a field is private (not public)
 fn is_field_private(visibility: &Visibility) -> bool {
     matches!(visibility, Visibility::Inherited)
@@ -38,6 +46,27 @@ fn parse_container_attributes(attrs: &[Attribute]) -> ContainerAttributes {
     container_attrs
 }
 
+fn parse_field_attributes(attrs: &[Attribute]) -> FieldAttributes {
+    let mut field_attrs = FieldAttributes::default();
+
+    for attr in attrs {
+        if attr.path().is_ident("tnuctipun") {
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("bson_type") {
+                    field_attrs.bson_type = Some(meta.value()?.parse()?);
+                } else if meta.input.peek(Token![=]) {
+                    // Skip the value of the attributes handled by FieldWitnesses (e.g. rename)
+                    let _: Lit = meta.value()?.parse()?;
+                }
+
+                Ok(())
+            });
+        }
+    }
+
+    field_attrs
+}
+
 // This function implements the MongoComparable derive macro
 // It automatically implements the MongoComparable trait for a struct
 // with different field types, providing evidence that fields of specific types
@@ -59,6 +88,17 @@ pub fn derive_mongo_comparable(input: TokenStream) -> TokenStream {
         _ => panic!("DeriveMongoComparable only works on structs"),
     };
 
+    // Count the fields of each type, as the comparability is implemented per field type
+    let mut field_type_counts = HashMap::new();
+
+    for field in fields {
+        if !is_field_private(&field.vis) || container_attrs.include_private {
+            *field_type_counts
+                .entry(type_to_string(&field.ty))
+                .or_insert(0usize) += 1;
+        }
+    }
+
     // Create a vector to store all our implementations
     let mut impls = Vec::new();
     let mut implemented_types = HashSet::new();
@@ -74,6 +114,25 @@ pub fn derive_mongo_comparable(input: TokenStream) -> TokenStream {
         let field_type = &field.ty;
         let field_tname = type_to_string(field_type);
 
+        // Resolve the effective type name declared with #[tnuctipun(bson_type = "...")]
+        let bson_type_name = match parse_field_attributes(&field.attrs).bson_type {
+            Some(bson_type) => match parse_bson_type(&bson_type) {
+                // The declared BSON type would also apply to the other fields of the same type
+                Ok(_) if field_type_counts.get(&field_tname).copied().unwrap_or(0) > 1 => {
+                    let message = format!(
+                        "bson_type attribute requires the field to be the only one of type '{field_tname}', as the comparability is defined per field type"
+                    );
+
+                    return syn::Error::new(bson_type.span(), message)
+                        .to_compile_error()
+                        .into();
+                }
+                Ok((_, type_name)) => Some(type_name),
+                Err(error) => return error.to_compile_error().into(),
+            },
+            None => None,
+        };
+
         // For any field type T, implement MongoComparable<T, T>
         let self_impl_key = format!("{field_tname}_{field_tname}");
 
@@ -87,7 +146,14 @@ pub fn derive_mongo_comparable(input: TokenStream) -> TokenStream {
             });
         }
 
-        if is_ordered_type_name(&field_tname) && !implemented_ordered_types.contains(&self_impl_key)
+        // Check the ordering on the base type name, so generic types such as `DateTime<Utc>` match
+        // (or on the declared BSON type, if any)
+        let ordered_type_name = bson_type_name
+            .map(str::to_string)
+            .unwrap_or_else(|| type_base_name(field_type));
+
+        if is_ordered_type_name(&ordered_type_name)
+            && !implemented_ordered_types.contains(&self_impl_key)
         {
             implemented_ordered_types.insert(self_impl_key);
 
@@ -102,8 +168,11 @@ pub fn derive_mongo_comparable(input: TokenStream) -> TokenStream {
         {
             let type_name = segment.ident.to_string();
 
+            // The declared BSON type of an optional field is handled with its inner type (below)
+            let scalar_bson_type_name = bson_type_name.filter(|_| type_name != "Option");
+
             // Generate the compatible types based on hardcoded rules
-            let compatible_types = get_compatible_types_for(&type_name);
+            let compatible_types = compatible_types_with(&type_name, scalar_bson_type_name);
 
             for compatible_type_str in compatible_types {
                 let impl_key = format!("{type_name}_{compatible_type_str}");
@@ -111,24 +180,16 @@ pub fn derive_mongo_comparable(input: TokenStream) -> TokenStream {
                 if !implemented_types.contains(&impl_key) {
                     implemented_types.insert(impl_key.clone());
 
-                    // For most types, we can directly use the string as an identifier
-                    // Only handle special cases explicitly
-                    let compatible_type = if compatible_type_str == "DateTime" {
-                        quote! { chrono::DateTime<chrono::Utc> }
-                    } else {
-                        // Parse the string into an identifier and use it directly
-                        let ident =
-                            syn::Ident::new(&compatible_type_str, proc_macro2::Span::call_site());
-                        quote! { #ident }
-                    };
+                    let (generics, compatible_type) = compatible_type_tokens(&compatible_type_str);
 
                     impls.push(quote! {
-                            impl tnuctipun::mongo_comparable::MongoComparable<#field_type, #compatible_type> for #name {}
+                            impl #generics tnuctipun::mongo_comparable::MongoComparable<#field_type, #compatible_type> for #name {}
                         });
                 }
             }
 
-            let ordered_compatible_types = get_ordered_compatible_types_for(&type_name);
+            let ordered_compatible_types =
+                ordered_compatible_types_with(&type_name, scalar_bson_type_name);
 
             for compatible_type_str in ordered_compatible_types {
                 let impl_key = format!("{type_name}_{compatible_type_str}");
@@ -136,21 +197,40 @@ pub fn derive_mongo_comparable(input: TokenStream) -> TokenStream {
                 if !implemented_ordered_types.contains(&impl_key) {
                     implemented_ordered_types.insert(impl_key);
 
-                    let compatible_type = if compatible_type_str == "DateTime" {
-                        quote! { chrono::DateTime<chrono::Utc> }
-                    } else {
-                        let ident =
-                            syn::Ident::new(&compatible_type_str, proc_macro2::Span::call_site());
-                        quote! { #ident }
-                    };
+                    let (generics, compatible_type) = compatible_type_tokens(&compatible_type_str);
 
                     impls.push(quote! {
-                            impl tnuctipun::mongo_comparable::MongoOrdered<#field_type, #compatible_type> for #name {}
+                            impl #generics tnuctipun::mongo_comparable::MongoOrdered<#field_type, #compatible_type> for #name {}
                         });
                 }
             }
         }
 
+        // The bson::DateTime fields store the BSON dates the chrono values are converted to
+        // (e.g. by `between_dates`), so they're comparable with them (and ordered, unless optional)
+        if bson_type_name.is_none() {
+            let optional_date_time = type_base_name(field_type) == "Option"
+                && extract_generic_arg(field_type).is_some_and(|inner| is_bson_date_time(&inner));
+
+            if is_bson_date_time(field_type) || optional_date_time {
+                let impl_key = format!("{field_tname}_chrono::DateTime<Utc>");
+
+                if !implemented_types.contains(&impl_key) {
+                    implemented_types.insert(impl_key.clone());
+                    impls.push(quote! {
+                        impl tnuctipun::mongo_comparable::MongoComparable<#field_type, chrono::DateTime<chrono::Utc>> for #name {}
+                    });
+                }
+
+                if !optional_date_time && !implemented_ordered_types.contains(&impl_key) {
+                    implemented_ordered_types.insert(impl_key);
+                    impls.push(quote! {
+                        impl tnuctipun::mongo_comparable::MongoOrdered<#field_type, chrono::DateTime<chrono::Utc>> for #name {}
+                    });
+                }
+            }
+        }
+
         // Now handle special cases for additional type compatibility
         if let Type::Path(type_path) = field_type
             && let Some(segment) = type_path.path.segments.last()
@@ -179,7 +259,8 @@ pub fn derive_mongo_comparable(input: TokenStream) -> TokenStream {
                         && let Some(inner_segment) = inner_type_path.path.segments.last()
                     {
                         let inner_type_name = inner_segment.ident.to_string();
-                        let compatible_types = get_compatible_types_for(&inner_type_name);
+                        let compatible_types =
+                            compatible_types_with(&inner_type_name, bson_type_name);
 
                         for compatible_type_str in compatible_types {
                             let impl_key =
@@ -188,22 +269,27 @@ pub fn derive_mongo_comparable(input: TokenStream) -> TokenStream {
                             if !implemented_types.contains(&impl_key) {
                                 implemented_types.insert(impl_key.clone());
 
-                                // For most types, we can directly use the string as an identifier
-                                // Only handle special cases explicitly
-                                let compatible_type = if compatible_type_str == "DateTime" {
-                                    quote! { chrono::DateTime<chrono::Utc> }
-                                } else {
-                                    // Parse the string into an identifier and use it directly
-                                    let ident = syn::Ident::new(
-                                        &compatible_type_str,
-                                        proc_macro2::Span::call_site(),
-                                    );
+                                let (generics, compatible_type) =
+                                    compatible_type_tokens(&compatible_type_str);
 
-                                    quote! { #ident }
-                                };
+                                impls.push(quote! {
+                                            impl #generics tnuctipun::mongo_comparable::MongoComparable<#field_type, Option<#compatible_type>> for #name {}
+                                        });
+                            }
+
+                            // Also implement MongoComparable<Option<T>, U>, so that values
+                            // compatible with T can be used against the optional field
+                            let impl_key =
+                                format!("Option<{inner_type_name}>_{compatible_type_str}");
+
+                            if !implemented_types.contains(&impl_key) {
+                                implemented_types.insert(impl_key);
+
+                                let (generics, compatible_type) =
+                                    compatible_type_tokens(&compatible_type_str);
 
                                 impls.push(quote! {
-                                            impl tnuctipun::mongo_comparable::MongoComparable<#field_type, Option<#compatible_type>> for #name {}
+                                            impl #generics tnuctipun::mongo_comparable::MongoComparable<#field_type, #compatible_type> for #name {}
                                         });
                             }
                         }
@@ -222,11 +308,22 @@ pub fn derive_mongo_comparable(input: TokenStream) -> TokenStream {
                 );
 
                 if !implemented_types.contains(&impl_key) {
-                    implemented_types.insert(impl_key);
+                    implemented_types.insert(impl_key.clone());
                     impls.push(quote! {
                                 impl tnuctipun::mongo_comparable::MongoComparable<#field_type, #item_type> for #name {}
                             });
                 }
+
+                // Implement MongoOrdered<C, I> for ordered items, as the range operators
+                // match the array elements (e.g. in `$elemMatch`)
+                if is_ordered_type_name(&type_base_name(&item_type))
+                    && !implemented_ordered_types.contains(&impl_key)
+                {
+                    implemented_ordered_types.insert(impl_key);
+                    impls.push(quote! {
+                                impl tnuctipun::mongo_comparable::MongoOrdered<#field_type, #item_type> for #name {}
+                            });
+                }
             }
         }
     }
@@ -244,19 +341,78 @@ fn get_compatible_types_for(type_name: &str) -> Vec<String> {
     match type_name {
         // Hardcoded MongoDB type compatibility rules
         "i32" => vec!["i16".to_string()],
-        "i64" => vec!["i16".to_string(), "i32".to_string()],
+        "i64" => vec!["i16".to_string(), "i32".to_string(), "u32".to_string()],
         "f64" => vec![
             "i16".to_string(),
             "i32".to_string(),
             "i64".to_string(),
+            "u32".to_string(),
             "f32".to_string(),
         ],
         "char" => vec!["String".to_string()],
+        "String" => vec![
+            "&str".to_string(),
+            "&String".to_string(),
+            "Regex".to_string(),
+        ],
         "DateTime" => vec!["i64".to_string()],
         _ => vec![],
     }
 }
 
+// Helper function to get the compatible types for a field type, or for its declared BSON type:
+// in the latter case, the values of the declared type and of its own compatible types are allowed
+fn compatible_types_with(type_name: &str, bson_type_name: Option<&str>) -> Vec<String> {
+    match bson_type_name {
+        Some(bson_type_name) => {
+            let mut compatible_types = vec![bson_type_name.to_string()];
+
+            compatible_types.extend(get_compatible_types_for(bson_type_name));
+            compatible_types.retain(|name| name != type_name);
+
+            compatible_types
+        }
+        None => get_compatible_types_for(type_name),
+    }
+}
+
+fn ordered_compatible_types_with(type_name: &str, bson_type_name: Option<&str>) -> Vec<String> {
+    match bson_type_name {
+        Some(bson_type_name) if is_ordered_type_name(bson_type_name) => {
+            compatible_types_with(type_name, Some(bson_type_name))
+                .into_iter()
+                .filter(|name| is_ordered_type_name(name))
+                .collect()
+        }
+        Some(_) => Vec::new(),
+        None => get_ordered_compatible_types_for(type_name),
+    }
+}
+
+// Helper function to get the impl generics and the type tokens for a compatible type name
+fn compatible_type_tokens(type_name: &str) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    // For most types, we can directly use the string as an identifier
+    // Only handle special cases explicitly
+    match type_name {
+        "DateTime" => (quote! {}, quote! { chrono::DateTime<chrono::Utc> }),
+        "&str" => (quote! { <'a> }, quote! { &'a str }),
+        "&String" => (quote! { <'a> }, quote! { &'a String }),
+        "Regex" => (quote! {}, quote! { tnuctipun::__private::bson::Regex }),
+        "ObjectId" => (
+            quote! {},
+            quote! { tnuctipun::__private::bson::oid::ObjectId },
+        ),
+        "Decimal128" => (quote! {}, quote! { tnuctipun::__private::bson::Decimal128 }),
+        "Binary" => (quote! {}, quote! { tnuctipun::__private::bson::Binary }),
+        _ => {
+            // Parse the string into an identifier and use it directly
+            let ident = syn::Ident::new(type_name, proc_macro2::Span::call_site());
+
+            (quote! {}, quote! { #ident })
+        }
+    }
+}
+
 fn get_ordered_compatible_types_for(type_name: &str) -> Vec<String> {
     if !is_ordered_type_name(type_name) {
         return Vec::new();
@@ -287,6 +443,10 @@ fn is_ordered_type_name(type_name: &str) -> bool {
             | "DateTime"
             | "NaiveDateTime"
             | "NaiveDate"
+            // Strings are ordered lexicographically (or per the collation)
+            | "String"
+            | "&str"
+            | "&String"
     )
 }
 
@@ -318,6 +478,17 @@ fn extract_generic_arg(ty: &Type) -> Option<Type> {
     None
 }
 
+// Helper function to get the base name of a type, without path nor generic arguments
+fn type_base_name(ty: &Type) -> String {
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+    {
+        return segment.ident.to_string();
+    }
+
+    type_to_string(ty)
+}
+
 // Helper function to convert a Type to a string for HashSet keys
 fn type_to_string(ty: &Type) -> String {
     match ty {
@@ -360,6 +531,17 @@ mod tests {
     use super::*;
     use syn::parse_quote;
 
+    #[test]
+    fn test_type_base_name() {
+        let datetime_type: Type = parse_quote!(chrono::DateTime<chrono::Utc>);
+
+        assert_eq!(type_base_name(&datetime_type), "DateTime");
+
+        let i32_type: Type = parse_quote!(i32);
+
+        assert_eq!(type_base_name(&i32_type), "i32");
+    }
+
     #[test]
     fn test_type_to_string_simple_types() {
         // Test simple types
@@ -489,10 +671,14 @@ mod tests {
         assert_eq!(get_ordered_compatible_types_for("i32"), vec!["i16"]);
         assert_eq!(
             get_ordered_compatible_types_for("f64"),
-            vec!["i16", "i32", "i64", "f32"]
+            vec!["i16", "i32", "i64", "u32", "f32"]
         );
         assert_eq!(get_ordered_compatible_types_for("DateTime"), vec!["i64"]);
         assert!(get_ordered_compatible_types_for("char").is_empty());
+        assert_eq!(
+            get_ordered_compatible_types_for("String"),
+            vec!["&str", "&String"]
+        );
     }
 
     #[test]
@@ -500,7 +686,9 @@ mod tests {
         assert!(is_ordered_type_name("i32"));
         assert!(is_ordered_type_name("f64"));
         assert!(is_ordered_type_name("DateTime"));
-        assert!(!is_ordered_type_name("String"));
+        assert!(is_ordered_type_name("String"));
+        assert!(is_ordered_type_name("&str"));
+        assert!(!is_ordered_type_name("Regex"));
         assert!(!is_ordered_type_name("char"));
     }
 
@@ -628,12 +816,12 @@ mod tests {
         // Test i64 can accept smaller integers
         let i64_compatible = get_compatible_types_for("i64");
 
-        assert_eq!(i64_compatible, vec!["i16", "i32"]);
+        assert_eq!(i64_compatible, vec!["i16", "i32", "u32"]);
 
         // Test f64 can accept all smaller numeric types
         let f64_compatible = get_compatible_types_for("f64");
 
-        assert_eq!(f64_compatible, vec!["i16", "i32", "i64", "f32"]);
+        assert_eq!(f64_compatible, vec!["i16", "i32", "i64", "u32", "f32"]);
     }
 
     #[test]
@@ -644,6 +832,14 @@ mod tests {
         assert_eq!(char_compatible, vec!["String"]);
     }
 
+    #[test]
+    fn test_get_compatible_types_for_string_str() {
+        // Test that String is compatible with string slices and borrowed strings
+        let string_compatible = get_compatible_types_for("String");
+
+        assert_eq!(string_compatible, vec!["&str", "&String", "Regex"]);
+    }
+
     #[test]
     fn test_get_compatible_types_for_datetime() {
         // Test DateTime compatibility with timestamp
@@ -652,10 +848,32 @@ mod tests {
         assert_eq!(datetime_compatible, vec!["i64"]);
     }
 
+    #[test]
+    fn test_compatible_types_with_bson_type() {
+        // The declared type replaces the compatibility set of the Rust type
+        assert_eq!(
+            compatible_types_with("String", Some("DateTime")),
+            vec!["DateTime", "i64"]
+        );
+        assert_eq!(
+            ordered_compatible_types_with("String", Some("DateTime")),
+            vec!["DateTime", "i64"]
+        );
+
+        // The Rust type itself is not repeated
+        assert_eq!(
+            compatible_types_with("i32", Some("i64")),
+            vec!["i64", "i16", "u32"]
+        );
+
+        // Without declared type, the hardcoded rules apply
+        assert_eq!(compatible_types_with("i32", None), vec!["i16"]);
+        assert!(ordered_compatible_types_with("i32", Some("bool")).is_empty());
+    }
+
     #[test]
     fn test_get_compatible_types_for_no_compatibility() {
         // Test types that have no defined compatibility rules
-        assert_eq!(get_compatible_types_for("String"), Vec::<String>::new());
         assert_eq!(get_compatible_types_for("bool"), Vec::<String>::new());
         assert_eq!(get_compatible_types_for("Vec"), Vec::<String>::new());
         assert_eq!(get_compatible_types_for("Option"), Vec::<String>::new());